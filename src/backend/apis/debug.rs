@@ -1,6 +1,6 @@
 use {
     super::super::super::backend::{
-        builder::{Clang, LLVMOptimizator},
+        builder::{Emit, LLVMOptimizator},
         compiler::options::CompilerOptions,
     },
     inkwell::{
@@ -8,14 +8,11 @@ use {
         builder::Builder,
         context::Context,
         module::{Linkage, Module},
-        targets::{Target, TargetMachine},
+        targets::TargetMachine,
         values::{FunctionValue, PointerValue},
         AddressSpace,
     },
-    std::{
-        fs,
-        path::{Path, PathBuf},
-    },
+    std::{fs, path::Path},
 };
 
 pub struct DebugAPI<'a, 'ctx> {
@@ -121,61 +118,30 @@ impl<'a, 'ctx> DebugAPI<'a, 'ctx> {
     }
 }
 
-pub fn compile_debug_api(options: &mut CompilerOptions) {
+pub fn compile_debug_api(options: &CompilerOptions) -> Result<(), String> {
     let debug_api_context: Context = Context::create();
     let debug_api_builder: Builder<'_> = debug_api_context.create_builder();
     let debug_api_module: Module<'_> = debug_api_context.create_module("debug.th");
 
     debug_api_module.set_triple(&options.target_triple);
 
-    let machine: TargetMachine = Target::from_triple(&options.target_triple)
-        .unwrap()
-        .create_target_machine(
-            &options.target_triple,
-            "",
-            "",
-            options.optimization.to_llvm_opt(),
-            options.reloc_mode,
-            options.code_model,
-        )
-        .unwrap();
+    let machine: TargetMachine = options.target_machine()?;
 
     debug_api_module.set_data_layout(&machine.get_target_data().get_data_layout());
 
     DebugAPI::include(&debug_api_module, &debug_api_builder, &debug_api_context);
 
-    if !Path::new("output/").exists() {
-        let _ = fs::create_dir_all("output/");
-    }
-
-    if !PathBuf::from("output/debug.o").exists() {
-        debug_api_module.write_bitcode_to_path(Path::new("output/debug.bc"));
-
-        LLVMOptimizator::optimize(
-            "output/debug.bc",
-            options.optimization.to_llvm_17_passes(),
-            options.optimization.to_str(true, false),
-        );
+    let directory: &Path = &options.output_directory;
 
-        let previous_library: bool = options.library;
-        let previous_executable: bool = options.executable;
-        let previous_static_library: bool = options.static_library;
-        let previous_output: String = options.output.clone();
-
-        options.library = true;
-        options.static_library = false;
-        options.executable = false;
-        options.output = String::from("debug.o");
-
-        Clang::new(&[PathBuf::from("output/debug.bc")], options).compile();
+    if !directory.exists() {
+        let _ = fs::create_dir_all(directory);
+    }
 
-        options.library = previous_library;
-        options.executable = previous_executable;
-        options.static_library = previous_static_library;
-        options.output = previous_output;
+    if !directory.join("debug.o").exists() {
+        LLVMOptimizator::optimize_runtime(&debug_api_module, &machine, &options.optimization)?;
 
-        let _ = fs::remove_file("output/debug.bc");
-        let _ = fs::copy("debug.o", "output/debug.o");
-        let _ = fs::remove_file("debug.o");
+        Emit::Object.write(&debug_api_module, &machine, &directory.join("debug.o"))?;
     }
+
+    Ok(())
 }
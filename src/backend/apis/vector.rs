@@ -1,6 +1,6 @@
 use {
     super::super::super::backend::{
-        builder::{Clang, LLVMOptimizator},
+        builder::{Emit, LLVMOptimizator},
         compiler::options::CompilerOptions,
     },
     inkwell::{
@@ -8,15 +8,12 @@ use {
         builder::Builder,
         context::Context,
         module::{Linkage, Module},
-        targets::{Target, TargetMachine},
+        targets::TargetMachine,
         types::{FunctionType, IntType, StructType},
         values::{FunctionValue, IntValue, PointerValue},
         AddressSpace, IntPredicate,
     },
-    std::{
-        fs,
-        path::{Path, PathBuf},
-    },
+    std::{fs, path::Path},
 };
 
 pub struct VectorAPI<'a, 'ctx> {
@@ -1646,61 +1643,30 @@ impl<'a, 'ctx> VectorAPI<'a, 'ctx> {
     */
 }
 
-pub fn compile_vector_api(options: &mut CompilerOptions) {
+pub fn compile_vector_api(options: &CompilerOptions) -> Result<(), String> {
     let vector_api_context: Context = Context::create();
     let vector_api_builder: Builder<'_> = vector_api_context.create_builder();
     let vector_api_module: Module<'_> = vector_api_context.create_module("vector.th");
 
     vector_api_module.set_triple(&options.target_triple);
 
-    let machine: TargetMachine = Target::from_triple(&options.target_triple)
-        .unwrap()
-        .create_target_machine(
-            &options.target_triple,
-            "",
-            "",
-            options.optimization.to_llvm_opt(),
-            options.reloc_mode,
-            options.code_model,
-        )
-        .unwrap();
+    let machine: TargetMachine = options.target_machine()?;
 
     vector_api_module.set_data_layout(&machine.get_target_data().get_data_layout());
 
     VectorAPI::include(&vector_api_module, &vector_api_builder, &vector_api_context);
 
-    if !Path::new("output/").exists() {
-        let _ = fs::create_dir_all("output/");
-    }
-
-    if !PathBuf::from("output/dist/vector.o").exists() {
-        vector_api_module.write_bitcode_to_path(Path::new("output/vector.bc"));
-
-        LLVMOptimizator::optimize(
-            "output/vector.bc",
-            options.optimization.to_llvm_17_passes(),
-            options.optimization.to_str(true, false),
-        );
+    let directory: &Path = &options.output_directory;
 
-        let previous_library: bool = options.library;
-        let previous_executable: bool = options.executable;
-        let previous_static_library: bool = options.static_library;
-        let previous_output: String = options.output.clone();
-
-        options.library = true;
-        options.executable = false;
-        options.static_library = false;
-        options.output = String::from("vector.o");
-
-        Clang::new(&[PathBuf::from("output/vector.bc")], options).compile();
+    if !directory.exists() {
+        let _ = fs::create_dir_all(directory);
+    }
 
-        options.library = previous_library;
-        options.executable = previous_executable;
-        options.static_library = previous_static_library;
-        options.output = previous_output;
+    if !directory.join("dist/vector.o").exists() {
+        LLVMOptimizator::optimize_runtime(&vector_api_module, &machine, &options.optimization)?;
 
-        let _ = fs::remove_file("output/vector.bc");
-        let _ = fs::copy("vector.o", "output/vector.o");
-        let _ = fs::remove_file("vector.o");
+        Emit::Object.write(&vector_api_module, &machine, &directory.join("vector.o"))?;
     }
+
+    Ok(())
 }
@@ -1,47 +1,95 @@
 #![allow(clippy::upper_case_acronyms)]
 
 use {
-    super::{
-        super::{logging, LLVM_BACKEND_COMPILER},
-        compiler::options::CompilerOptions,
+    super::compiler::options::{CompilerOptions, Opt},
+    inkwell::{
+        llvm_sys::support::LLVMParseCommandLineOptions,
+        module::Module,
+        passes::PassBuilderOptions,
+        targets::{FileType, TargetMachine},
+    },
+    std::{
+        ffi::{c_char, c_int},
+        path::{Path, PathBuf},
+        process::Command,
+        sync::Once,
     },
-    std::{fs, path::PathBuf, process::Command},
 };
 
-pub struct Clang<'a> {
-    files: &'a [PathBuf],
-    options: &'a CompilerOptions,
-}
+/*
 
-impl<'a> Clang<'a> {
-    pub fn new(files: &'a [PathBuf], options: &'a CompilerOptions) -> Self {
-        Self { files, options }
-    }
+    The module is optimized and emitted in-process: the object files, the assembly, the LLVM IR
+    and the LLVM bitcode are written from the module. The tools of the LLVM backend are only run
+    to link the object files, a failed tool returns its error output, so the caller decides how
+    to report it.
 
-    pub fn compile(&self) {
-        if self.options.emit_llvm_ir {
-            LLVMDissambler::new(self.files).dissamble();
+*/
 
-            self.files.iter().for_each(|path| {
-                let _ = fs::remove_file(path);
-            });
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Emit {
+    Object,
+    Assembly,
+    LLVMIR,
+    LLVMBitcode,
+}
 
-            return;
+impl Emit {
+    #[inline]
+    pub fn extension(&self) -> &str {
+        match self {
+            Emit::Object => "o",
+            Emit::Assembly => "s",
+            Emit::LLVMIR => "ll",
+            Emit::LLVMBitcode => "bc",
         }
+    }
 
-        if self.options.emit_asm {
-            LLC::new(self.files, self.options).compile();
-
-            self.files.iter().for_each(|path| {
-                let _ = fs::remove_file(path);
-            });
+    pub fn write(
+        &self,
+        module: &Module<'_>,
+        machine: &TargetMachine,
+        path: &Path,
+    ) -> Result<(), String> {
+        match self {
+            Emit::Object => machine
+                .write_to_file(module, FileType::Object, path)
+                .map_err(|error| error.to_string()),
+
+            Emit::Assembly => machine
+                .write_to_file(module, FileType::Assembly, path)
+                .map_err(|error| error.to_string()),
+
+            Emit::LLVMIR => module
+                .print_to_file(path)
+                .map_err(|error| error.to_string()),
+
+            Emit::LLVMBitcode if module.write_bitcode_to_path(path) => Ok(()),
+
+            Emit::LLVMBitcode => Err(format!(
+                "The bitcode can't be written to \"{}\".",
+                path.to_string_lossy()
+            )),
         }
+    }
+}
+
+pub struct Clang<'a> {
+    files: &'a [PathBuf],
+    options: &'a CompilerOptions,
+    toolchain: &'a Path,
+}
 
-        if self.options.emit_llvm_bitcode {
-            return;
+impl<'a> Clang<'a> {
+    pub fn new(files: &'a [PathBuf], options: &'a CompilerOptions, toolchain: &'a Path) -> Self {
+        Self {
+            files,
+            options,
+            toolchain,
         }
+    }
 
-        let mut clang_command: Command = Command::new(LLVM_BACKEND_COMPILER.join("clang-17"));
+    pub fn link(&self) -> Result<(), String> {
+        let mut clang_command: Command = Command::new(self.toolchain.join("clang-17"));
 
         if self.options.executable {
             clang_command.args([
@@ -51,8 +99,9 @@ impl<'a> Clang<'a> {
                 self.options.optimization.to_str(true, false),
             ]);
         } else {
+            // The object files of a library are linked together into a relocatable object file.
             let library_variant: &str = if self.options.library {
-                "-c"
+                "-r"
             } else {
                 "--emit-static-lib"
             };
@@ -72,77 +121,101 @@ impl<'a> Clang<'a> {
 
         clang_command.args(["-o", &self.options.output]);
 
-        handle_command(&mut clang_command);
+        handle_command(&mut clang_command)
     }
 }
 
-pub struct LLC<'a> {
-    files: &'a [PathBuf],
-    options: &'a CompilerOptions,
-}
-
-impl<'a> LLC<'a> {
-    pub fn new(files: &'a [PathBuf], options: &'a CompilerOptions) -> Self {
-        Self { files, options }
-    }
-
-    pub fn compile(&self) {
-        let mut llc_command: Command = Command::new(LLVM_BACKEND_COMPILER.join("llc"));
-
-        llc_command.args([
-            self.options.optimization.to_str(true, false),
-            "--asm-verbose",
-            "--filetype=asm",
-        ]);
+pub struct LLVMOptimizator;
 
-        llc_command.args(self.files);
+impl LLVMOptimizator {
+    pub fn optimize(
+        module: &Module<'_>,
+        machine: &TargetMachine,
+        options: &CompilerOptions,
+    ) -> Result<(), String> {
+        if options.time_passes {
+            Self::enable_time_passes();
+        }
 
-        handle_command(&mut llc_command);
+        Self::run_passes(
+            module,
+            machine,
+            (options.passes(), &options.optimization),
+            options.print_passes,
+        )
     }
-}
 
-pub struct LLVMDissambler<'a> {
-    files: &'a [PathBuf],
-}
-
-impl<'a> LLVMDissambler<'a> {
-    pub fn new(files: &'a [PathBuf]) -> Self {
-        Self { files }
+    pub fn optimize_runtime(
+        module: &Module<'_>,
+        machine: &TargetMachine,
+        optimization: &Opt,
+    ) -> Result<(), String> {
+        // The runtime APIs aren't written by the user, only the optimization level applies to them.
+        Self::run_passes(
+            module,
+            machine,
+            (optimization.to_llvm_17_passes(), optimization),
+            false,
+        )
     }
 
-    pub fn dissamble(&self) {
-        handle_command(Command::new(LLVM_BACKEND_COMPILER.join("llvm-dis")).args(self.files));
+    fn run_passes(
+        module: &Module<'_>,
+        machine: &TargetMachine,
+        pipeline: (&str, &Opt),
+        print_passes: bool,
+    ) -> Result<(), String> {
+        // Like Clang, the loops and the straight-line code are vectorized from "O2".
+        let vectorize: bool = matches!(pipeline.1, Opt::Mid | Opt::Mcqueen);
+
+        let pass_options: PassBuilderOptions = PassBuilderOptions::create();
+
+        pass_options.set_debug_logging(print_passes);
+        pass_options.set_loop_vectorization(vectorize);
+        pass_options.set_loop_slp_vectorization(vectorize);
+
+        module
+            .run_passes(pipeline.0, machine, pass_options)
+            .map_err(|error| {
+                format!(
+                    "The pass pipeline \"{}\" can't be run: {}.",
+                    pipeline.0,
+                    error.to_string().trim_end_matches('.')
+                )
+            })
     }
-}
 
-pub struct LLVMOptimizator;
+    fn enable_time_passes() {
+        /*
+            The timing of the passes is an option of the command line of LLVM, it's enabled
+            once for the whole process. A report is printed in the stderr after every pipeline.
+        */
 
-impl LLVMOptimizator {
-    pub fn optimize(path: &str, opt: &str, opt_lto: &str) {
-        handle_command(
-            Command::new(LLVM_BACKEND_COMPILER.join("opt"))
-                .arg(format!("-p={}", opt))
-                .arg(path)
-                .arg("-o")
-                .arg(path),
-        );
-
-        handle_command(
-            Command::new(LLVM_BACKEND_COMPILER.join("llvm-lto"))
-                .arg(opt_lto)
-                .arg(path),
-        );
+        static TIME_PASSES: Once = Once::new();
+
+        TIME_PASSES.call_once(|| {
+            let args: [*const c_char; 2] = [c"thrushc".as_ptr(), c"-time-passes".as_ptr()];
+
+            unsafe {
+                LLVMParseCommandLineOptions(args.len() as c_int, args.as_ptr(), c"".as_ptr());
+            }
+        });
     }
 }
 
 #[inline]
-fn handle_command(command: &mut Command) {
-    if let Ok(child) = command.output() {
-        if !child.status.success() {
-            logging::log(
-                logging::LogType::ERROR,
-                &String::from_utf8_lossy(&child.stderr).replace("\n", ""),
-            );
+fn handle_command(command: &mut Command) -> Result<(), String> {
+    match command.output() {
+        Ok(child) if !child.status.success() => {
+            Err(String::from_utf8_lossy(&child.stderr).replace("\n", ""))
         }
+
+        Ok(_) => Ok(()),
+
+        Err(error) => Err(format!(
+            "\"{}\" can't be run: {}.",
+            command.get_program().to_string_lossy(),
+            error
+        )),
     }
 }
@@ -0,0 +1,310 @@
+use {
+    super::{
+        super::{super::frontend::lexer::DataTypes, instruction::Capture},
+        objects::CompilerObjects,
+        utils, Instruction,
+    },
+    inkwell::{
+        basic_block::BasicBlock,
+        builder::Builder,
+        context::Context,
+        module::{Linkage, Module},
+        types::{BasicMetadataTypeEnum, BasicTypeEnum, FunctionType, StructType},
+        values::{BasicValueEnum, FunctionValue, IntValue, PointerValue, StructValue},
+        AddressSpace,
+    },
+};
+
+/*
+
+    CLOSURES LAYOUT
+
+    Closures are lowered to a fat pointer, the function receives the environment as the first parameter.
+
+    { ptr, ptr }
+      ^^^  ^^^
+    Function - Environment
+
+    The environment is allocated in the heap, it starts with the destructor of the environment.
+
+    { ptr, T1, T2, ... }
+      ^^^  ^^^^^^^^^^^
+    Drop - Captures (the value, or a pointer to the variable if it is captured by reference)
+
+    The variable that holds the closure owns the environment and calls to the destructor when it is
+    deallocated. Returning or copying the closure moves the ownership.
+
+*/
+
+#[inline]
+pub fn build_closure_type(context: &Context) -> StructType<'_> {
+    context.struct_type(
+        &[
+            context.ptr_type(AddressSpace::default()).into(),
+            context.ptr_type(AddressSpace::default()).into(),
+        ],
+        false,
+    )
+}
+
+pub fn build_env_type<'ctx>(
+    context: &'ctx Context,
+    captures: &[Capture<'ctx>],
+) -> StructType<'ctx> {
+    let mut fields: Vec<BasicTypeEnum<'ctx>> = Vec::with_capacity(captures.len() + 1);
+
+    fields.push(context.ptr_type(AddressSpace::default()).into());
+
+    captures.iter().for_each(|capture| {
+        fields.push(build_capture_type(context, capture));
+    });
+
+    context.struct_type(&fields, false)
+}
+
+#[inline]
+fn build_capture_type<'ctx>(
+    context: &'ctx Context,
+    capture: &Capture<'ctx>,
+) -> BasicTypeEnum<'ctx> {
+    if capture.3 {
+        return context.ptr_type(AddressSpace::default()).into();
+    }
+
+    utils::datatype_to_basic_type_enum(context, &capture.1)
+}
+
+pub fn build_closure_fn_type<'ctx>(
+    context: &'ctx Context,
+    kind: &DataTypes,
+    params: &[DataTypes],
+) -> FunctionType<'ctx> {
+    let mut param_types: Vec<BasicMetadataTypeEnum<'ctx>> = Vec::with_capacity(params.len() + 1);
+
+    param_types.push(context.ptr_type(AddressSpace::default()).into());
+
+    params.iter().for_each(|param| {
+        param_types.push(utils::datatype_to_basicmetadata_type_enum(context, param));
+    });
+
+    utils::build_fn_type(context, kind, &param_types)
+}
+
+pub fn predefine_closure<'ctx>(
+    module: &Module<'ctx>,
+    context: &'ctx Context,
+    name: &str,
+    params: &[Instruction<'ctx>],
+    return_kind: &Option<DataTypes>,
+) -> FunctionValue<'ctx> {
+    let params: Vec<DataTypes> = params.iter().map(|param| param.get_data_type()).collect();
+
+    let kind: FunctionType<'ctx> =
+        build_closure_fn_type(context, &return_kind.unwrap_or(DataTypes::Void), &params);
+
+    let function: FunctionValue<'ctx> =
+        module.add_function(name, kind, Some(Linkage::LinkerPrivate));
+
+    module.add_function(
+        &format!("{}.drop", name),
+        context
+            .void_type()
+            .fn_type(&[context.ptr_type(AddressSpace::default()).into()], false),
+        Some(Linkage::LinkerPrivate),
+    );
+
+    function
+}
+
+pub fn define_drop<'ctx>(
+    module: &Module<'ctx>,
+    builder: &Builder<'ctx>,
+    context: &'ctx Context,
+    name: &str,
+    captures: &[Capture<'ctx>],
+) {
+    let drop: FunctionValue<'ctx> = module.get_function(&format!("{}.drop", name)).unwrap();
+    let env_type: StructType<'ctx> = build_env_type(context, captures);
+
+    builder.position_at_end(context.append_basic_block(drop, ""));
+
+    let env: PointerValue<'ctx> = drop.get_first_param().unwrap().into_pointer_value();
+
+    captures
+        .iter()
+        .enumerate()
+        .filter(|(_, capture)| !capture.3)
+        .for_each(|(index, capture)| {
+            let field: PointerValue<'ctx> = builder
+                .build_struct_gep(env_type, env, index as u32 + 1, "")
+                .unwrap();
+
+            if capture.1 == DataTypes::String {
+                let string: PointerValue<'ctx> = builder
+                    .build_load(context.ptr_type(AddressSpace::default()), field, "")
+                    .unwrap()
+                    .into_pointer_value();
+
+                builder
+                    .build_call(
+                        module.get_function("Vec.destroy").unwrap(),
+                        &[string.into()],
+                        "",
+                    )
+                    .unwrap();
+
+                builder.build_free(string).unwrap();
+            }
+
+            if capture.1 == DataTypes::Closure {
+                let closure: StructValue<'ctx> = builder
+                    .build_load(build_closure_type(context), field, "")
+                    .unwrap()
+                    .into_struct_value();
+
+                compile_drop(builder, context, closure);
+            }
+        });
+
+    builder.build_free(env).unwrap();
+    builder.build_return(None).unwrap();
+}
+
+pub fn compile_closure<'ctx>(
+    module: &Module<'ctx>,
+    builder: &Builder<'ctx>,
+    context: &'ctx Context,
+    name: &str,
+    captures: &[Capture<'ctx>],
+    objects: &CompilerObjects<'ctx>,
+) -> BasicValueEnum<'ctx> {
+    let env_type: StructType<'ctx> = build_env_type(context, captures);
+    let env: PointerValue<'ctx> = builder.build_malloc(env_type, "").unwrap();
+
+    let drop: PointerValue<'ctx> = module
+        .get_function(&format!("{}.drop", name))
+        .unwrap()
+        .as_global_value()
+        .as_pointer_value();
+
+    builder
+        .build_store(
+            builder.build_struct_gep(env_type, env, 0, "").unwrap(),
+            drop,
+        )
+        .unwrap();
+
+    captures.iter().enumerate().for_each(|(index, capture)| {
+        let var: PointerValue<'ctx> = objects.find_and_get(capture.0).unwrap();
+
+        let value: BasicValueEnum<'ctx> = if capture.3 {
+            var.into()
+        } else if capture.1 == DataTypes::String {
+            builder
+                .build_call(module.get_function("Vec.clone").unwrap(), &[var.into()], "")
+                .unwrap()
+                .try_as_basic_value()
+                .unwrap_left()
+        } else {
+            builder
+                .build_load(build_capture_type(context, capture), var, "")
+                .unwrap()
+        };
+
+        let field: PointerValue<'ctx> = builder
+            .build_struct_gep(env_type, env, index as u32 + 1, "")
+            .unwrap();
+
+        builder.build_store(field, value).unwrap();
+    });
+
+    let function: PointerValue<'ctx> = objects
+        .find_and_get_function(name)
+        .unwrap()
+        .as_global_value()
+        .as_pointer_value();
+
+    let closure: StructValue<'ctx> = builder
+        .build_insert_value(build_closure_type(context).get_undef(), function, 0, "")
+        .unwrap()
+        .into_struct_value();
+
+    builder
+        .build_insert_value(closure, env, 1, "")
+        .unwrap()
+        .into_struct_value()
+        .into()
+}
+
+pub fn bind_captures<'ctx>(
+    builder: &Builder<'ctx>,
+    context: &'ctx Context,
+    env: PointerValue<'ctx>,
+    captures: &[Capture<'ctx>],
+    objects: &mut CompilerObjects<'ctx>,
+) {
+    let env_type: StructType<'ctx> = build_env_type(context, captures);
+
+    captures.iter().enumerate().for_each(|(index, capture)| {
+        let field: PointerValue<'ctx> = builder
+            .build_struct_gep(env_type, env, index as u32 + 1, "")
+            .unwrap();
+
+        if capture.3 || capture.1 == DataTypes::String {
+            let var: PointerValue<'ctx> = builder
+                .build_load(context.ptr_type(AddressSpace::default()), field, "")
+                .unwrap()
+                .into_pointer_value();
+
+            objects.insert(capture.0.to_string(), var);
+
+            return;
+        }
+
+        objects.insert(capture.0.to_string(), field);
+    });
+}
+
+pub fn compile_drop<'ctx>(
+    builder: &Builder<'ctx>,
+    context: &'ctx Context,
+    closure: StructValue<'ctx>,
+) {
+    let env: PointerValue<'ctx> = builder
+        .build_extract_value(closure, 1, "")
+        .unwrap()
+        .into_pointer_value();
+
+    let function: FunctionValue<'ctx> = builder.get_insert_block().unwrap().get_parent().unwrap();
+
+    let drop_block: BasicBlock<'ctx> = context.append_basic_block(function, "");
+    let exit_block: BasicBlock<'ctx> = context.append_basic_block(function, "");
+
+    let is_null: IntValue<'ctx> = builder.build_is_null(env, "").unwrap();
+
+    builder
+        .build_conditional_branch(is_null, exit_block, drop_block)
+        .unwrap();
+
+    builder.position_at_end(drop_block);
+
+    let drop: PointerValue<'ctx> = builder
+        .build_load(context.ptr_type(AddressSpace::default()), env, "")
+        .unwrap()
+        .into_pointer_value();
+
+    builder
+        .build_indirect_call(
+            context
+                .void_type()
+                .fn_type(&[context.ptr_type(AddressSpace::default()).into()], false),
+            drop,
+            &[env.into()],
+            "",
+        )
+        .unwrap();
+
+    builder.build_unconditional_branch(exit_block).unwrap();
+
+    builder.position_at_end(exit_block);
+}
@@ -3,24 +3,25 @@ use {
         super::{
             super::frontend::lexer::DataTypes,
             apis::{debug::DebugAPI, vector::VectorAPI},
-            instruction::Instruction,
+            instruction::{Capture, EnumVariants, Instruction, MatchArm},
         },
-        functions, general,
+        closures, enums, functions, general,
         objects::CompilerObjects,
         options::CompilerOptions,
-        utils, variable,
+        structs, utils, variable,
     },
     inkwell::{
         basic_block::BasicBlock,
         builder::Builder,
         context::Context,
         module::{Linkage, Module},
-        types::{FunctionType, StructType},
+        types::{BasicTypeEnum, FunctionType, IntType, StructType},
         values::{
-            BasicMetadataValueEnum, BasicValueEnum, FloatValue, FunctionValue, GlobalValue,
-            InstructionOpcode, InstructionValue, IntValue, PointerValue,
+            BasicMetadataValueEnum, BasicValue, BasicValueEnum, CallSiteValue, FloatValue,
+            FunctionValue, GlobalValue, InstructionOpcode, InstructionValue, IntValue, PhiValue,
+            PointerValue, StructValue,
         },
-        AddressSpace,
+        AddressSpace, IntPredicate,
     },
 };
 
@@ -33,6 +34,7 @@ pub struct Codegen<'a, 'ctx> {
     objects: CompilerObjects<'ctx>,
     options: &'a CompilerOptions,
     function: Option<FunctionValue<'ctx>>,
+    loops: Vec<(Option<&'ctx str>, BasicBlock<'ctx>, BasicBlock<'ctx>)>,
 }
 
 impl<'a, 'ctx> Codegen<'a, 'ctx> {
@@ -52,6 +54,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
             objects: CompilerObjects::new(),
             options,
             function: None,
+            loops: Vec::new(),
         }
         .start();
     }
@@ -72,6 +75,8 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
             DebugAPI::define(self.module, self.builder, self.context);
         }
 
+        self.predefine_structs();
+        self.predefine_enums();
         self.predefine_functions();
 
         while !self.is_end() {
@@ -82,25 +87,45 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
 
     fn codegen(&mut self, instr: &'ctx Instruction<'ctx>) -> Instruction<'ctx> {
         match instr {
-            Instruction::Block { stmts, .. } => {
-                self.objects.push();
-
-                stmts.iter().for_each(|instr| {
-                    self.codegen(instr);
-                });
-
-                self.objects.pop();
+            Instruction::Block { stmts, .. } => match self.compile_block(stmts) {
+                Some(value) => Instruction::BasicValueEnum(value),
+                None => Instruction::Null,
+            },
 
-                Instruction::Null
+            Instruction::Yield { value, kind } => {
+                Instruction::BasicValueEnum(self.compile_expression(value, kind))
             }
 
+            Instruction::If {
+                cond,
+                block,
+                otherwise,
+                kind,
+            } => match self.compile_if(cond, block, otherwise.as_deref(), kind) {
+                Some(value) => Instruction::BasicValueEnum(value),
+                None => Instruction::Null,
+            },
+
             Instruction::Free {
                 name,
                 is_string,
                 free_only,
+                is_closure,
             } => {
                 let var: PointerValue<'ctx> = self.objects.find_and_get(name).unwrap();
 
+                if *is_closure {
+                    let closure: StructValue<'ctx> = self
+                        .builder
+                        .build_load(closures::build_closure_type(self.context), var, "")
+                        .unwrap()
+                        .into_struct_value();
+
+                    closures::compile_drop(self.builder, self.context, closure);
+
+                    return Instruction::Null;
+                }
+
                 if *is_string && !free_only {
                     self.builder
                         .build_call(
@@ -121,6 +146,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                 cond,
                 actions,
                 block,
+                label,
             } => {
                 let function: FunctionValue<'ctx> = self.function.unwrap();
 
@@ -149,7 +175,10 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                 self.builder.position_at_end(then_block);
 
                 self.codegen(actions.as_ref().unwrap());
+
+                self.loops.push((*label, exit_block, start_block));
                 self.codegen(block.as_ref());
+                self.loops.pop();
 
                 self.builder
                     .build_unconditional_branch(start_block)
@@ -160,22 +189,362 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                 Instruction::Null
             }
 
+            Instruction::Break { label } | Instruction::Continue { label } => {
+                let (_, exit_block, continue_block) = *self
+                    .loops
+                    .iter()
+                    .rev()
+                    .find(|(loop_label, ..)| label.is_none() || loop_label == label)
+                    .unwrap();
+
+                let target: BasicBlock<'ctx> = if matches!(instr, Instruction::Break { .. }) {
+                    exit_block
+                } else {
+                    continue_block
+                };
+
+                self.builder.build_unconditional_branch(target).unwrap();
+
+                let after_block: BasicBlock<'ctx> =
+                    self.context.append_basic_block(self.function.unwrap(), "");
+
+                self.builder.position_at_end(after_block);
+
+                Instruction::Null
+            }
+
+            Instruction::ForRange {
+                variable,
+                kind,
+                start,
+                end,
+                step,
+                inclusive,
+                block,
+                label,
+                line,
+            } => {
+                let function: FunctionValue<'ctx> = self.function.unwrap();
+                let llvm_kind: IntType<'ctx> =
+                    utils::datatype_integer_to_llvm_type(self.context, kind);
+
+                let start: IntValue<'ctx> = self.compile_integer_expression(start, kind);
+                let end: IntValue<'ctx> = self.compile_integer_expression(end, kind);
+
+                let (step, literal_step): (IntValue<'ctx>, bool) = match step {
+                    Some(step) => (
+                        self.compile_integer_expression(step, kind),
+                        matches!(step.as_ref(), Instruction::Integer(..)),
+                    ),
+                    None => (llvm_kind.const_int(1, false), true),
+                };
+
+                self.objects.push();
+
+                let counter: PointerValue<'ctx> = utils::build_alloca_int(self.builder, llvm_kind);
+
+                self.builder.build_store(counter, start).unwrap();
+
+                self.objects.insert(variable.to_string(), counter);
+
+                // A zero literal is rejected by the parser, any other step is checked at runtime.
+                if !literal_step {
+                    let zero_block: BasicBlock<'ctx> =
+                        self.context.append_basic_block(function, "");
+                    let checked_block: BasicBlock<'ctx> =
+                        self.context.append_basic_block(function, "");
+
+                    let is_zero: IntValue<'ctx> = self
+                        .builder
+                        .build_int_compare(IntPredicate::EQ, step, llvm_kind.const_zero(), "")
+                        .unwrap();
+
+                    self.builder
+                        .build_conditional_branch(is_zero, zero_block, checked_block)
+                        .unwrap();
+
+                    self.builder.position_at_end(zero_block);
+
+                    utils::build_zero_step(self.module, self.context, self.builder, *line);
+
+                    self.builder.position_at_end(checked_block);
+                }
+
+                let cond_block: BasicBlock<'ctx> = self.context.append_basic_block(function, "");
+                let body_block: BasicBlock<'ctx> = self.context.append_basic_block(function, "");
+                let step_block: BasicBlock<'ctx> = self.context.append_basic_block(function, "");
+                let next_block: BasicBlock<'ctx> = self.context.append_basic_block(function, "");
+                let exit_block: BasicBlock<'ctx> = self.context.append_basic_block(function, "");
+
+                self.builder.build_unconditional_branch(cond_block).unwrap();
+
+                self.builder.position_at_end(cond_block);
+
+                let (ascending, descending) = if *inclusive {
+                    (IntPredicate::SLE, IntPredicate::SGE)
+                } else {
+                    (IntPredicate::SLT, IntPredicate::SGT)
+                };
+
+                let is_ascending: IntValue<'ctx> = self
+                    .builder
+                    .build_int_compare(ascending, start, end, "")
+                    .unwrap();
+
+                let is_descending: IntValue<'ctx> = self
+                    .builder
+                    .build_int_compare(descending, start, end, "")
+                    .unwrap();
+
+                let negative_step: IntValue<'ctx> = self
+                    .builder
+                    .build_int_compare(IntPredicate::SLT, step, llvm_kind.const_zero(), "")
+                    .unwrap();
+
+                let cond: IntValue<'ctx> = self
+                    .builder
+                    .build_select(negative_step, is_descending, is_ascending, "")
+                    .unwrap()
+                    .into_int_value();
+
+                self.builder
+                    .build_conditional_branch(cond, body_block, exit_block)
+                    .unwrap();
+
+                self.builder.position_at_end(body_block);
+
+                self.loops.push((*label, exit_block, step_block));
+                self.codegen(block);
+                self.loops.pop();
+
+                self.builder.build_unconditional_branch(step_block).unwrap();
+
+                self.builder.position_at_end(step_block);
+
+                let current: IntValue<'ctx> = self
+                    .builder
+                    .build_load(llvm_kind, counter, "")
+                    .unwrap()
+                    .into_int_value();
+
+                /*
+                    The distance to the end is checked before of the increment, so the counter
+                    never goes past the end. An inclusive range up to the maximum of its type
+                    would overflow on the increment and never end otherwise.
+
+                    The counter is between the start and the end, so the distance and the size
+                    of the step always fit as unsigned integers.
+                */
+
+                let ascending_distance: IntValue<'ctx> =
+                    self.builder.build_int_sub(end, current, "").unwrap();
+
+                let descending_distance: IntValue<'ctx> =
+                    self.builder.build_int_sub(current, end, "").unwrap();
+
+                let distance: IntValue<'ctx> = self
+                    .builder
+                    .build_select(negative_step, descending_distance, ascending_distance, "")
+                    .unwrap()
+                    .into_int_value();
+
+                let negated_step: IntValue<'ctx> = self.builder.build_int_neg(step, "").unwrap();
+
+                let step_size: IntValue<'ctx> = self
+                    .builder
+                    .build_select(negative_step, negated_step, step, "")
+                    .unwrap()
+                    .into_int_value();
+
+                let has_next: IntValue<'ctx> = self
+                    .builder
+                    .build_int_compare(
+                        if *inclusive {
+                            IntPredicate::UGE
+                        } else {
+                            IntPredicate::UGT
+                        },
+                        distance,
+                        step_size,
+                        "",
+                    )
+                    .unwrap();
+
+                self.builder
+                    .build_conditional_branch(has_next, next_block, exit_block)
+                    .unwrap();
+
+                self.builder.position_at_end(next_block);
+
+                let next: IntValue<'ctx> = self.builder.build_int_add(current, step, "").unwrap();
+
+                self.builder.build_store(counter, next).unwrap();
+
+                self.builder.build_unconditional_branch(body_block).unwrap();
+
+                self.builder.position_at_end(exit_block);
+
+                self.objects.pop();
+
+                Instruction::Null
+            }
+
+            Instruction::ForEach {
+                variable,
+                kind,
+                iterable,
+                block,
+                label,
+            } => {
+                let function: FunctionValue<'ctx> = self.function.unwrap();
+
+                let collection: PointerValue<'ctx> = match iterable.as_ref() {
+                    Instruction::RefVar { name, .. } => self.objects.find_and_get(name).unwrap(),
+                    Instruction::String(str, _) => {
+                        utils::build_dynamic_string(self.module, self.builder, self.context, str)
+                    }
+                    _ => unreachable!(),
+                };
+
+                let size: IntValue<'ctx> = self
+                    .builder
+                    .build_call(
+                        self.module.get_function("Vec.size").unwrap(),
+                        &[collection.into()],
+                        "",
+                    )
+                    .unwrap()
+                    .try_as_basic_value()
+                    .unwrap_left()
+                    .into_int_value();
+
+                self.objects.push();
+
+                let index: PointerValue<'ctx> =
+                    utils::build_alloca_int(self.builder, self.context.i64_type());
+
+                self.builder
+                    .build_store(index, self.context.i64_type().const_zero())
+                    .unwrap();
+
+                let element: PointerValue<'ctx> =
+                    utils::build_ptr(self.context, self.builder, *kind);
+
+                self.objects.insert(variable.to_string(), element);
+
+                let cond_block: BasicBlock<'ctx> = self.context.append_basic_block(function, "");
+                let body_block: BasicBlock<'ctx> = self.context.append_basic_block(function, "");
+                let step_block: BasicBlock<'ctx> = self.context.append_basic_block(function, "");
+                let exit_block: BasicBlock<'ctx> = self.context.append_basic_block(function, "");
+
+                self.builder.build_unconditional_branch(cond_block).unwrap();
+
+                self.builder.position_at_end(cond_block);
+
+                let current: IntValue<'ctx> = self
+                    .builder
+                    .build_load(self.context.i64_type(), index, "")
+                    .unwrap()
+                    .into_int_value();
+
+                let cond: IntValue<'ctx> = self
+                    .builder
+                    .build_int_compare(IntPredicate::ULT, current, size, "")
+                    .unwrap();
+
+                self.builder
+                    .build_conditional_branch(cond, body_block, exit_block)
+                    .unwrap();
+
+                self.builder.position_at_end(body_block);
+
+                let value: BasicValueEnum<'ctx> = self
+                    .builder
+                    .build_call(
+                        self.module
+                            .get_function(utils::datatype_to_vector_getter(kind))
+                            .unwrap(),
+                        &[collection.into(), current.into()],
+                        "",
+                    )
+                    .unwrap()
+                    .try_as_basic_value()
+                    .unwrap_left();
+
+                self.builder.build_store(element, value).unwrap();
+
+                self.loops.push((*label, exit_block, step_block));
+                self.codegen(block);
+                self.loops.pop();
+
+                self.builder.build_unconditional_branch(step_block).unwrap();
+
+                self.builder.position_at_end(step_block);
+
+                let current: IntValue<'ctx> = self
+                    .builder
+                    .build_load(self.context.i64_type(), index, "")
+                    .unwrap()
+                    .into_int_value();
+
+                let next: IntValue<'ctx> = self
+                    .builder
+                    .build_int_add(current, self.context.i64_type().const_int(1, false), "")
+                    .unwrap();
+
+                self.builder.build_store(index, next).unwrap();
+
+                self.builder.build_unconditional_branch(cond_block).unwrap();
+
+                self.builder.position_at_end(exit_block);
+
+                if let Instruction::String(_, _) = iterable.as_ref() {
+                    self.builder
+                        .build_call(
+                            self.module.get_function("Vec.destroy").unwrap(),
+                            &[collection.into()],
+                            "",
+                        )
+                        .unwrap();
+
+                    self.builder.build_free(collection).unwrap();
+                }
+
+                self.objects.pop();
+
+                Instruction::Null
+            }
+
             Instruction::Function {
                 name,
                 external_name,
                 params,
                 body,
                 return_kind,
+                return_type_name,
                 is_public,
                 is_external,
             } => {
                 if let Some(body) = body {
-                    self.compile_function(name, params, body, return_kind, *is_public, false);
+                    self.compile_function(
+                        name,
+                        params,
+                        body,
+                        (return_kind, return_type_name),
+                        *is_public,
+                        false,
+                    );
                     return Instruction::Null;
                 }
 
                 if *is_external {
-                    self.compile_external_function(name, params, return_kind, external_name);
+                    self.compile_external_function(
+                        name,
+                        params,
+                        return_kind,
+                        return_type_name,
+                        external_name,
+                    );
                 }
 
                 Instruction::Null
@@ -214,12 +583,72 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                 kind,
                 value,
                 only_comptime,
+                type_name,
                 ..
             } => {
                 if *only_comptime {
                     return Instruction::Null;
                 }
 
+                if *kind == DataTypes::Enum {
+                    self.compile_enum_var(name, type_name, value);
+                    return Instruction::Null;
+                }
+
+                if *kind == DataTypes::Struct {
+                    self.compile_struct_var(name, type_name, value);
+                    return Instruction::Null;
+                }
+
+                if matches!(kind, DataTypes::Fn | DataTypes::Closure)
+                    && matches!(value.as_ref(), Instruction::Null)
+                {
+                    let ptr: PointerValue<'ctx> =
+                        utils::build_ptr(self.context, self.builder, *kind);
+
+                    self.builder
+                        .build_store(
+                            ptr,
+                            utils::datatype_to_basic_type_enum(self.context, kind).const_zero(),
+                        )
+                        .unwrap();
+
+                    self.objects.insert(name.to_string(), ptr);
+
+                    return Instruction::Null;
+                }
+
+                if matches!(kind, DataTypes::Fn | DataTypes::Closure)
+                    || matches!(
+                        value.as_ref(),
+                        Instruction::Match { .. }
+                            | Instruction::If { .. }
+                            | Instruction::Block { .. }
+                            | Instruction::IndirectCall { .. }
+                            | Instruction::MethodCall { .. }
+                            | Instruction::Property { .. }
+                            | Instruction::Propagate { .. }
+                            | Instruction::Unwrap { .. }
+                    )
+                {
+                    let value: BasicValueEnum<'ctx> = self.compile_expression(value, kind);
+
+                    if *kind == DataTypes::String {
+                        self.objects
+                            .insert(name.to_string(), value.into_pointer_value());
+
+                        return Instruction::Null;
+                    }
+
+                    let ptr: PointerValue<'ctx> =
+                        utils::build_ptr(self.context, self.builder, *kind);
+
+                    self.builder.build_store(ptr, value).unwrap();
+                    self.objects.insert(name.to_string(), ptr);
+
+                    return Instruction::Null;
+                }
+
                 variable::compile(
                     self.module,
                     self.builder,
@@ -234,7 +663,69 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                 Instruction::Null
             }
 
-            Instruction::MutVar { name, kind, value } => {
+            Instruction::MutVar {
+                name,
+                kind,
+                value,
+                type_name,
+                ..
+            } => {
+                if *kind == DataTypes::Enum {
+                    let enum_type: BasicTypeEnum<'ctx> =
+                        enums::build_enum_type(self.context, self.objects.get_enum(type_name));
+
+                    let var: PointerValue<'ctx> = self.objects.find_and_get(name).unwrap();
+
+                    self.store_enum(var, enum_type, value);
+
+                    return Instruction::Null;
+                }
+
+                if *kind == DataTypes::Struct {
+                    let var: PointerValue<'ctx> = self.objects.find_and_get(name).unwrap();
+                    let value: BasicValueEnum<'ctx> = self.compile_struct_value(value);
+
+                    self.builder.build_store(var, value).unwrap();
+
+                    return Instruction::Null;
+                }
+
+                if matches!(kind, DataTypes::Fn | DataTypes::Closure) {
+                    let var: PointerValue<'ctx> = self.objects.find_and_get(name).unwrap();
+                    let value: BasicValueEnum<'ctx> = self.compile_expression(value, kind);
+
+                    if *kind == DataTypes::Closure {
+                        let old: StructValue<'ctx> = self
+                            .builder
+                            .build_load(closures::build_closure_type(self.context), var, "")
+                            .unwrap()
+                            .into_struct_value();
+
+                        closures::compile_drop(self.builder, self.context, old);
+                    }
+
+                    self.builder.build_store(var, value).unwrap();
+
+                    return Instruction::Null;
+                }
+
+                if *kind != DataTypes::String
+                    && matches!(
+                        value.as_ref(),
+                        Instruction::MethodCall { .. }
+                            | Instruction::Property { .. }
+                            | Instruction::Propagate { .. }
+                            | Instruction::Unwrap { .. }
+                    )
+                {
+                    let var: PointerValue<'ctx> = self.objects.find_and_get(name).unwrap();
+                    let value: BasicValueEnum<'ctx> = self.compile_expression(value, kind);
+
+                    self.builder.build_store(var, value).unwrap();
+
+                    return Instruction::Null;
+                }
+
                 variable::compile_mut(
                     self.module,
                     self.builder,
@@ -303,19 +794,66 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                 self.function.unwrap(),
             )),
 
-            Instruction::EntryPoint { body } => {
-                self.function = Some(self.build_main());
+            Instruction::Match {
+                value, arms, kind, ..
+            } => match self.compile_match(value, arms, kind) {
+                Some(value) => Instruction::BasicValueEnum(value),
+                None => Instruction::Null,
+            },
 
-                self.codegen(body);
+            Instruction::Propagate {
+                value,
+                kind,
+                return_type_name,
+                deallocators,
+            } => match self.compile_propagate(value, kind, return_type_name, deallocators) {
+                Some(value) => Instruction::BasicValueEnum(value),
+                None => Instruction::Null,
+            },
+
+            Instruction::Unwrap { value, line, .. } => {
+                Instruction::BasicValueEnum(self.compile_unwrap(value, *line))
+            }
 
-                self.builder
+            Instruction::MutProperty {
+                name,
+                struct_name,
+                index,
+                value,
+                kind,
+            } => {
+                let var: PointerValue<'ctx> = self.objects.find_and_get(name).unwrap();
+
+                let field: PointerValue<'ctx> =
+                    structs::build_field_ptr(self.builder, self.context, struct_name, var, *index);
+
+                let value: BasicValueEnum<'ctx> = self.compile_expression(value, kind);
+
+                self.builder.build_store(field, value).unwrap();
+
+                Instruction::Null
+            }
+
+            Instruction::Enum { .. }
+            | Instruction::EnumValue { .. }
+            | Instruction::Struct { .. }
+            | Instruction::Null => Instruction::Null,
+
+            Instruction::EntryPoint { body } => {
+                self.function = Some(self.build_main());
+
+                self.codegen(body);
+
+                self.builder
                     .build_return(Some(&self.context.i32_type().const_int(0, false)))
                     .unwrap();
 
                 Instruction::Null
             }
 
-            Instruction::Call { name, args, kind } => {
+            Instruction::Call {
+                name, args, kind, ..
+            } => {
                 functions::compile_call(
                     self.module,
                     self.builder,
@@ -329,6 +867,55 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                 Instruction::Null
             }
 
+            Instruction::MethodCall {
+                name,
+                receiver,
+                args,
+                kind,
+                ..
+            } => {
+                functions::compile_method_call(
+                    self.module,
+                    self.builder,
+                    self.context,
+                    (name, receiver),
+                    args,
+                    kind,
+                    &self.objects,
+                );
+
+                Instruction::Null
+            }
+
+            Instruction::IndirectCall {
+                name,
+                args,
+                params,
+                kind,
+                is_closure,
+                ..
+            } => {
+                self.compile_indirect_call(name, args, params, kind, *is_closure);
+
+                Instruction::Null
+            }
+
+            Instruction::FunctionRef { .. }
+            | Instruction::Closure { .. }
+            | Instruction::StructLiteral { .. }
+            | Instruction::Property { .. } => Instruction::Null,
+
+            Instruction::ClosureFunction {
+                name,
+                params,
+                captures,
+                body,
+                return_kind,
+            } => {
+                self.compile_closure_function(name, params, captures, body, return_kind);
+                Instruction::Null
+            }
+
             e => {
                 println!("{:?}", e);
                 todo!()
@@ -403,6 +990,23 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
             return;
         }
 
+        if matches!(kind, DataTypes::Fn | DataTypes::Closure | DataTypes::Struct) {
+            let value: BasicValueEnum<'ctx> = self.compile_expression(instr, kind);
+
+            self.builder.build_return(Some(&value)).unwrap();
+
+            return;
+        }
+
+        if *kind == DataTypes::Enum {
+            let value: BasicValueEnum<'ctx> = self.compile_enum_value(instr);
+            let value: BasicValueEnum<'ctx> = self.load_enum(instr.get_type_name(), value);
+
+            self.builder.build_return(Some(&value)).unwrap();
+
+            return;
+        }
+
         if let Instruction::Integer(_, num, is_signed) = instr {
             self.builder
                 .build_return(Some(&utils::build_const_integer(
@@ -455,132 +1059,1462 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
             return;
         }
 
-        if let Instruction::Char(byte) = instr {
-            self.builder
-                .build_return(Some(&self.context.i8_type().const_int(*byte as u64, false)))
-                .unwrap();
-
-            return;
+        if let Instruction::Char(byte) = instr {
+            self.builder
+                .build_return(Some(&self.context.i8_type().const_int(*byte as u64, false)))
+                .unwrap();
+
+            return;
+        }
+
+        if let Instruction::Boolean(bool) = instr {
+            self.builder
+                .build_return(Some(
+                    &self.context.bool_type().const_int(*bool as u64, false),
+                ))
+                .unwrap();
+
+            return;
+        }
+
+        if let Instruction::RefVar { name, .. } = instr {
+            if let DataTypes::String = kind {
+                self.builder
+                    .build_return(Some(&self.objects.find_and_get(name).unwrap()))
+                    .unwrap();
+
+                return;
+            }
+
+            if kind.is_integer() {
+                let num: IntValue<'_> = self
+                    .builder
+                    .build_load(
+                        utils::datatype_integer_to_llvm_type(self.context, kind),
+                        self.objects.find_and_get(name).unwrap(),
+                        "",
+                    )
+                    .unwrap()
+                    .into_int_value();
+
+                self.builder.build_return(Some(&num)).unwrap();
+
+                return;
+            }
+
+            if kind.is_float() {
+                let num: FloatValue<'_> = self
+                    .builder
+                    .build_load(
+                        utils::datatype_float_to_llvm_type(self.context, kind),
+                        self.objects.find_and_get(name).unwrap(),
+                        "",
+                    )
+                    .unwrap()
+                    .into_float_value();
+
+                self.builder.build_return(Some(&num)).unwrap();
+
+                return;
+            }
+        }
+
+        if let Instruction::Match { .. }
+        | Instruction::If { .. }
+        | Instruction::Block { .. }
+        | Instruction::IndirectCall { .. }
+        | Instruction::MethodCall { .. }
+        | Instruction::Property { .. }
+        | Instruction::Binary { .. }
+        | Instruction::Group { .. }
+        | Instruction::Propagate { .. }
+        | Instruction::Unwrap { .. } = instr
+        {
+            let value: BasicValueEnum<'ctx> = self.compile_expression(instr, kind);
+
+            self.builder.build_return(Some(&value)).unwrap();
+
+            return;
+        }
+
+        todo!()
+    }
+
+    fn compile_external_function(
+        &mut self,
+        name: &'ctx str,
+        params: &[Instruction<'ctx>],
+        return_kind: &Option<DataTypes>,
+        return_type_name: &str,
+        external_name: &str,
+    ) {
+        let kind: FunctionType<'_> =
+            utils::datatype_to_fn_type(self.context, return_kind, return_type_name, params);
+        let function: FunctionValue<'_> =
+            self.module
+                .add_function(external_name, kind, Some(Linkage::External));
+
+        self.objects.insert_function(name, function);
+    }
+
+    fn compile_function(
+        &mut self,
+        name: &'ctx str,
+        params: &[Instruction<'ctx>],
+        body: &'ctx Instruction<'ctx>,
+        (return_kind, return_type_name): (&Option<DataTypes>, &str),
+        is_public: bool,
+        only_define: bool,
+    ) {
+        if only_define && self.module.get_function(name).is_none() {
+            let kind: FunctionType =
+                utils::datatype_to_fn_type(self.context, return_kind, return_type_name, params);
+
+            let function: FunctionValue<'_> =
+                self.module.add_function(name, kind, Some(Linkage::Common));
+
+            if !is_public {
+                function.set_linkage(Linkage::LinkerPrivate);
+            }
+
+            let mut index: usize = 0;
+
+            function.get_params().iter().for_each(|param| {
+                if let Some(Instruction::Param { name, .. }) = params.get(index) {
+                    param.set_name(name);
+                }
+
+                index += 1;
+            });
+
+            self.function = Some(function);
+
+            self.objects.insert_function(name, function);
+
+            return;
+        }
+
+        let function: FunctionValue<'ctx> = self.module.get_function(name).unwrap();
+
+        self.function = Some(function);
+
+        let entry: BasicBlock = self.context.append_basic_block(function, "");
+
+        self.builder.position_at_end(entry);
+
+        self.objects.push();
+
+        self.bind_params(function, params, 0);
+
+        self.codegen(body);
+
+        self.objects.pop();
+
+        self.finish_function(return_kind);
+    }
+
+    fn compile_closure_function(
+        &mut self,
+        name: &'ctx str,
+        params: &[Instruction<'ctx>],
+        captures: &[Capture<'ctx>],
+        body: &'ctx Instruction<'ctx>,
+        return_kind: &Option<DataTypes>,
+    ) {
+        closures::define_drop(self.module, self.builder, self.context, name, captures);
+
+        let function: FunctionValue<'ctx> = self.objects.find_and_get_function(name).unwrap();
+
+        self.function = Some(function);
+
+        let entry: BasicBlock = self.context.append_basic_block(function, "");
+
+        self.builder.position_at_end(entry);
+
+        self.objects.push();
+
+        let env: PointerValue<'ctx> = function.get_first_param().unwrap().into_pointer_value();
+
+        closures::bind_captures(self.builder, self.context, env, captures, &mut self.objects);
+
+        self.bind_params(function, params, 1);
+
+        self.codegen(body);
+
+        self.objects.pop();
+
+        self.finish_function(return_kind);
+    }
+
+    fn finish_function(&mut self, return_kind: &Option<DataTypes>) {
+        if return_kind.is_none() {
+            self.builder.build_return(None).unwrap();
+            return;
+        }
+
+        // The parser proves that every path returns, the rest of blocks come after infinite loops.
+        if self
+            .builder
+            .get_insert_block()
+            .is_some_and(|block| block.get_terminator().is_none())
+        {
+            self.builder.build_unreachable().unwrap();
+        }
+    }
+
+    fn bind_params(
+        &mut self,
+        function: FunctionValue<'ctx>,
+        params: &[Instruction<'ctx>],
+        skip: usize,
+    ) {
+        function
+            .get_param_iter()
+            .skip(skip)
+            .zip(params.iter())
+            .for_each(|(value, param)| {
+                if let Instruction::Param {
+                    name,
+                    kind,
+                    type_name,
+                    ..
+                } = param
+                {
+                    if *kind == DataTypes::String || *name == "this" {
+                        self.objects
+                            .insert(name.to_string(), value.into_pointer_value());
+
+                        return;
+                    }
+
+                    if *kind == DataTypes::Struct {
+                        let struct_type: StructType<'ctx> =
+                            structs::get_struct_type(self.context, type_name);
+
+                        let ptr: PointerValue<'ctx> =
+                            self.builder.build_alloca(struct_type, "").unwrap();
+
+                        let copy: BasicValueEnum<'ctx> = self
+                            .builder
+                            .build_load(struct_type, value.into_pointer_value(), "")
+                            .unwrap();
+
+                        self.builder.build_store(ptr, copy).unwrap();
+                        self.objects.insert(name.to_string(), ptr);
+
+                        return;
+                    }
+
+                    let ptr: PointerValue<'ctx> =
+                        utils::build_ptr(self.context, self.builder, *kind);
+
+                    self.builder.build_store(ptr, value).unwrap();
+
+                    self.objects.insert(name.to_string(), ptr);
+                }
+            });
+    }
+
+    fn compile_integer_expression(
+        &mut self,
+        instr: &'ctx Instruction<'ctx>,
+        kind: &DataTypes,
+    ) -> IntValue<'ctx> {
+        let mut value: BasicValueEnum<'ctx> = match instr {
+            Instruction::Binary {
+                left,
+                op,
+                right,
+                kind: binary_kind,
+                ..
+            } => general::compile_binary_op(
+                self.module,
+                self.builder,
+                self.context,
+                left,
+                op,
+                right,
+                binary_kind,
+                &self.objects,
+                self.function.unwrap(),
+            ),
+
+            Instruction::Group { .. } => instr.compile_group_as_binary(
+                self.module,
+                self.builder,
+                self.context,
+                &self.objects,
+                self.function.unwrap(),
+            ),
+
+            Instruction::Unary { .. } => general::compile_unary_op(
+                self.module,
+                self.builder,
+                self.context,
+                instr,
+                &self.objects,
+                self.function.unwrap(),
+            ),
+
+            Instruction::Call {
+                name,
+                args,
+                kind: call_kind,
+                ..
+            } => functions::compile_call(
+                self.module,
+                self.builder,
+                self.context,
+                name,
+                args,
+                call_kind,
+                &self.objects,
+            )
+            .unwrap(),
+
+            Instruction::Match {
+                value,
+                arms,
+                kind: match_kind,
+                ..
+            } => self.compile_match(value, arms, match_kind).unwrap(),
+
+            Instruction::Propagate {
+                value,
+                kind: propagate_kind,
+                return_type_name,
+                deallocators,
+            } => self
+                .compile_propagate(value, propagate_kind, return_type_name, deallocators)
+                .unwrap(),
+
+            Instruction::Unwrap { value, line, .. } => self.compile_unwrap(value, *line),
+
+            Instruction::If {
+                cond,
+                block,
+                otherwise,
+                kind: if_kind,
+            } => self
+                .compile_if(cond, block, otherwise.as_deref(), if_kind)
+                .unwrap(),
+
+            Instruction::IndirectCall {
+                name,
+                args,
+                params,
+                kind: call_kind,
+                is_closure,
+                ..
+            } => self
+                .compile_indirect_call(name, args, params, call_kind, *is_closure)
+                .unwrap(),
+
+            Instruction::Block { .. } => {
+                self.compile_branch(instr, &instr.get_data_type()).unwrap()
+            }
+
+            Instruction::MethodCall {
+                name,
+                receiver,
+                args,
+                kind: call_kind,
+                ..
+            } => functions::compile_method_call(
+                self.module,
+                self.builder,
+                self.context,
+                (name, receiver),
+                args,
+                call_kind,
+                &self.objects,
+            )
+            .unwrap(),
+
+            _ => compile_instr_as_basic_value_enum(
+                self.module,
+                self.builder,
+                self.context,
+                instr,
+                &[],
+                false,
+                &self.objects,
+            ),
+        };
+
+        if value.is_struct_value() {
+            let binary: &Instruction<'ctx> = match instr {
+                Instruction::Group { instr, .. } => instr,
+                instr => instr,
+            };
+
+            value = utils::build_possible_overflow(
+                self.module,
+                self.context,
+                self.builder,
+                value.into_struct_value(),
+                binary.get_binary_data_types(),
+                self.function.unwrap(),
+            );
+        }
+
+        utils::integer_autocast(
+            &instr.get_data_type(),
+            kind,
+            None,
+            value,
+            self.builder,
+            self.context,
+        )
+        .unwrap_or(value)
+        .into_int_value()
+    }
+
+    fn compile_enum_var(&mut self, name: &str, type_name: &str, value: &'ctx Instruction<'ctx>) {
+        let enum_type: BasicTypeEnum<'ctx> =
+            enums::build_enum_type(self.context, self.objects.get_enum(type_name));
+
+        let ptr: PointerValue<'ctx> = self.builder.build_alloca(enum_type, "").unwrap();
+
+        if !matches!(value, Instruction::Null) {
+            self.store_enum(ptr, enum_type, value);
+        }
+
+        self.objects.insert(name.to_string(), ptr);
+    }
+
+    fn store_enum(
+        &mut self,
+        ptr: PointerValue<'ctx>,
+        enum_type: BasicTypeEnum<'ctx>,
+        value: &'ctx Instruction<'ctx>,
+    ) {
+        let value: BasicValueEnum<'ctx> = self.compile_enum_value(value);
+
+        if enum_type.is_int_type() {
+            self.builder.build_store(ptr, value).unwrap();
+            return;
+        }
+
+        let copy: BasicValueEnum<'ctx> = self
+            .builder
+            .build_load(enum_type, value.into_pointer_value(), "")
+            .unwrap();
+
+        self.builder.build_store(ptr, copy).unwrap();
+    }
+
+    fn compile_enum_value(&mut self, instr: &'ctx Instruction<'ctx>) -> BasicValueEnum<'ctx> {
+        let variants: &'ctx EnumVariants<'ctx> = self.objects.get_enum(instr.get_type_name());
+
+        match instr {
+            Instruction::EnumValue { tag, args, .. } => {
+                if enums::is_c_like(variants) {
+                    return self.context.i32_type().const_int(*tag as u64, false).into();
+                }
+
+                let enum_type: StructType<'ctx> =
+                    enums::build_enum_type(self.context, variants).into_struct_type();
+
+                let storage: PointerValue<'ctx> = self.builder.build_alloca(enum_type, "").unwrap();
+
+                let tag_ptr: PointerValue<'ctx> = self
+                    .builder
+                    .build_struct_gep(enum_type, storage, 0, "")
+                    .unwrap();
+
+                self.builder
+                    .build_store(
+                        tag_ptr,
+                        self.context.i32_type().const_int(*tag as u64, false),
+                    )
+                    .unwrap();
+
+                let payload: &'ctx [DataTypes] = &variants[*tag as usize].1;
+                let payload_type: StructType<'ctx> =
+                    enums::build_payload_type(self.context, payload);
+
+                let payload_ptr: PointerValue<'ctx> = self
+                    .builder
+                    .build_struct_gep(enum_type, storage, 1, "")
+                    .unwrap();
+
+                args.iter()
+                    .zip(payload.iter())
+                    .enumerate()
+                    .for_each(|(index, (arg, kind))| {
+                        let value: BasicValueEnum<'ctx> = self.compile_expression(arg, kind);
+
+                        let field_ptr: PointerValue<'ctx> = self
+                            .builder
+                            .build_struct_gep(payload_type, payload_ptr, index as u32, "")
+                            .unwrap();
+
+                        self.builder.build_store(field_ptr, value).unwrap();
+                    });
+
+                storage.into()
+            }
+
+            Instruction::RefVar { name, .. } => {
+                let var: PointerValue<'ctx> = self.objects.find_and_get(name).unwrap();
+
+                if enums::is_c_like(variants) {
+                    return self
+                        .builder
+                        .build_load(self.context.i32_type(), var, "")
+                        .unwrap();
+                }
+
+                var.into()
+            }
+
+            Instruction::Match {
+                value, arms, kind, ..
+            } => self.compile_match(value, arms, kind).unwrap(),
+
+            Instruction::If {
+                cond,
+                block,
+                otherwise,
+                kind,
+            } => self
+                .compile_if(cond, block, otherwise.as_deref(), kind)
+                .unwrap(),
+
+            Instruction::Block { .. } => self.compile_branch(instr, &DataTypes::Enum).unwrap(),
+
+            Instruction::Call {
+                name, args, kind, ..
+            } => {
+                let value: BasicValueEnum<'ctx> = functions::compile_call(
+                    self.module,
+                    self.builder,
+                    self.context,
+                    name,
+                    args,
+                    kind,
+                    &self.objects,
+                )
+                .unwrap();
+
+                self.spill_enum(value)
+            }
+
+            Instruction::MethodCall {
+                name,
+                receiver,
+                args,
+                kind,
+                ..
+            } => {
+                let value: BasicValueEnum<'ctx> = functions::compile_method_call(
+                    self.module,
+                    self.builder,
+                    self.context,
+                    (name, receiver),
+                    args,
+                    kind,
+                    &self.objects,
+                )
+                .unwrap();
+
+                self.spill_enum(value)
+            }
+
+            _ => unreachable!(),
+        }
+    }
+
+    fn spill_enum(&mut self, value: BasicValueEnum<'ctx>) -> BasicValueEnum<'ctx> {
+        /*
+            The functions return the enums with payload by value, but the enum values
+            are handled through a pointer to its storage.
+        */
+
+        if !value.is_struct_value() {
+            return value;
+        }
+
+        let storage: PointerValue<'ctx> = self.builder.build_alloca(value.get_type(), "").unwrap();
+
+        self.builder.build_store(storage, value).unwrap();
+
+        storage.into()
+    }
+
+    fn load_enum(&mut self, type_name: &str, value: BasicValueEnum<'ctx>) -> BasicValueEnum<'ctx> {
+        if !value.is_pointer_value() {
+            return value;
+        }
+
+        self.builder
+            .build_load(
+                self.context.get_struct_type(type_name).unwrap(),
+                value.into_pointer_value(),
+                "",
+            )
+            .unwrap()
+    }
+
+    fn compile_propagate(
+        &mut self,
+        value: &'ctx Instruction<'ctx>,
+        kind: &DataTypes,
+        return_type_name: &str,
+        deallocators: &'ctx [Instruction<'ctx>],
+    ) -> Option<BasicValueEnum<'ctx>> {
+        /*
+            value?
+
+            The `Err` of the value is returned as the `Err` of the function, after free the live
+            objects. The execution continues with the payload of the `Ok`.
+        */
+
+        let variants: &'ctx EnumVariants<'ctx> = self.objects.get_enum(value.get_type_name());
+        let result: BasicValueEnum<'ctx> = self.compile_enum_value(value);
+
+        let enum_type: BasicTypeEnum<'ctx> = enums::build_enum_type(self.context, variants);
+        let tag: IntValue<'ctx> = self.load_enum_tag(enum_type, result);
+
+        let is_err: IntValue<'ctx> = self
+            .builder
+            .build_int_compare(
+                IntPredicate::EQ,
+                tag,
+                self.context.i32_type().const_int(1, false),
+                "",
+            )
+            .unwrap();
+
+        let function: FunctionValue<'ctx> = self.function.unwrap();
+
+        let err_block: BasicBlock<'ctx> = self.context.append_basic_block(function, "");
+        let ok_block: BasicBlock<'ctx> = self.context.append_basic_block(function, "");
+
+        self.builder
+            .build_conditional_branch(is_err, err_block, ok_block)
+            .unwrap();
+
+        self.builder.position_at_end(err_block);
+
+        deallocators.iter().for_each(|deallocator| {
+            self.codegen(deallocator);
+        });
+
+        let target_type: BasicTypeEnum<'ctx> =
+            enums::build_enum_type(self.context, self.objects.get_enum(return_type_name));
+
+        if target_type.is_int_type() {
+            self.builder
+                .build_return(Some(&self.context.i32_type().const_int(1, false)))
+                .unwrap();
+        } else {
+            let target: PointerValue<'ctx> = self.builder.build_alloca(target_type, "").unwrap();
+
+            let tag_ptr: PointerValue<'ctx> = self
+                .builder
+                .build_struct_gep(target_type, target, 0, "")
+                .unwrap();
+
+            self.builder
+                .build_store(tag_ptr, self.context.i32_type().const_int(1, false))
+                .unwrap();
+
+            if !variants[1].1.is_empty() {
+                let payload_type: StructType<'ctx> =
+                    enums::build_payload_type(self.context, &variants[1].1);
+
+                let payload_ptr: PointerValue<'ctx> = self
+                    .builder
+                    .build_struct_gep(enum_type, result.into_pointer_value(), 1, "")
+                    .unwrap();
+
+                let payload: BasicValueEnum<'ctx> = self
+                    .builder
+                    .build_load(payload_type, payload_ptr, "")
+                    .unwrap();
+
+                let target_payload_ptr: PointerValue<'ctx> = self
+                    .builder
+                    .build_struct_gep(target_type, target, 1, "")
+                    .unwrap();
+
+                self.builder
+                    .build_store(target_payload_ptr, payload)
+                    .unwrap();
+            }
+
+            let err: BasicValueEnum<'ctx> = self.load_enum(return_type_name, target.into());
+
+            self.builder.build_return(Some(&err)).unwrap();
+        }
+
+        self.builder.position_at_end(ok_block);
+
+        if *kind == DataTypes::Void {
+            return None;
+        }
+
+        Some(self.load_payload_value(enum_type, result, &variants[0].1))
+    }
+
+    fn compile_unwrap(
+        &mut self,
+        value: &'ctx Instruction<'ctx>,
+        line: usize,
+    ) -> BasicValueEnum<'ctx> {
+        let variants: &'ctx EnumVariants<'ctx> = self.objects.get_enum(value.get_type_name());
+        let optional: BasicValueEnum<'ctx> = self.compile_enum_value(value);
+
+        let enum_type: BasicTypeEnum<'ctx> = enums::build_enum_type(self.context, variants);
+        let tag: IntValue<'ctx> = self.load_enum_tag(enum_type, optional);
+
+        let is_null: IntValue<'ctx> = self
+            .builder
+            .build_int_compare(
+                IntPredicate::EQ,
+                tag,
+                self.context.i32_type().const_int(1, false),
+                "",
+            )
+            .unwrap();
+
+        let function: FunctionValue<'ctx> = self.function.unwrap();
+
+        let null_block: BasicBlock<'ctx> = self.context.append_basic_block(function, "");
+        let some_block: BasicBlock<'ctx> = self.context.append_basic_block(function, "");
+
+        self.builder
+            .build_conditional_branch(is_null, null_block, some_block)
+            .unwrap();
+
+        self.builder.position_at_end(null_block);
+
+        utils::build_null_unwrap(
+            self.module,
+            self.context,
+            self.builder,
+            value.get_type_name(),
+            line,
+        );
+
+        self.builder.position_at_end(some_block);
+
+        self.load_payload_value(enum_type, optional, &variants[0].1)
+    }
+
+    fn load_enum_tag(
+        &mut self,
+        enum_type: BasicTypeEnum<'ctx>,
+        value: BasicValueEnum<'ctx>,
+    ) -> IntValue<'ctx> {
+        if enum_type.is_int_type() {
+            return value.into_int_value();
+        }
+
+        let tag_ptr: PointerValue<'ctx> = self
+            .builder
+            .build_struct_gep(enum_type, value.into_pointer_value(), 0, "")
+            .unwrap();
+
+        self.builder
+            .build_load(self.context.i32_type(), tag_ptr, "")
+            .unwrap()
+            .into_int_value()
+    }
+
+    fn load_payload_value(
+        &mut self,
+        enum_type: BasicTypeEnum<'ctx>,
+        value: BasicValueEnum<'ctx>,
+        payload: &[DataTypes],
+    ) -> BasicValueEnum<'ctx> {
+        let payload_type: StructType<'ctx> = enums::build_payload_type(self.context, payload);
+
+        let payload_ptr: PointerValue<'ctx> = self
+            .builder
+            .build_struct_gep(enum_type, value.into_pointer_value(), 1, "")
+            .unwrap();
+
+        self.builder
+            .build_load(
+                payload_type.get_field_type_at_index(0).unwrap(),
+                payload_ptr,
+                "",
+            )
+            .unwrap()
+    }
+
+    fn compile_struct_var(&mut self, name: &str, type_name: &str, value: &'ctx Instruction<'ctx>) {
+        let struct_type: StructType<'ctx> = structs::get_struct_type(self.context, type_name);
+
+        let ptr: PointerValue<'ctx> = self.builder.build_alloca(struct_type, "").unwrap();
+
+        if matches!(value, Instruction::Null) {
+            self.builder
+                .build_store(ptr, struct_type.const_zero())
+                .unwrap();
+        } else {
+            let value: BasicValueEnum<'ctx> = self.compile_struct_value(value);
+
+            self.builder.build_store(ptr, value).unwrap();
+        }
+
+        self.objects.insert(name.to_string(), ptr);
+    }
+
+    fn compile_struct_value(&mut self, instr: &'ctx Instruction<'ctx>) -> BasicValueEnum<'ctx> {
+        let struct_type: StructType<'ctx> =
+            structs::get_struct_type(self.context, instr.get_type_name());
+
+        match instr {
+            Instruction::StructLiteral { name, fields } => {
+                let mut value: StructValue<'ctx> = struct_type.get_undef();
+
+                self.objects
+                    .get_struct(name)
+                    .iter()
+                    .zip(fields.iter())
+                    .enumerate()
+                    .for_each(|(index, (field, instr))| {
+                        let field: BasicValueEnum<'ctx> = self.compile_expression(instr, &field.1);
+
+                        value = self
+                            .builder
+                            .build_insert_value(value, field, index as u32, "")
+                            .unwrap()
+                            .into_struct_value();
+                    });
+
+                value.into()
+            }
+
+            Instruction::RefVar { name, .. } => self
+                .builder
+                .build_load(struct_type, self.objects.find_and_get(name).unwrap(), "")
+                .unwrap(),
+
+            Instruction::Property {
+                name,
+                struct_name,
+                index,
+                ..
+            } => structs::load_field(
+                self.builder,
+                self.context,
+                struct_name,
+                self.objects.find_and_get(name).unwrap(),
+                *index,
+            ),
+
+            Instruction::Call {
+                name, args, kind, ..
+            } => functions::compile_call(
+                self.module,
+                self.builder,
+                self.context,
+                name,
+                args,
+                kind,
+                &self.objects,
+            )
+            .unwrap(),
+
+            Instruction::MethodCall {
+                name,
+                receiver,
+                args,
+                kind,
+                ..
+            } => functions::compile_method_call(
+                self.module,
+                self.builder,
+                self.context,
+                (name, receiver),
+                args,
+                kind,
+                &self.objects,
+            )
+            .unwrap(),
+
+            Instruction::Match {
+                value, arms, kind, ..
+            } => self.compile_match(value, arms, kind).unwrap(),
+
+            Instruction::If {
+                cond,
+                block,
+                otherwise,
+                kind,
+            } => self
+                .compile_if(cond, block, otherwise.as_deref(), kind)
+                .unwrap(),
+
+            Instruction::Block { .. } => self.compile_branch(instr, &DataTypes::Struct).unwrap(),
+
+            Instruction::Binary {
+                left,
+                op,
+                right,
+                kind,
+                ..
+            } => general::compile_binary_op(
+                self.module,
+                self.builder,
+                self.context,
+                left,
+                op,
+                right,
+                kind,
+                &self.objects,
+                self.function.unwrap(),
+            ),
+
+            Instruction::Group { .. } => instr.compile_group_as_binary(
+                self.module,
+                self.builder,
+                self.context,
+                &self.objects,
+                self.function.unwrap(),
+            ),
+
+            _ => unreachable!(),
+        }
+    }
+
+    fn compile_block(&mut self, stmts: &'ctx [Instruction<'ctx>]) -> Option<BasicValueEnum<'ctx>> {
+        let mut value: Option<BasicValueEnum<'ctx>> = None;
+
+        self.objects.push();
+
+        stmts.iter().for_each(|instr| {
+            let compiled: Instruction<'ctx> = self.codegen(instr);
+
+            if let (Instruction::Yield { .. }, Instruction::BasicValueEnum(yielded)) =
+                (instr, compiled)
+            {
+                value = Some(yielded);
+            }
+        });
+
+        self.objects.pop();
+
+        value
+    }
+
+    fn compile_if(
+        &mut self,
+        cond: &'ctx Instruction<'ctx>,
+        block: &'ctx Instruction<'ctx>,
+        otherwise: Option<&'ctx Instruction<'ctx>>,
+        kind: &DataTypes,
+    ) -> Option<BasicValueEnum<'ctx>> {
+        let function: FunctionValue<'ctx> = self.function.unwrap();
+
+        let cond: IntValue<'ctx> = self.compile_integer_expression(cond, &DataTypes::Bool);
+
+        let then_block: BasicBlock<'ctx> = self.context.append_basic_block(function, "");
+        let else_block: BasicBlock<'ctx> = self.context.append_basic_block(function, "");
+        let merge_block: BasicBlock<'ctx> = self.context.append_basic_block(function, "");
+
+        self.builder
+            .build_conditional_branch(cond, then_block, else_block)
+            .unwrap();
+
+        let mut incoming: Vec<(BasicValueEnum<'ctx>, BasicBlock<'ctx>)> = Vec::with_capacity(2);
+
+        self.builder.position_at_end(then_block);
+
+        let value: Option<BasicValueEnum<'ctx>> = self.compile_branch(block, kind);
+        self.branch_to_merge(merge_block, value, &mut incoming);
+
+        self.builder.position_at_end(else_block);
+
+        let value: Option<BasicValueEnum<'ctx>> =
+            otherwise.and_then(|otherwise| self.compile_branch(otherwise, kind));
+
+        self.branch_to_merge(merge_block, value, &mut incoming);
+
+        self.builder.position_at_end(merge_block);
+
+        if *kind == DataTypes::Void || incoming.is_empty() {
+            return None;
+        }
+
+        let phi: PhiValue<'ctx> = self
+            .builder
+            .build_phi(incoming[0].0.get_type(), "")
+            .unwrap();
+
+        incoming.iter().for_each(|(value, block)| {
+            phi.add_incoming(&[(value as &dyn BasicValue<'ctx>, *block)]);
+        });
+
+        Some(phi.as_basic_value())
+    }
+
+    fn compile_branch(
+        &mut self,
+        body: &'ctx Instruction<'ctx>,
+        kind: &DataTypes,
+    ) -> Option<BasicValueEnum<'ctx>> {
+        match body {
+            Instruction::Block { stmts } => {
+                let value: BasicValueEnum<'ctx> = self.compile_block(stmts)?;
+                let from: DataTypes = body.get_data_type();
+
+                if from.is_integer() {
+                    return utils::integer_autocast(
+                        &from,
+                        kind,
+                        None,
+                        value,
+                        self.builder,
+                        self.context,
+                    )
+                    .or(Some(value));
+                }
+
+                if from.is_float() {
+                    return utils::float_autocast(
+                        &from,
+                        kind,
+                        None,
+                        value,
+                        self.builder,
+                        self.context,
+                    )
+                    .or(Some(value));
+                }
+
+                Some(value)
+            }
+
+            body if *kind == DataTypes::Void => {
+                self.codegen(body);
+                None
+            }
+
+            body => Some(self.compile_expression(body, kind)),
+        }
+    }
+
+    fn branch_to_merge(
+        &mut self,
+        merge_block: BasicBlock<'ctx>,
+        value: Option<BasicValueEnum<'ctx>>,
+        incoming: &mut Vec<(BasicValueEnum<'ctx>, BasicBlock<'ctx>)>,
+    ) {
+        let current_block: BasicBlock<'ctx> = self.builder.get_insert_block().unwrap();
+
+        if current_block.get_terminator().is_some() {
+            return;
+        }
+
+        if let Some(value) = value {
+            incoming.push((value, current_block));
         }
 
-        if let Instruction::Boolean(bool) = instr {
+        self.builder
+            .build_unconditional_branch(merge_block)
+            .unwrap();
+    }
+
+    fn compile_match(
+        &mut self,
+        value: &'ctx Instruction<'ctx>,
+        arms: &'ctx [MatchArm<'ctx>],
+        kind: &DataTypes,
+    ) -> Option<BasicValueEnum<'ctx>> {
+        let function: FunctionValue<'ctx> = self.function.unwrap();
+        let variants: &'ctx EnumVariants<'ctx> = self.objects.get_enum(value.get_type_name());
+
+        let enum_type: BasicTypeEnum<'ctx> = enums::build_enum_type(self.context, variants);
+        let scrutinee: BasicValueEnum<'ctx> = self.compile_enum_value(value);
+
+        let tag: IntValue<'ctx> = if enums::is_c_like(variants) {
+            scrutinee.into_int_value()
+        } else {
+            let tag_ptr: PointerValue<'ctx> = self
+                .builder
+                .build_struct_gep(
+                    enum_type.into_struct_type(),
+                    scrutinee.into_pointer_value(),
+                    0,
+                    "",
+                )
+                .unwrap();
+
             self.builder
-                .build_return(Some(
-                    &self.context.bool_type().const_int(*bool as u64, false),
+                .build_load(self.context.i32_type(), tag_ptr, "")
+                .unwrap()
+                .into_int_value()
+        };
+
+        let result: Option<(BasicTypeEnum<'ctx>, PointerValue<'ctx>)> = match kind {
+            DataTypes::Void => None,
+            kind => {
+                let result_type: BasicTypeEnum<'ctx> = if *kind == DataTypes::Enum {
+                    enums::build_enum_value_type(
+                        self.context,
+                        self.objects.get_enum(arms[0].2.get_type_name()),
+                    )
+                } else if *kind == DataTypes::Struct {
+                    structs::get_struct_type(self.context, arms[0].2.get_type_name()).into()
+                } else {
+                    utils::datatype_to_basic_type_enum(self.context, kind)
+                };
+
+                Some((
+                    result_type,
+                    self.builder.build_alloca(result_type, "").unwrap(),
                 ))
-                .unwrap();
+            }
+        };
 
-            return;
-        }
+        // Decision tree, every variant jumps to the first arm that matches it.
 
-        if let Instruction::RefVar { name, .. } = instr {
-            if let DataTypes::String = kind {
-                self.builder
-                    .build_return(Some(&self.objects.find_and_get(name).unwrap()))
-                    .unwrap();
+        let decisions: Vec<usize> = (0..variants.len())
+            .map(|tag| {
+                arms.iter()
+                    .position(|arm| arm.0.is_none() || arm.0 == Some(tag as u32))
+                    .unwrap()
+            })
+            .collect();
+
+        let arms_blocks: Vec<BasicBlock<'ctx>> = arms
+            .iter()
+            .map(|_| self.context.append_basic_block(function, ""))
+            .collect();
+
+        let unreachable_block: BasicBlock<'ctx> = self.context.append_basic_block(function, "");
+        let exit_block: BasicBlock<'ctx> = self.context.append_basic_block(function, "");
+
+        let cases: Vec<(IntValue<'ctx>, BasicBlock<'ctx>)> = decisions
+            .iter()
+            .enumerate()
+            .map(|(tag, arm)| {
+                (
+                    self.context.i32_type().const_int(tag as u64, false),
+                    arms_blocks[*arm],
+                )
+            })
+            .collect();
 
-                return;
-            }
+        self.builder
+            .build_switch(tag, unreachable_block, &cases)
+            .unwrap();
 
-            if kind.is_integer() {
-                let num: IntValue<'_> = self
-                    .builder
-                    .build_load(
-                        utils::datatype_integer_to_llvm_type(self.context, kind),
-                        self.objects.find_and_get(name).unwrap(),
-                        "",
-                    )
-                    .unwrap()
-                    .into_int_value();
+        self.builder.position_at_end(unreachable_block);
+        self.builder.build_unreachable().unwrap();
 
-                self.builder.build_return(Some(&num)).unwrap();
+        arms.iter()
+            .zip(arms_blocks.iter())
+            .for_each(|((tag, bindings, body), block)| {
+                self.builder.position_at_end(*block);
 
-                return;
-            }
+                self.objects.push();
 
-            if kind.is_float() {
-                let num: FloatValue<'_> = self
+                if let Some(tag) = tag {
+                    if !bindings.is_empty() {
+                        self.bind_payload(
+                            enum_type,
+                            scrutinee.into_pointer_value(),
+                            &variants[*tag as usize].1,
+                            bindings,
+                        );
+                    }
+                }
+
+                let value: Option<BasicValueEnum<'ctx>> = self.compile_branch(body, kind);
+
+                if let (Some((_, result)), Some(value)) = (result, value) {
+                    self.builder.build_store(result, value).unwrap();
+                }
+
+                self.objects.pop();
+
+                if self
                     .builder
-                    .build_load(
-                        utils::datatype_float_to_llvm_type(self.context, kind),
-                        self.objects.find_and_get(name).unwrap(),
-                        "",
-                    )
+                    .get_insert_block()
                     .unwrap()
-                    .into_float_value();
-
-                self.builder.build_return(Some(&num)).unwrap();
+                    .get_terminator()
+                    .is_none()
+                {
+                    self.builder.build_unconditional_branch(exit_block).unwrap();
+                }
+            });
 
-                return;
-            }
-        }
+        self.builder.position_at_end(exit_block);
 
-        todo!()
+        result
+            .map(|(result_type, result)| self.builder.build_load(result_type, result, "").unwrap())
     }
 
-    fn compile_external_function(
+    fn bind_payload(
         &mut self,
-        name: &'ctx str,
-        params: &[Instruction<'ctx>],
-        return_kind: &Option<DataTypes>,
-        external_name: &str,
+        enum_type: BasicTypeEnum<'ctx>,
+        scrutinee: PointerValue<'ctx>,
+        payload: &[DataTypes],
+        bindings: &[Option<&'ctx str>],
     ) {
-        let kind: FunctionType<'_> = utils::datatype_to_fn_type(self.context, return_kind, params);
-        let function: FunctionValue<'_> =
-            self.module
-                .add_function(external_name, kind, Some(Linkage::External));
+        let payload_type: StructType<'ctx> = enums::build_payload_type(self.context, payload);
 
-        self.objects.insert_function(name, function);
+        let payload_ptr: PointerValue<'ctx> = self
+            .builder
+            .build_struct_gep(enum_type.into_struct_type(), scrutinee, 1, "")
+            .unwrap();
+
+        bindings
+            .iter()
+            .zip(payload.iter())
+            .enumerate()
+            .for_each(|(index, (binding, kind))| {
+                if let Some(name) = binding {
+                    let field_ptr: PointerValue<'ctx> = self
+                        .builder
+                        .build_struct_gep(payload_type, payload_ptr, index as u32, "")
+                        .unwrap();
+
+                    let field: BasicValueEnum<'ctx> = self
+                        .builder
+                        .build_load(
+                            utils::datatype_to_basic_type_enum(self.context, kind),
+                            field_ptr,
+                            "",
+                        )
+                        .unwrap();
+
+                    if *kind == DataTypes::String {
+                        self.objects
+                            .insert(name.to_string(), field.into_pointer_value());
+
+                        return;
+                    }
+
+                    let ptr: PointerValue<'ctx> =
+                        utils::build_ptr(self.context, self.builder, *kind);
+
+                    self.builder.build_store(ptr, field).unwrap();
+
+                    self.objects.insert(name.to_string(), ptr);
+                }
+            });
     }
 
-    fn compile_function(
+    fn compile_indirect_call(
         &mut self,
-        name: &'ctx str,
-        params: &[Instruction<'ctx>],
-        body: &'ctx Instruction<'ctx>,
-        return_kind: &Option<DataTypes>,
-        is_public: bool,
-        only_define: bool,
-    ) {
-        if only_define && self.module.get_function(name).is_none() {
-            let kind: FunctionType = utils::datatype_to_fn_type(self.context, return_kind, params);
+        name: &str,
+        args: &'ctx [Instruction<'ctx>],
+        params: &[DataTypes],
+        kind: &DataTypes,
+        is_closure: bool,
+    ) -> Option<BasicValueEnum<'ctx>> {
+        let function: PointerValue<'ctx>;
+        let fn_type: FunctionType<'ctx>;
+
+        let mut compiled_args: Vec<BasicMetadataValueEnum<'ctx>> =
+            Vec::with_capacity(args.len() + 1);
+
+        if is_closure {
+            // The environment of the closure is passed before the arguments.
+            let closure: StructValue<'ctx> = self
+                .builder
+                .build_load(
+                    closures::build_closure_type(self.context),
+                    self.objects.find_and_get(name).unwrap(),
+                    "",
+                )
+                .unwrap()
+                .into_struct_value();
 
-            let function: FunctionValue<'_> =
-                self.module.add_function(name, kind, Some(Linkage::Common));
+            function = self
+                .builder
+                .build_extract_value(closure, 0, "")
+                .unwrap()
+                .into_pointer_value();
 
-            if !is_public {
-                function.set_linkage(Linkage::LinkerPrivate);
-            }
+            compiled_args.push(
+                self.builder
+                    .build_extract_value(closure, 1, "")
+                    .unwrap()
+                    .into(),
+            );
 
-            let mut index: usize = 0;
+            fn_type = closures::build_closure_fn_type(self.context, kind, params);
+        } else {
+            function = self
+                .builder
+                .build_load(
+                    self.context.ptr_type(AddressSpace::default()),
+                    self.objects.find_and_get(name).unwrap(),
+                    "",
+                )
+                .unwrap()
+                .into_pointer_value();
 
-            function.get_params().iter().for_each(|param| {
-                if let Some(Instruction::Param { name, .. }) = params.get(index) {
-                    param.set_name(name);
-                }
+            fn_type = utils::signature_to_fn_type(self.context, kind, params);
+        }
 
-                index += 1;
-            });
+        args.iter().zip(params.iter()).for_each(|(arg, param)| {
+            let value: BasicValueEnum<'ctx> = self.compile_argument(arg, param);
+            compiled_args.push(value.into());
+        });
 
-            self.function = Some(function);
+        let call: CallSiteValue<'ctx> = self
+            .builder
+            .build_indirect_call(fn_type, function, &compiled_args, "")
+            .unwrap();
 
-            self.objects.insert_function(name, function);
+        if *kind != DataTypes::Void {
+            return Some(call.try_as_basic_value().unwrap_left());
+        }
 
-            return;
+        None
+    }
+
+    fn compile_argument(
+        &mut self,
+        arg: &'ctx Instruction<'ctx>,
+        kind: &DataTypes,
+    ) -> BasicValueEnum<'ctx> {
+        // The structs are passed by reference and the string literals as constants.
+        if matches!(kind, DataTypes::Struct | DataTypes::String) {
+            return compile_instr_as_basic_value_enum(
+                self.module,
+                self.builder,
+                self.context,
+                arg,
+                &[],
+                arg.is_var(),
+                &self.objects,
+            );
         }
 
-        let function: FunctionValue<'ctx> = self.module.get_function(name).unwrap();
+        self.compile_expression(arg, kind)
+    }
 
-        // self.function = Some(function);
+    fn compile_expression(
+        &mut self,
+        instr: &'ctx Instruction<'ctx>,
+        kind: &DataTypes,
+    ) -> BasicValueEnum<'ctx> {
+        match kind {
+            DataTypes::Enum => self.compile_enum_value(instr),
+            DataTypes::Struct => self.compile_struct_value(instr),
+            kind if kind.is_integer() => self.compile_integer_expression(instr, kind).into(),
+
+            _ => match instr {
+                Instruction::Match {
+                    value,
+                    arms,
+                    kind: match_kind,
+                    ..
+                } => self.compile_match(value, arms, match_kind).unwrap(),
 
-        let entry: BasicBlock = self.context.append_basic_block(function, "");
+                Instruction::Propagate {
+                    value,
+                    kind: propagate_kind,
+                    return_type_name,
+                    deallocators,
+                } => self
+                    .compile_propagate(value, propagate_kind, return_type_name, deallocators)
+                    .unwrap(),
+
+                Instruction::Unwrap { value, line, .. } => self.compile_unwrap(value, *line),
+
+                Instruction::If {
+                    cond,
+                    block,
+                    otherwise,
+                    kind: if_kind,
+                } => self
+                    .compile_if(cond, block, otherwise.as_deref(), if_kind)
+                    .unwrap(),
+
+                Instruction::Block { .. } => self.compile_branch(instr, kind).unwrap(),
+
+                Instruction::IndirectCall {
+                    name,
+                    args,
+                    params,
+                    kind: call_kind,
+                    is_closure,
+                    ..
+                } => self
+                    .compile_indirect_call(name, args, params, call_kind, *is_closure)
+                    .unwrap(),
+
+                Instruction::Binary {
+                    left,
+                    op,
+                    right,
+                    kind: binary_kind,
+                    ..
+                } => general::compile_binary_op(
+                    self.module,
+                    self.builder,
+                    self.context,
+                    left,
+                    op,
+                    right,
+                    binary_kind,
+                    &self.objects,
+                    self.function.unwrap(),
+                ),
 
-        self.builder.position_at_end(entry);
+                Instruction::Group { .. } => instr.compile_group_as_binary(
+                    self.module,
+                    self.builder,
+                    self.context,
+                    &self.objects,
+                    self.function.unwrap(),
+                ),
 
-        self.codegen(body);
+                Instruction::Call {
+                    name,
+                    args,
+                    kind: call_kind,
+                    ..
+                } => functions::compile_call(
+                    self.module,
+                    self.builder,
+                    self.context,
+                    name,
+                    args,
+                    call_kind,
+                    &self.objects,
+                )
+                .unwrap(),
 
-        if return_kind.is_none() {
-            self.builder.build_return(None).unwrap();
+                Instruction::MethodCall {
+                    name,
+                    receiver,
+                    args,
+                    kind: call_kind,
+                    ..
+                } => functions::compile_method_call(
+                    self.module,
+                    self.builder,
+                    self.context,
+                    (name, receiver),
+                    args,
+                    call_kind,
+                    &self.objects,
+                )
+                .unwrap(),
+
+                _ => compile_instr_as_basic_value_enum(
+                    self.module,
+                    self.builder,
+                    self.context,
+                    instr,
+                    &[],
+                    true,
+                    &self.objects,
+                ),
+            },
         }
     }
 
@@ -604,6 +2538,7 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                 params,
                 body,
                 return_kind,
+                return_type_name,
                 is_public,
                 ..
             } = instr
@@ -613,12 +2548,48 @@ impl<'a, 'ctx> Codegen<'a, 'ctx> {
                         name,
                         params,
                         body.as_ref().unwrap(),
-                        return_kind,
+                        (return_kind, return_type_name),
                         *is_public,
                         true,
                     );
                 }
             }
+
+            if let Instruction::ClosureFunction {
+                name,
+                params,
+                return_kind,
+                ..
+            } = instr
+            {
+                let function: FunctionValue<'ctx> = closures::predefine_closure(
+                    self.module,
+                    self.context,
+                    name,
+                    params,
+                    return_kind,
+                );
+
+                self.objects.insert_function(name, function);
+            }
+        });
+    }
+
+    fn predefine_structs(&mut self) {
+        self.instructions.iter().for_each(|instr| {
+            if let Instruction::Struct { name, fields } = instr {
+                structs::declare_struct(self.context, name, fields);
+                self.objects.insert_struct(name, fields);
+            }
+        });
+    }
+
+    fn predefine_enums(&mut self) {
+        self.instructions.iter().for_each(|instr| {
+            if let Instruction::Enum { name, variants } = instr {
+                enums::declare_enum(self.context, name, variants);
+                self.objects.insert_enum(name, variants);
+            }
         });
     }
 
@@ -829,9 +2800,101 @@ pub fn compile_instr_as_basic_value_enum<'ctx>(
         return context.bool_type().const_int(*bool as u64, false).into();
     }
 
+    if let Instruction::FunctionRef { name, .. } = instr {
+        return objects
+            .find_and_get_function(name)
+            .unwrap()
+            .as_global_value()
+            .as_pointer_value()
+            .into();
+    }
+
+    if let Instruction::Closure { name, captures, .. } = instr {
+        return closures::compile_closure(module, builder, context, name, captures, objects);
+    }
+
+    if let Instruction::Property {
+        name,
+        struct_name,
+        index,
+        kind,
+        ..
+    } = instr
+    {
+        let var: PointerValue<'ctx> = objects.find_and_get(name).unwrap();
+
+        if *kind == DataTypes::Struct {
+            return structs::build_field_ptr(builder, context, struct_name, var, *index).into();
+        }
+
+        return structs::load_field(builder, context, struct_name, var, *index);
+    }
+
+    if let Instruction::StructLiteral { name, fields } = instr {
+        let ptr: PointerValue<'ctx> = builder
+            .build_alloca(structs::get_struct_type(context, name), "")
+            .unwrap();
+
+        objects
+            .get_struct(name)
+            .iter()
+            .zip(fields.iter())
+            .enumerate()
+            .for_each(|(index, (field, value))| {
+                let mut compiled: BasicValueEnum<'ctx> = compile_instr_as_basic_value_enum(
+                    module,
+                    builder,
+                    context,
+                    value,
+                    &[],
+                    value.is_var(),
+                    objects,
+                );
+
+                if field.1 == DataTypes::Struct {
+                    compiled = builder
+                        .build_load(
+                            structs::get_struct_type(context, field.2),
+                            compiled.into_pointer_value(),
+                            "",
+                        )
+                        .unwrap();
+                } else if field.1.is_integer() {
+                    compiled = utils::integer_autocast(
+                        &value.get_data_type(),
+                        &field.1,
+                        None,
+                        compiled,
+                        builder,
+                        context,
+                    )
+                    .unwrap_or(compiled);
+                }
+
+                builder
+                    .build_store(
+                        structs::build_field_ptr(builder, context, name, ptr, index as u32),
+                        compiled,
+                    )
+                    .unwrap();
+            });
+
+        return ptr.into();
+    }
+
     if let Instruction::RefVar { name, kind, .. } = instr {
         let var: PointerValue<'ctx> = objects.find_and_get(name).unwrap();
 
+        if *kind == DataTypes::Struct {
+            return var.into();
+        }
+
+        if matches!(kind, DataTypes::Fn | DataTypes::Closure) {
+            return builder
+                .build_load(utils::datatype_to_basic_type_enum(context, kind), var, "")
+                .unwrap();
+        }
+
         if kind.is_float() {
             return builder
                 .build_load(utils::datatype_float_to_llvm_type(context, kind), var, "")
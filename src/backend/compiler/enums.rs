@@ -0,0 +1,112 @@
+use {
+    super::{super::super::frontend::lexer::DataTypes, utils},
+    inkwell::{
+        context::Context,
+        types::{BasicTypeEnum, StructType},
+        AddressSpace,
+    },
+};
+
+/*
+
+    ENUMS LAYOUT
+
+    C-like enums (without payloads) are lowered to the tag of the variant.
+
+    i32
+    ^^^
+    Tag
+
+    Enums with payloads are lowered to a tagged union, the payload is sized to the biggest variant.
+    They're also declared as a named type, used to pass them by value through the functions.
+
+    { i32, [N x i64] }
+      ^^^  ^^^^^^^^^
+     Tag - Payload
+
+*/
+
+#[inline]
+pub fn is_c_like(variants: &[(&str, Vec<DataTypes>)]) -> bool {
+    variants.iter().all(|(_, payload)| payload.is_empty())
+}
+
+pub fn declare_enum(context: &Context, name: &str, variants: &[(&str, Vec<DataTypes>)]) {
+    if is_c_like(variants) {
+        return;
+    }
+
+    context
+        .opaque_struct_type(name)
+        .set_body(&build_enum_fields(context, variants), false);
+}
+
+pub fn build_enum_type<'ctx>(
+    context: &'ctx Context,
+    variants: &[(&str, Vec<DataTypes>)],
+) -> BasicTypeEnum<'ctx> {
+    if is_c_like(variants) {
+        return context.i32_type().into();
+    }
+
+    context
+        .struct_type(&build_enum_fields(context, variants), false)
+        .into()
+}
+
+pub fn build_enum_value_type<'ctx>(
+    context: &'ctx Context,
+    variants: &[(&str, Vec<DataTypes>)],
+) -> BasicTypeEnum<'ctx> {
+    if is_c_like(variants) {
+        return context.i32_type().into();
+    }
+
+    context.ptr_type(AddressSpace::default()).into()
+}
+
+pub fn build_payload_type<'ctx>(context: &'ctx Context, payload: &[DataTypes]) -> StructType<'ctx> {
+    let fields: Vec<BasicTypeEnum<'ctx>> = payload
+        .iter()
+        .map(|kind| utils::datatype_to_basic_type_enum(context, kind))
+        .collect();
+
+    context.struct_type(&fields, false)
+}
+
+fn build_enum_fields<'ctx>(
+    context: &'ctx Context,
+    variants: &[(&str, Vec<DataTypes>)],
+) -> [BasicTypeEnum<'ctx>; 2] {
+    let words: u32 = variants
+        .iter()
+        .map(|(_, payload)| payload_size(payload))
+        .max()
+        .unwrap_or(0)
+        .div_ceil(8);
+
+    [
+        context.i32_type().into(),
+        context.i64_type().array_type(words).into(),
+    ]
+}
+
+fn payload_size(payload: &[DataTypes]) -> u32 {
+    payload.iter().fold(0, |size, kind| {
+        let field_size: u32 = datatype_size(kind);
+
+        size.next_multiple_of(field_size) + field_size
+    })
+}
+
+#[inline]
+fn datatype_size(kind: &DataTypes) -> u32 {
+    match kind {
+        DataTypes::I8 | DataTypes::Bool | DataTypes::Char => 1,
+        DataTypes::I16 => 2,
+        DataTypes::I32 | DataTypes::F32 => 4,
+        DataTypes::I64 | DataTypes::F64 | DataTypes::String => 8,
+
+        _ => unreachable!(),
+    }
+}
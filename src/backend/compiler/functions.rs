@@ -6,7 +6,7 @@ use {
         builder::Builder,
         context::Context,
         module::Module,
-        values::{BasicMetadataValueEnum, BasicValueEnum},
+        values::{BasicMetadataValueEnum, BasicValueEnum, CallSiteValue},
     },
 };
 
@@ -60,3 +60,46 @@ pub fn compile_call<'ctx>(
         None
     }
 }
+
+pub fn compile_method_call<'ctx>(
+    module: &Module<'ctx>,
+    builder: &Builder<'ctx>,
+    context: &'ctx Context,
+    (name, receiver): (&str, &str),
+    args: &'ctx [Instruction<'ctx>],
+    kind: &DataTypes,
+    objects: &CompilerObjects<'ctx>,
+) -> Option<BasicValueEnum<'ctx>> {
+    let mut compiled_args: Vec<BasicMetadataValueEnum> = Vec::with_capacity(args.len() + 1);
+
+    compiled_args.push(objects.find_and_get(receiver).unwrap().into());
+
+    args.iter().for_each(|arg| {
+        compiled_args.push(
+            codegen::compile_instr_as_basic_value_enum(
+                module,
+                builder,
+                context,
+                arg,
+                &[],
+                arg.is_var(),
+                objects,
+            )
+            .into(),
+        );
+    });
+
+    let call: CallSiteValue<'ctx> = builder
+        .build_call(
+            objects.find_and_get_function(name).unwrap(),
+            &compiled_args,
+            "",
+        )
+        .unwrap();
+
+    if *kind != DataTypes::Void {
+        return Some(call.try_as_basic_value().unwrap_left());
+    }
+
+    None
+}
@@ -3,6 +3,7 @@
 use {
     super::{
         super::super::frontend::lexer::{DataTypes, TokenKind},
+        codegen, functions,
         objects::CompilerObjects,
         utils, Instruction,
     },
@@ -10,7 +11,11 @@ use {
         builder::Builder,
         context::Context,
         module::Module,
-        values::{BasicValueEnum, FloatValue, FunctionValue, IntValue, PointerValue, StructValue},
+        types::BasicMetadataTypeEnum,
+        values::{
+            BasicMetadataValueEnum, BasicValueEnum, FloatValue, FunctionValue, IntValue,
+            PointerValue, StructValue,
+        },
     },
 };
 
@@ -26,6 +31,13 @@ pub fn compile_binary_op<'ctx>(
     function: FunctionValue<'ctx>,
 ) -> BasicValueEnum<'ctx> {
     match (left, op, right, kind) {
+        (left, _, right, _)
+            if left.get_data_type() == DataTypes::Struct
+                || right.get_data_type() == DataTypes::Struct =>
+        {
+            compile_overloaded_op(module, builder, context, left, op, right, objects, function)
+        }
+
         (
             Instruction::Integer(left_kind, left_num, signed_one),
             TokenKind::Plus | TokenKind::Minus | TokenKind::Star | TokenKind::Slash,
@@ -738,6 +750,113 @@ pub fn compile_binary_op<'ctx>(
             }
         }
 
+        (left, _, right, _)
+            if matches!(
+                left,
+                Instruction::Property { .. } | Instruction::MethodCall { .. }
+            ) || matches!(
+                right,
+                Instruction::Property { .. } | Instruction::MethodCall { .. }
+            ) =>
+        {
+            let left_kind: DataTypes = left.get_data_type();
+            let right_kind: DataTypes = right.get_data_type();
+
+            let target: DataTypes = match op {
+                TokenKind::And | TokenKind::Or => DataTypes::Bool,
+                _ if *kind != DataTypes::Bool => *kind,
+                _ if left_kind.is_integer() && right_kind.is_integer() => {
+                    left_kind.max_integer_datatype(right_kind)
+                }
+                _ if left_kind == DataTypes::F64 || right_kind == DataTypes::F64 => DataTypes::F64,
+                _ => left_kind,
+            };
+
+            let left: BasicValueEnum<'ctx> =
+                compile_operand(module, builder, context, left, &target, objects, function);
+
+            let right: BasicValueEnum<'ctx> =
+                compile_operand(module, builder, context, right, &target, objects, function);
+
+            match op {
+                TokenKind::And => builder
+                    .build_and(left.into_int_value(), right.into_int_value(), "")
+                    .unwrap()
+                    .into(),
+                TokenKind::Or => builder
+                    .build_or(left.into_int_value(), right.into_int_value(), "")
+                    .unwrap()
+                    .into(),
+
+                TokenKind::EqEq
+                | TokenKind::BangEq
+                | TokenKind::Less
+                | TokenKind::Greater
+                | TokenKind::GreaterEq
+                | TokenKind::LessEq
+                    if left.is_float_value() =>
+                {
+                    builder
+                        .build_float_compare(
+                            op.as_float_predicate(),
+                            left.into_float_value(),
+                            right.into_float_value(),
+                            "",
+                        )
+                        .unwrap()
+                        .into()
+                }
+
+                TokenKind::EqEq
+                | TokenKind::BangEq
+                | TokenKind::Less
+                | TokenKind::Greater
+                | TokenKind::GreaterEq
+                | TokenKind::LessEq => builder
+                    .build_int_compare(
+                        op.as_int_predicate(target.is_signed(), target.is_signed()),
+                        left.into_int_value(),
+                        right.into_int_value(),
+                        "",
+                    )
+                    .unwrap()
+                    .into(),
+
+                TokenKind::Plus if left.is_float_value() => builder
+                    .build_float_add(left.into_float_value(), right.into_float_value(), "")
+                    .unwrap()
+                    .into(),
+                TokenKind::Minus if left.is_float_value() => builder
+                    .build_float_sub(left.into_float_value(), right.into_float_value(), "")
+                    .unwrap()
+                    .into(),
+                TokenKind::Star if left.is_float_value() => builder
+                    .build_float_mul(left.into_float_value(), right.into_float_value(), "")
+                    .unwrap()
+                    .into(),
+                TokenKind::Slash if left.is_float_value() => builder
+                    .build_float_div(left.into_float_value(), right.into_float_value(), "")
+                    .unwrap()
+                    .into(),
+
+                TokenKind::Slash => builder
+                    .build_int_signed_div(left.into_int_value(), right.into_int_value(), "")
+                    .unwrap()
+                    .into(),
+
+                TokenKind::Plus | TokenKind::Minus | TokenKind::Star => utils::build_overflow(
+                    module,
+                    builder,
+                    &target,
+                    op,
+                    left.into_int_value(),
+                    right.into_int_value(),
+                ),
+
+                _ => unreachable!(),
+            }
+        }
+
         a => {
             println!("{:#?}", a);
             todo!()
@@ -745,6 +864,221 @@ pub fn compile_binary_op<'ctx>(
     }
 }
 
+fn compile_operand<'ctx>(
+    module: &Module<'ctx>,
+    builder: &Builder<'ctx>,
+    context: &'ctx Context,
+    instr: &'ctx Instruction<'ctx>,
+    target: &DataTypes,
+    objects: &CompilerObjects<'ctx>,
+    function: FunctionValue<'ctx>,
+) -> BasicValueEnum<'ctx> {
+    let mut value: BasicValueEnum<'ctx> = match instr {
+        Instruction::Binary {
+            left,
+            op,
+            right,
+            kind,
+            ..
+        } => compile_binary_op(
+            module, builder, context, left, op, right, kind, objects, function,
+        ),
+
+        Instruction::Group { .. } => {
+            instr.compile_group_as_binary(module, builder, context, objects, function)
+        }
+
+        Instruction::Call {
+            name, args, kind, ..
+        } => functions::compile_call(module, builder, context, name, args, kind, objects).unwrap(),
+
+        Instruction::MethodCall {
+            name,
+            receiver,
+            args,
+            kind,
+            ..
+        } => functions::compile_method_call(
+            module,
+            builder,
+            context,
+            (name, receiver),
+            args,
+            kind,
+            objects,
+        )
+        .unwrap(),
+
+        instr => codegen::compile_instr_as_basic_value_enum(
+            module,
+            builder,
+            context,
+            instr,
+            &[],
+            false,
+            objects,
+        ),
+    };
+
+    let kind: DataTypes = instr.get_data_type();
+
+    if value.is_struct_value() && kind != DataTypes::Struct {
+        let binary: &Instruction<'ctx> = match instr {
+            Instruction::Group { instr, .. } => instr,
+            instr => instr,
+        };
+
+        value = utils::build_possible_overflow(
+            module,
+            context,
+            builder,
+            value.into_struct_value(),
+            binary.get_binary_data_types(),
+            function,
+        );
+    }
+
+    if kind.is_integer() {
+        return utils::integer_autocast(&kind, target, None, value, builder, context)
+            .unwrap_or(value);
+    }
+
+    if kind.is_float() {
+        return utils::float_autocast(&kind, target, None, value, builder, context)
+            .unwrap_or(value);
+    }
+
+    value
+}
+
+fn compile_overloaded_op<'ctx>(
+    module: &Module<'ctx>,
+    builder: &Builder<'ctx>,
+    context: &'ctx Context,
+    left: &'ctx Instruction<'ctx>,
+    op: &TokenKind,
+    right: &'ctx Instruction<'ctx>,
+    objects: &CompilerObjects<'ctx>,
+    function: FunctionValue<'ctx>,
+) -> BasicValueEnum<'ctx> {
+    // The struct operand is the receiver of the operator method, see `Parser::operator_overload`.
+    let reflected: bool = left.get_data_type() != DataTypes::Struct;
+
+    let (receiver, method): (&'ctx Instruction<'ctx>, &str) = if reflected {
+        (right, op.as_reflected_operator_method().unwrap())
+    } else {
+        (left, op.as_operator_method().unwrap())
+    };
+
+    let operator: FunctionValue<'ctx> = objects
+        .find_and_get_function(&format!("{}::{}", receiver.get_type_name(), method))
+        .unwrap();
+
+    let param: BasicMetadataTypeEnum<'ctx> = operator.get_type().get_param_types()[1].into();
+
+    // The operands are evaluated in the order of the source, even when the call is reflected.
+    let left: BasicMetadataValueEnum<'ctx> =
+        compile_overloaded_operand(module, builder, context, left, param, objects, function);
+
+    let right: BasicMetadataValueEnum<'ctx> =
+        compile_overloaded_operand(module, builder, context, right, param, objects, function);
+
+    let (receiver, other): (BasicMetadataValueEnum<'ctx>, BasicMetadataValueEnum<'ctx>) =
+        if reflected {
+            (right, left)
+        } else {
+            (left, right)
+        };
+
+    builder
+        .build_call(operator, &[receiver, other], "")
+        .unwrap()
+        .try_as_basic_value()
+        .unwrap_left()
+}
+
+fn compile_overloaded_operand<'ctx>(
+    module: &Module<'ctx>,
+    builder: &Builder<'ctx>,
+    context: &'ctx Context,
+    instr: &'ctx Instruction<'ctx>,
+    param: BasicMetadataTypeEnum<'ctx>,
+    objects: &CompilerObjects<'ctx>,
+    function: FunctionValue<'ctx>,
+) -> BasicMetadataValueEnum<'ctx> {
+    let kind: DataTypes = instr.get_data_type();
+
+    if kind == DataTypes::Struct {
+        return compile_struct_operand(module, builder, context, instr, objects, function).into();
+    }
+
+    let value: BasicValueEnum<'ctx> =
+        compile_operand(module, builder, context, instr, &kind, objects, function);
+
+    if kind.is_integer() {
+        builder
+            .build_int_cast_sign_flag(
+                value.into_int_value(),
+                param.into_int_type(),
+                kind.is_signed(),
+                "",
+            )
+            .unwrap()
+            .into()
+    } else if kind.is_float() {
+        builder
+            .build_float_cast(value.into_float_value(), param.into_float_type(), "")
+            .unwrap()
+            .into()
+    } else {
+        value.into()
+    }
+}
+
+fn compile_struct_operand<'ctx>(
+    module: &Module<'ctx>,
+    builder: &Builder<'ctx>,
+    context: &'ctx Context,
+    instr: &'ctx Instruction<'ctx>,
+    objects: &CompilerObjects<'ctx>,
+    function: FunctionValue<'ctx>,
+) -> PointerValue<'ctx> {
+    match instr {
+        Instruction::RefVar { name, .. } => objects.find_and_get(name).unwrap(),
+
+        Instruction::Property { .. } | Instruction::StructLiteral { .. } => {
+            codegen::compile_instr_as_basic_value_enum(
+                module,
+                builder,
+                context,
+                instr,
+                &[],
+                false,
+                objects,
+            )
+            .into_pointer_value()
+        }
+
+        instr => {
+            let value: BasicValueEnum<'ctx> = compile_operand(
+                module,
+                builder,
+                context,
+                instr,
+                &DataTypes::Struct,
+                objects,
+                function,
+            );
+
+            let ptr: PointerValue<'ctx> = builder.build_alloca(value.get_type(), "").unwrap();
+
+            builder.build_store(ptr, value).unwrap();
+
+            ptr
+        }
+    }
+}
+
 pub fn compile_unary_op<'ctx>(
     module: &Module<'ctx>,
     builder: &Builder<'ctx>,
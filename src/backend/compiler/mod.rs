@@ -1,8 +1,11 @@
+pub mod closures;
 pub mod codegen;
+pub mod enums;
 pub mod functions;
 pub mod general;
 pub mod objects;
 pub mod options;
+pub mod structs;
 pub mod utils;
 pub mod variable;
 
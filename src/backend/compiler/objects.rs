@@ -1,4 +1,5 @@
 use {
+    super::super::instruction::{EnumVariants, StructFields},
     ahash::AHashMap as HashMap,
     inkwell::values::{FunctionValue, PointerValue},
 };
@@ -7,14 +8,24 @@ use {
 pub struct CompilerObjects<'ctx> {
     pub functions: HashMap<&'ctx str, FunctionValue<'ctx>>,
     pub blocks: Vec<HashMap<String, PointerValue<'ctx>>>,
+    pub enums: HashMap<&'ctx str, &'ctx EnumVariants<'ctx>>,
+    pub structs: HashMap<&'ctx str, &'ctx StructFields<'ctx>>,
     pub scope: usize,
 }
 
+impl<'ctx> Default for CompilerObjects<'ctx> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'ctx> CompilerObjects<'ctx> {
     pub fn new() -> Self {
         Self {
             functions: HashMap::new(),
             blocks: Vec::new(),
+            enums: HashMap::new(),
+            structs: HashMap::new(),
             scope: 0,
         }
     }
@@ -41,6 +52,26 @@ impl<'ctx> CompilerObjects<'ctx> {
         self.functions.insert(name, function);
     }
 
+    #[inline]
+    pub fn insert_enum(&mut self, name: &'ctx str, variants: &'ctx EnumVariants<'ctx>) {
+        self.enums.insert(name, variants);
+    }
+
+    #[inline]
+    pub fn get_enum(&self, name: &str) -> &'ctx EnumVariants<'ctx> {
+        self.enums.get(name).unwrap()
+    }
+
+    #[inline]
+    pub fn insert_struct(&mut self, name: &'ctx str, fields: &'ctx StructFields<'ctx>) {
+        self.structs.insert(name, fields);
+    }
+
+    #[inline]
+    pub fn get_struct(&self, name: &str) -> &'ctx StructFields<'ctx> {
+        self.structs.get(name).unwrap()
+    }
+
     /*  #[inline]
         pub fn get_in_current(&self, name: &str) -> Option<&PointerValue<'ctx>> {
             self.blocks[self.scope - 1].get(name)
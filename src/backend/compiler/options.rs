@@ -1,7 +1,9 @@
 use std::path::PathBuf;
 
+use super::super::super::{frontend::lints::LintLevels, messages::Language};
+
 use inkwell::{
-    targets::{CodeModel, RelocMode, TargetMachine, TargetTriple},
+    targets::{CodeModel, RelocMode, Target, TargetMachine, TargetTriple},
     OptimizationLevel,
 };
 
@@ -72,6 +74,13 @@ impl Linking {
     }
 }
 
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum ErrorFormat {
+    #[default]
+    Human,
+    Json,
+}
+
 #[derive(Debug, Clone)]
 pub struct ThrushFile {
     pub name: String,
@@ -92,8 +101,12 @@ impl ThrushFile {
 #[derive(Debug)]
 pub struct CompilerOptions {
     pub output: String,
+    pub output_directory: PathBuf,
     pub target_triple: TargetTriple,
     pub optimization: Opt,
+    pub passes: Option<String>,
+    pub print_passes: bool,
+    pub time_passes: bool,
     pub emit_llvm_ir: bool,
     pub emit_llvm_bitcode: bool,
     pub emit_asm: bool,
@@ -107,14 +120,21 @@ pub struct CompilerOptions {
     pub code_model: CodeModel,
     pub files: Vec<ThrushFile>,
     pub args: Vec<String>,
+    pub lints: LintLevels,
+    pub error_format: ErrorFormat,
+    pub language: Language,
 }
 
 impl Default for CompilerOptions {
     fn default() -> Self {
         Self {
             output: String::new(),
+            output_directory: PathBuf::from("output"),
             target_triple: TargetMachine::get_default_triple(),
             optimization: Opt::default(),
+            passes: None,
+            print_passes: false,
+            time_passes: false,
             emit_llvm_ir: false,
             emit_llvm_bitcode: false,
 
@@ -129,6 +149,38 @@ impl Default for CompilerOptions {
             code_model: CodeModel::Default,
             files: Vec::new(),
             args: Vec::new(),
+            lints: LintLevels::default(),
+            error_format: ErrorFormat::default(),
+            language: Language::from_env(),
         }
     }
 }
+
+impl CompilerOptions {
+    #[inline]
+    pub fn passes(&self) -> &str {
+        // A custom pipeline replaces the one of the optimization level.
+        self.passes
+            .as_deref()
+            .unwrap_or(self.optimization.to_llvm_17_passes())
+    }
+
+    pub fn target_machine(&self) -> Result<TargetMachine, String> {
+        Target::from_triple(&self.target_triple)
+            .map_err(|error| error.to_string())?
+            .create_target_machine(
+                &self.target_triple,
+                "",
+                "",
+                self.optimization.to_llvm_opt(),
+                self.reloc_mode,
+                self.code_model,
+            )
+            .ok_or_else(|| {
+                format!(
+                    "The target machine for \"{}\" can't be created.",
+                    self.target_triple.as_str().to_string_lossy()
+                )
+            })
+    }
+}
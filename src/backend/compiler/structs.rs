@@ -0,0 +1,87 @@
+use {
+    super::{
+        super::{super::frontend::lexer::DataTypes, instruction::StructFields},
+        utils,
+    },
+    inkwell::{
+        builder::Builder,
+        context::Context,
+        types::{BasicTypeEnum, StructType},
+        values::{BasicValueEnum, PointerValue},
+    },
+};
+
+/*
+
+    STRUCTS LAYOUT
+
+    The structs are lowered to named structs with the fields in order of declaration.
+
+    %Point = type { i32, i32 }
+                    ^^^  ^^^
+                     x  -  y
+
+    The variables of struct type live in the stack. The structs are passed to the functions
+    by pointer and the callee copies them, except `this` that points to the receiver of the method.
+
+*/
+
+pub fn declare_struct<'ctx>(context: &'ctx Context, name: &str, fields: &StructFields) {
+    let struct_type: StructType<'ctx> = context.opaque_struct_type(name);
+
+    let fields: Vec<BasicTypeEnum<'ctx>> = fields
+        .iter()
+        .map(|field| build_field_type(context, &field.1, field.2))
+        .collect();
+
+    struct_type.set_body(&fields, false);
+}
+
+#[inline]
+pub fn get_struct_type<'ctx>(context: &'ctx Context, name: &str) -> StructType<'ctx> {
+    context.get_struct_type(name).unwrap()
+}
+
+pub fn build_field_type<'ctx>(
+    context: &'ctx Context,
+    kind: &DataTypes,
+    type_name: &str,
+) -> BasicTypeEnum<'ctx> {
+    match kind {
+        DataTypes::Struct => get_struct_type(context, type_name).into(),
+        kind => utils::datatype_to_basic_type_enum(context, kind),
+    }
+}
+
+#[inline]
+pub fn build_field_ptr<'ctx>(
+    builder: &Builder<'ctx>,
+    context: &'ctx Context,
+    struct_name: &str,
+    ptr: PointerValue<'ctx>,
+    index: u32,
+) -> PointerValue<'ctx> {
+    builder
+        .build_struct_gep(get_struct_type(context, struct_name), ptr, index, "")
+        .unwrap()
+}
+
+pub fn load_field<'ctx>(
+    builder: &Builder<'ctx>,
+    context: &'ctx Context,
+    struct_name: &str,
+    ptr: PointerValue<'ctx>,
+    index: u32,
+) -> BasicValueEnum<'ctx> {
+    let field_type: BasicTypeEnum<'ctx> = get_struct_type(context, struct_name)
+        .get_field_type_at_index(index)
+        .unwrap();
+
+    builder
+        .build_load(
+            field_type,
+            build_field_ptr(builder, context, struct_name, ptr, index),
+            "",
+        )
+        .unwrap()
+}
@@ -1,17 +1,22 @@
 use {
-    super::super::{
+    super::{
         super::{
-            diagnostic,
-            frontend::lexer::{DataTypes, TokenKind},
+            super::{
+                diagnostic,
+                frontend::lexer::{DataTypes, TokenKind},
+            },
+            instruction::Instruction,
         },
-        instruction::Instruction,
+        closures, structs,
     },
     inkwell::{
         basic_block::BasicBlock,
         builder::Builder,
         context::Context,
         module::{Linkage, Module},
-        types::{ArrayType, BasicMetadataTypeEnum, FloatType, FunctionType, IntType},
+        types::{
+            ArrayType, BasicMetadataTypeEnum, BasicTypeEnum, FloatType, FunctionType, IntType,
+        },
         values::{
             BasicValueEnum, FloatValue, FunctionValue, GlobalValue, IntValue, PointerValue,
             StructValue,
@@ -122,6 +127,7 @@ pub fn build_int_array_type_from_size(
 pub fn datatype_to_fn_type<'ctx>(
     context: &'ctx Context,
     kind: &Option<DataTypes>,
+    return_type_name: &str,
     params: &[Instruction<'_>],
 ) -> FunctionType<'ctx> {
     let mut param_types: Vec<BasicMetadataTypeEnum<'ctx>> = Vec::with_capacity(params.len());
@@ -134,22 +140,52 @@ pub fn datatype_to_fn_type<'ctx>(
         _ => unreachable!(),
     });
 
-    match kind {
-        Some(kind) => match kind {
-            DataTypes::I8 | DataTypes::Char => context.i8_type().fn_type(&param_types, true),
-            DataTypes::I16 => context.i16_type().fn_type(&param_types, true),
-            DataTypes::I32 => context.i32_type().fn_type(&param_types, true),
-            DataTypes::I64 => context.i64_type().fn_type(&param_types, true),
-            DataTypes::Void => context.void_type().fn_type(&param_types, true),
-            DataTypes::String => context
-                .ptr_type(AddressSpace::default())
-                .fn_type(&param_types, true),
-            DataTypes::Bool => context.bool_type().fn_type(&param_types, true),
-            DataTypes::F32 => context.f32_type().fn_type(&param_types, true),
-            DataTypes::F64 => context.f64_type().fn_type(&param_types, true),
-        },
+    if *kind == Some(DataTypes::Struct) {
+        return structs::get_struct_type(context, return_type_name).fn_type(&param_types, true);
+    }
 
-        None => context.void_type().fn_type(&param_types, true),
+    if *kind == Some(DataTypes::Enum) {
+        return match context.get_struct_type(return_type_name) {
+            Some(enum_type) => enum_type.fn_type(&param_types, true),
+            None => context.i32_type().fn_type(&param_types, true),
+        };
+    }
+
+    build_fn_type(context, &kind.unwrap_or(DataTypes::Void), &param_types)
+}
+
+pub fn signature_to_fn_type<'ctx>(
+    context: &'ctx Context,
+    kind: &DataTypes,
+    params: &[DataTypes],
+) -> FunctionType<'ctx> {
+    let param_types: Vec<BasicMetadataTypeEnum<'ctx>> = params
+        .iter()
+        .map(|param| datatype_to_basicmetadata_type_enum(context, param))
+        .collect();
+
+    build_fn_type(context, kind, &param_types)
+}
+
+pub fn build_fn_type<'ctx>(
+    context: &'ctx Context,
+    kind: &DataTypes,
+    param_types: &[BasicMetadataTypeEnum<'ctx>],
+) -> FunctionType<'ctx> {
+    match kind {
+        DataTypes::I8 | DataTypes::Char => context.i8_type().fn_type(param_types, true),
+        DataTypes::I16 => context.i16_type().fn_type(param_types, true),
+        DataTypes::I32 => context.i32_type().fn_type(param_types, true),
+        DataTypes::I64 => context.i64_type().fn_type(param_types, true),
+        DataTypes::Void => context.void_type().fn_type(param_types, true),
+        DataTypes::String | DataTypes::Fn => context
+            .ptr_type(AddressSpace::default())
+            .fn_type(param_types, true),
+        DataTypes::Bool => context.bool_type().fn_type(param_types, true),
+        DataTypes::F32 => context.f32_type().fn_type(param_types, true),
+        DataTypes::F64 => context.f64_type().fn_type(param_types, true),
+        DataTypes::Closure => closures::build_closure_type(context).fn_type(param_types, true),
+        DataTypes::Enum | DataTypes::Struct => unreachable!(),
     }
 }
 
@@ -164,7 +200,24 @@ pub fn datatype_to_basicmetadata_type_enum<'ctx>(
         DataTypes::I64 => context.i64_type().into(),
         DataTypes::F32 => context.f32_type().into(),
         DataTypes::F64 => context.f64_type().into(),
-        DataTypes::String => context.ptr_type(AddressSpace::default()).into(),
+        DataTypes::String | DataTypes::Fn | DataTypes::Struct => {
+            context.ptr_type(AddressSpace::default()).into()
+        }
+        DataTypes::Closure => closures::build_closure_type(context).into(),
+
+        _ => unreachable!(),
+    }
+}
+
+pub fn datatype_to_basic_type_enum<'ctx>(
+    context: &'ctx Context,
+    kind: &DataTypes,
+) -> BasicTypeEnum<'ctx> {
+    match kind {
+        kind if kind.is_integer() => datatype_integer_to_llvm_type(context, kind).into(),
+        kind if kind.is_float() => datatype_float_to_llvm_type(context, kind).into(),
+        DataTypes::String | DataTypes::Fn => context.ptr_type(AddressSpace::default()).into(),
+        DataTypes::Closure => closures::build_closure_type(context).into(),
 
         _ => unreachable!(),
     }
@@ -415,6 +468,97 @@ pub fn build_dynamic_string<'ctx>(
     string
 }
 
+pub fn build_null_unwrap<'ctx>(
+    module: &Module<'ctx>,
+    context: &'ctx Context,
+    builder: &Builder<'ctx>,
+    type_name: &str,
+    line: usize,
+) {
+    builder
+        .build_call(
+            module.get_function("panic").unwrap(),
+            &[
+                module
+                    .get_global("stderr")
+                    .unwrap()
+                    .as_pointer_value()
+                    .into(),
+                build_string_constant(module, builder, context, "%s\0").into(),
+                build_string_constant(
+                    module,
+                    builder,
+                    context,
+                    &format!(
+                        "{}
+
+Details:
+
+    ● Line: {}
+    ● Type: {}
+
+{} \n\0",
+                        diagnostic::create_panic_message("Unwrap of a Null Value"),
+                        line,
+                        type_name,
+                        diagnostic::create_help_message(
+                            "Check that the optional value is not null with `!= null` before of unwrap it."
+                        )
+                    ),
+                )
+                .into(),
+            ],
+            "",
+        )
+        .unwrap();
+
+    builder.build_unreachable().unwrap();
+}
+
+pub fn build_zero_step<'ctx>(
+    module: &Module<'ctx>,
+    context: &'ctx Context,
+    builder: &Builder<'ctx>,
+    line: usize,
+) {
+    builder
+        .build_call(
+            module.get_function("panic").unwrap(),
+            &[
+                module
+                    .get_global("stderr")
+                    .unwrap()
+                    .as_pointer_value()
+                    .into(),
+                build_string_constant(module, builder, context, "%s\0").into(),
+                build_string_constant(
+                    module,
+                    builder,
+                    context,
+                    &format!(
+                        "{}
+
+Details:
+
+    ● Line: {}
+
+{} \n\0",
+                        diagnostic::create_panic_message("Zero Step of a Range"),
+                        line,
+                        diagnostic::create_help_message(
+                            "Check that the step of the range is not zero before of the loop."
+                        )
+                    ),
+                )
+                .into(),
+            ],
+            "",
+        )
+        .unwrap();
+
+    builder.build_unreachable().unwrap();
+}
+
 pub fn build_possible_overflow<'ctx>(
     module: &Module<'ctx>,
     context: &'ctx Context,
@@ -555,6 +699,18 @@ pub fn build_overflow<'ctx>(
     }
 }
 
+#[inline]
+pub fn datatype_to_vector_getter(kind: &DataTypes) -> &str {
+    match kind {
+        DataTypes::I8 | DataTypes::Char | DataTypes::Bool => "Vec.get_i8",
+        DataTypes::I16 => "Vec.get_i16",
+        DataTypes::I32 => "Vec.get_i32",
+        DataTypes::I64 => "Vec.get_i64",
+
+        _ => unreachable!(),
+    }
+}
+
 pub fn build_ptr<'ctx>(
     context: &'ctx Context,
     builder: &Builder<'ctx>,
@@ -569,6 +725,14 @@ pub fn build_ptr<'ctx>(
             build_alloca_float(builder, datatype_float_to_llvm_type(context, &kind))
         }
 
+        DataTypes::Fn => builder
+            .build_alloca(context.ptr_type(AddressSpace::default()), "")
+            .unwrap(),
+
+        DataTypes::Closure => builder
+            .build_alloca(closures::build_closure_type(context), "")
+            .unwrap(),
+
         _ => context.ptr_type(AddressSpace::default()).const_null(),
     }
 }
@@ -277,6 +277,7 @@ fn compile_string_var<'ctx>(
         name: call_name,
         args,
         kind: kind_call,
+        ..
     } = value
     {
         objects.insert(
@@ -437,6 +438,7 @@ fn compile_integer_var<'ctx>(
         name: call_name,
         args,
         kind: kind_call,
+        ..
     } = value
     {
         let value: BasicValueEnum<'_> =
@@ -1,6 +1,6 @@
 use {
     super::{
-        super::frontend::lexer::{DataTypes, TokenKind},
+        super::frontend::lexer::{DataTypes, Span, TokenKind},
         compiler::{general, objects::CompilerObjects},
     },
     inkwell::{
@@ -11,6 +11,26 @@ use {
     },
 };
 
+/*
+
+    MATCH ARM
+
+    (Option<u32>, Vec<Option<&str>>, Instruction)
+     ^^^^^^^^^^|  ^^^^^^^^^^^^^^^^^|  ^^^^^^^^^^^
+    Variant Tag (None if is `_`) - Payload Bindings - Arm Body
+
+*/
+
+pub type MatchArm<'ctx> = (Option<u32>, Vec<Option<&'ctx str>>, Instruction<'ctx>);
+
+pub type EnumVariants<'ctx> = Vec<(&'ctx str, Vec<DataTypes>)>;
+
+// (Name, Type, Type Name, By Reference?)
+pub type Capture<'ctx> = (&'ctx str, DataTypes, &'ctx str, bool);
+
+// (Field Name, Type, Type Name)
+pub type StructFields<'ctx> = Vec<(&'ctx str, DataTypes, &'ctx str)>;
+
 #[derive(Debug, Clone, Default)]
 pub enum Instruction<'ctx> {
     BasicValueEnum(BasicValueEnum<'ctx>),
@@ -23,6 +43,41 @@ pub enum Instruction<'ctx> {
         cond: Option<Box<Instruction<'ctx>>>,
         actions: Option<Box<Instruction<'ctx>>>,
         block: Box<Instruction<'ctx>>,
+        label: Option<&'ctx str>,
+    },
+    ForRange {
+        variable: &'ctx str,
+        kind: DataTypes,
+        start: Box<Instruction<'ctx>>,
+        end: Box<Instruction<'ctx>>,
+        step: Option<Box<Instruction<'ctx>>>,
+        inclusive: bool,
+        block: Box<Instruction<'ctx>>,
+        label: Option<&'ctx str>,
+        line: usize,
+    },
+    ForEach {
+        variable: &'ctx str,
+        kind: DataTypes,
+        iterable: Box<Instruction<'ctx>>,
+        block: Box<Instruction<'ctx>>,
+        label: Option<&'ctx str>,
+    },
+    Break {
+        label: Option<&'ctx str>,
+    },
+    Continue {
+        label: Option<&'ctx str>,
+    },
+    If {
+        cond: Box<Instruction<'ctx>>,
+        block: Box<Instruction<'ctx>>,
+        otherwise: Option<Box<Instruction<'ctx>>>,
+        kind: DataTypes,
+    },
+    Yield {
+        value: Box<Instruction<'ctx>>,
+        kind: DataTypes,
     },
     Integer(DataTypes, f64, bool),
     Float(DataTypes, f64, bool),
@@ -35,6 +90,8 @@ pub enum Instruction<'ctx> {
     Param {
         name: &'ctx str,
         kind: DataTypes,
+        type_name: &'ctx str,
+        span: Span,
     },
     Function {
         name: &'ctx str,
@@ -42,26 +99,102 @@ pub enum Instruction<'ctx> {
         params: Vec<Instruction<'ctx>>,
         body: Option<Box<Instruction<'ctx>>>,
         return_kind: Option<DataTypes>,
+        return_type_name: &'ctx str,
         is_public: bool,
         is_external: bool,
     },
+    ClosureFunction {
+        name: &'ctx str,
+        params: Vec<Instruction<'ctx>>,
+        captures: Vec<Capture<'ctx>>,
+        body: Box<Instruction<'ctx>>,
+        return_kind: Option<DataTypes>,
+    },
+    Closure {
+        name: &'ctx str,
+        captures: Vec<Capture<'ctx>>,
+        type_name: &'ctx str,
+    },
     Return(Box<Instruction<'ctx>>, DataTypes),
     Var {
         name: &'ctx str,
         kind: DataTypes,
         value: Box<Instruction<'ctx>>,
         line: usize,
+        span: Span,
         only_comptime: bool,
+        type_name: &'ctx str,
     },
     RefVar {
         name: &'ctx str,
         line: usize,
+        span: Span,
         kind: DataTypes,
+        type_name: &'ctx str,
     },
     MutVar {
         name: &'ctx str,
         kind: DataTypes,
         value: Box<Instruction<'ctx>>,
+        type_name: &'ctx str,
+        line: usize,
+        span: Span,
+    },
+    Enum {
+        name: &'ctx str,
+        variants: EnumVariants<'ctx>,
+    },
+    EnumValue {
+        name: &'ctx str,
+        tag: u32,
+        args: Vec<Instruction<'ctx>>,
+    },
+    Struct {
+        name: &'ctx str,
+        fields: StructFields<'ctx>,
+    },
+    StructLiteral {
+        name: &'ctx str,
+        fields: Vec<Instruction<'ctx>>,
+    },
+    Property {
+        name: &'ctx str,
+        struct_name: &'ctx str,
+        index: u32,
+        kind: DataTypes,
+        type_name: &'ctx str,
+    },
+    MutProperty {
+        name: &'ctx str,
+        struct_name: &'ctx str,
+        index: u32,
+        value: Box<Instruction<'ctx>>,
+        kind: DataTypes,
+    },
+    MethodCall {
+        name: &'ctx str,
+        receiver: &'ctx str,
+        args: Vec<Instruction<'ctx>>,
+        kind: DataTypes,
+        type_name: &'ctx str,
+    },
+    Match {
+        value: Box<Instruction<'ctx>>,
+        type_name: &'ctx str,
+        arms: Vec<MatchArm<'ctx>>,
+        kind: DataTypes,
+    },
+    Propagate {
+        value: Box<Instruction<'ctx>>,
+        kind: DataTypes,
+        return_type_name: &'ctx str,
+        deallocators: Vec<Instruction<'ctx>>,
+    },
+    Unwrap {
+        value: Box<Instruction<'ctx>>,
+        kind: DataTypes,
+        line: usize,
+        span: Span,
     },
     Indexe {
         origin: &'ctx str,
@@ -72,6 +205,19 @@ pub enum Instruction<'ctx> {
         name: &'ctx str,
         args: Vec<Instruction<'ctx>>,
         kind: DataTypes,
+        type_name: &'ctx str,
+    },
+    IndirectCall {
+        name: &'ctx str,
+        args: Vec<Instruction<'ctx>>,
+        params: Vec<DataTypes>,
+        kind: DataTypes,
+        type_name: &'ctx str,
+        is_closure: bool,
+    },
+    FunctionRef {
+        name: &'ctx str,
+        type_name: &'ctx str,
     },
     Binary {
         left: Box<Instruction<'ctx>>,
@@ -79,12 +225,14 @@ pub enum Instruction<'ctx> {
         right: Box<Instruction<'ctx>>,
         kind: DataTypes,
         line: usize,
+        span: Span,
     },
     Unary {
         op: &'ctx TokenKind,
         value: Box<Instruction<'ctx>>,
         kind: DataTypes,
         line: usize,
+        span: Span,
     },
     Group {
         instr: Box<Instruction<'ctx>>,
@@ -94,6 +242,7 @@ pub enum Instruction<'ctx> {
         name: &'ctx str,
         free_only: bool,
         is_string: bool,
+        is_closure: bool,
     },
     Boolean(bool),
     Pass,
@@ -132,6 +281,36 @@ impl<'ctx> Instruction<'ctx> {
         false
     }
 
+    #[inline]
+    pub fn is_expression(&self) -> bool {
+        matches!(
+            self,
+            Instruction::Integer(..)
+                | Instruction::Float(..)
+                | Instruction::String(..)
+                | Instruction::Char(_)
+                | Instruction::Boolean(_)
+                | Instruction::RefVar { .. }
+                | Instruction::Indexe { .. }
+                | Instruction::Call { .. }
+                | Instruction::IndirectCall { .. }
+                | Instruction::MethodCall { .. }
+                | Instruction::StructLiteral { .. }
+                | Instruction::Property { .. }
+                | Instruction::FunctionRef { .. }
+                | Instruction::Closure { .. }
+                | Instruction::Binary { .. }
+                | Instruction::Unary { .. }
+                | Instruction::Group { .. }
+                | Instruction::EnumValue { .. }
+                | Instruction::Match { .. }
+                | Instruction::Propagate { .. }
+                | Instruction::Unwrap { .. }
+                | Instruction::If { .. }
+                | Instruction::Block { .. }
+        ) && self.get_data_type() != DataTypes::Void
+    }
+
     #[inline]
     pub fn is_var(&self) -> bool {
         if let Instruction::Var { .. } | Instruction::RefVar { .. } = self {
@@ -171,7 +350,7 @@ impl<'ctx> Instruction<'ctx> {
         false
     }
 
-    pub fn as_binary(&self) -> (&Instruction, &TokenKind, &Instruction, &DataTypes) {
+    pub fn as_binary(&self) -> (&Instruction<'_>, &TokenKind, &Instruction<'_>, &DataTypes) {
         if let Instruction::Binary {
             left,
             op,
@@ -229,7 +408,26 @@ impl<'ctx> Instruction<'ctx> {
             Instruction::Unary { value, .. } => value.get_data_type(),
             Instruction::Param { kind, .. } => *kind,
             Instruction::Call { kind, .. } => *kind,
+            Instruction::IndirectCall { kind, .. } => *kind,
+            Instruction::MethodCall { kind, .. } => *kind,
+            Instruction::StructLiteral { .. } => DataTypes::Struct,
+            Instruction::Property { kind, .. } => *kind,
+            Instruction::FunctionRef { .. } => DataTypes::Fn,
+            Instruction::Closure { .. } => DataTypes::Closure,
             Instruction::Indexe { kind, .. } => *kind,
+            Instruction::EnumValue { .. } => DataTypes::Enum,
+            Instruction::Match { kind, .. } => *kind,
+            Instruction::Propagate { kind, .. } => *kind,
+            Instruction::Unwrap { kind, .. } => *kind,
+            Instruction::If { kind, .. } => *kind,
+            Instruction::Yield { kind, .. } => *kind,
+            Instruction::Block { stmts } => stmts
+                .iter()
+                .find_map(|stmt| match stmt {
+                    Instruction::Yield { kind, .. } => Some(*kind),
+                    _ => None,
+                })
+                .unwrap_or(DataTypes::Void),
             e => {
                 println!("{:?}", e);
 
@@ -238,6 +436,46 @@ impl<'ctx> Instruction<'ctx> {
         }
     }
 
+    pub fn get_type_name(&self) -> &'ctx str {
+        match self {
+            Instruction::RefVar { type_name, .. } => type_name,
+            Instruction::EnumValue { name, .. } => name,
+            Instruction::Param { type_name, .. } => type_name,
+            Instruction::Call { type_name, .. } => type_name,
+            Instruction::IndirectCall { type_name, .. } => type_name,
+            Instruction::MethodCall { type_name, .. } => type_name,
+            Instruction::StructLiteral { name, .. } => name,
+            Instruction::Property { type_name, .. } => type_name,
+            Instruction::FunctionRef { type_name, .. } => type_name,
+            Instruction::Closure { type_name, .. } => type_name,
+            Instruction::Match {
+                kind: DataTypes::Enum | DataTypes::Struct,
+                arms,
+                ..
+            } => arms[0].2.get_type_name(),
+            Instruction::Group { instr, .. } => instr.get_type_name(),
+            Instruction::Binary {
+                left,
+                right,
+                kind: DataTypes::Struct,
+                ..
+            } => {
+                if left.get_data_type() == DataTypes::Struct {
+                    return left.get_type_name();
+                }
+
+                right.get_type_name()
+            }
+            Instruction::If { block, .. } => block.get_type_name(),
+            Instruction::Yield { value, .. } => value.get_type_name(),
+            Instruction::Block { stmts } => stmts
+                .iter()
+                .find(|stmt| matches!(stmt, Instruction::Yield { .. }))
+                .map_or("", |stmt| stmt.get_type_name()),
+            _ => "",
+        }
+    }
+
     pub fn compile_group_as_binary(
         &'ctx self,
         module: &Module<'ctx>,
@@ -1,11 +1,15 @@
 use {
-    super::{
-        backend::compiler::options::{CompilerOptions, Linking, Opt, ThrushFile},
-        constants::TARGETS,
-    },
     inkwell::targets::{CodeModel, RelocMode, TargetMachine, TargetTriple},
     std::{path::PathBuf, process},
     stylic::{style, Color, Stylize},
+    thrushc::{
+        backend::compiler::options::{CompilerOptions, ErrorFormat, Linking, Opt, ThrushFile},
+        constants::TARGETS,
+        error::ThrushErrorKind,
+        explain, fix,
+        frontend::lints::{self, LintLevel},
+        messages::{self, message, Language},
+    },
 };
 
 pub struct Cli {
@@ -20,7 +24,15 @@ impl Cli {
             args,
         };
 
-        args_parsed._parse();
+        // The messages of the command line follow `--lang` too, wherever it's among the arguments.
+        let language: Language = args_parsed
+            .args
+            .windows(2)
+            .find(|pair| matches!(pair[0].as_str(), "--lang" | "-lang"))
+            .and_then(|pair| Language::from_code(&pair[1]))
+            .unwrap_or(args_parsed.options.language);
+
+        messages::with_language(language, || args_parsed._parse());
 
         args_parsed
     }
@@ -59,6 +71,54 @@ impl Cli {
                 process::exit(0);
             }
 
+            "explain" => {
+                *index += 1;
+
+                if *index >= self.args.len() {
+                    self.report_error(&message("cli.missing_explain_code", &[]));
+                }
+
+                match ThrushErrorKind::from_code(&self.args[*index]) {
+                    Some(kind) => explain::explain(kind),
+                    None => {
+                        self.report_error(&message("cli.unknown_code", &[&self.args[*index]]));
+                    }
+                }
+
+                process::exit(0);
+            }
+
+            "fix" => {
+                *index += 1;
+
+                let dry_run: bool = self.args[*index..].iter().any(|arg| arg == "--dry-run");
+
+                self.args.retain(|arg| arg != "--dry-run");
+
+                while *index < self.args.len() {
+                    self.analyze(self.args[*index].clone(), index);
+                }
+
+                if let Some(arg) = self.options.args.first() {
+                    self.report_error(&message("cli.not_thrush_file", &[arg]));
+                }
+
+                if self.options.files.is_empty() {
+                    self.report_error(&message("cli.missing_fix_files", &[]));
+                }
+
+                let has_errors: bool = messages::with_language(self.options.language, || {
+                    fix::fix(
+                        &self.options.files,
+                        &self.options.lints,
+                        dry_run,
+                        self.options.error_format,
+                    )
+                });
+
+                process::exit(has_errors as i32);
+            }
+
             "version" | "-v" | "--version" => {
                 *index += 1;
                 println!("v{}", env!("CARGO_PKG_VERSION"));
@@ -68,7 +128,7 @@ impl Cli {
                 *index += 2;
 
                 if *index > self.args.len() {
-                    self.report_error(&format!("Missing argument for {}", arg));
+                    self.report_error(&message("cli.missing_argument", &[&arg]));
                 }
 
                 self.options.output = self.args[self.extract_relative_index(*index)].to_string();
@@ -78,7 +138,7 @@ impl Cli {
                 *index += 1;
 
                 if *index > self.args.len() {
-                    self.report_error(&format!("Missing argument for {}", arg));
+                    self.report_error(&message("cli.missing_argument", &[&arg]));
                 }
 
                 self.options.optimization =
@@ -88,7 +148,7 @@ impl Cli {
                         "O2" => Opt::Mid,
                         "mcqueen" => Opt::Mcqueen,
                         any => {
-                            self.report_error(&format!("Unknown optimization level \"{}\"", any));
+                            self.report_error(&message("cli.unknown_optimization", &[&any]));
                             Opt::default()
                         }
                     };
@@ -96,11 +156,33 @@ impl Cli {
                 *index += 1;
             }
 
+            "--passes" | "-passes" => {
+                *index += 1;
+
+                if *index >= self.args.len() {
+                    self.report_error(&message("cli.missing_passes", &[&arg]));
+                }
+
+                self.options.passes = Some(self.args[*index].to_string());
+
+                *index += 1;
+            }
+
+            "--print-passes" | "-print-passes" => {
+                *index += 1;
+                self.options.print_passes = true;
+            }
+
+            "--time-passes" | "-time-passes" => {
+                *index += 1;
+                self.options.time_passes = true;
+            }
+
             "--emit" | "-emit" => {
                 *index += 1;
 
                 if *index > self.args.len() {
-                    self.report_error(&format!("Missing argument for {}", arg));
+                    self.report_error(&message("cli.missing_argument", &[&arg]));
                 }
 
                 match self.args[self.extract_relative_index(*index)].as_str() {
@@ -108,10 +190,7 @@ impl Cli {
                     "llvm-bc" => self.options.emit_llvm_bitcode = true,
                     "asm" => self.options.emit_asm = true,
                     any => {
-                        self.report_error(&format!(
-                            "\"{}\" is invalid target to emit raw compiled code. Maybe \"(--emit || -emit) llvm-ir || llvm-bc || asm\", is the command?",
-                            any
-                        ));
+                        self.report_error(&message("cli.unknown_emit", &[&any]));
                     }
                 }
 
@@ -122,9 +201,9 @@ impl Cli {
                 *index += 1;
 
                 if self.options.executable {
-                    self.report_error(&format!(
-                        "You can't use \"{}\" and \"{}\" together.",
-                        "--executable", "--library"
+                    self.report_error(&message(
+                        "cli.incompatible_flags",
+                        &[&"--executable", &"--library"],
                     ));
                 }
 
@@ -135,9 +214,9 @@ impl Cli {
                 *index += 1;
 
                 if self.options.executable || self.options.library {
-                    self.report_error(&format!(
-                        "You can't use \"{}\" and \"{}\" together.",
-                        "--executable || --library", "--static-library"
+                    self.report_error(&message(
+                        "cli.incompatible_flags",
+                        &[&"--executable || --library", &"--static-library"],
                     ));
                 }
 
@@ -148,7 +227,7 @@ impl Cli {
                 *index += 1;
 
                 if *index > self.args.len() {
-                    self.report_error(&format!("Missing argument for {}", arg));
+                    self.report_error(&message("cli.missing_argument", &[&arg]));
                 }
 
                 match self.args[self.extract_relative_index(*index)].as_str() {
@@ -158,9 +237,9 @@ impl Cli {
                     }
 
                     _ => {
-                        self.report_error(&format!(
-                            "Invalid target: {}",
-                            self.args[self.extract_relative_index(*index)]
+                        self.report_error(&message(
+                            "cli.invalid_target",
+                            &[&self.args[self.extract_relative_index(*index)]],
                         ));
                     }
                 }
@@ -182,7 +261,7 @@ impl Cli {
                 *index += 1;
 
                 if *index > self.args.len() {
-                    self.report_error(&format!("Missing argument for \"{}\".", arg));
+                    self.report_error(&message("cli.missing_quoted_argument", &[&arg]));
                 }
 
                 self.options.reloc_mode =
@@ -200,7 +279,7 @@ impl Cli {
                 *index += 1;
 
                 if *index > self.args.len() {
-                    self.report_error(&format!("Missing argument for \"{}\".", arg));
+                    self.report_error(&message("cli.missing_quoted_argument", &[&arg]));
                 }
 
                 self.options.code_model =
@@ -219,10 +298,7 @@ impl Cli {
                 *index += 1;
 
                 if *index > self.args.len() {
-                    self.report_error(&format!(
-                        "Missing built-in API specification for \"{}\".",
-                        arg
-                    ));
+                    self.report_error(&message("cli.missing_api", &[&arg]));
                 }
 
                 match self.args[self.extract_relative_index(*index)].as_str() {
@@ -235,14 +311,78 @@ impl Cli {
                         *index += 1;
                     }
                     _ => {
-                        self.report_error(&format!(
-                            "Unknown built-in API name: \"{}\".",
-                            self.args[self.extract_relative_index(*index)]
+                        self.report_error(&message(
+                            "cli.unknown_api",
+                            &[&self.args[self.extract_relative_index(*index)]],
                         ));
                     }
                 }
             }
 
+            "-W" | "--warn" | "-D" | "--deny" => {
+                *index += 1;
+
+                if *index >= self.args.len() {
+                    self.report_error(&message("cli.missing_lint", &[&arg]));
+                }
+
+                let level: LintLevel = if matches!(arg.as_str(), "-W" | "--warn") {
+                    LintLevel::Warn
+                } else {
+                    LintLevel::Deny
+                };
+
+                match lints::resolve(self.args[*index].as_str()) {
+                    Some(lints) => self.options.lints.set(lints, level),
+                    None => {
+                        self.report_error(&message("cli.unknown_lint", &[&self.args[*index]]));
+                    }
+                }
+
+                *index += 1;
+            }
+
+            "--deny-warnings" | "-deny-warnings" => {
+                *index += 1;
+                self.options.lints.deny_warnings();
+            }
+
+            "--error-format" | "-error-format" => {
+                *index += 1;
+
+                if *index >= self.args.len() {
+                    self.report_error(&message("cli.missing_quoted_argument", &[&arg]));
+                }
+
+                self.options.error_format = self.error_format(&self.args[*index]);
+
+                *index += 1;
+            }
+
+            format if format.starts_with("--error-format=") => {
+                *index += 1;
+                self.options.error_format = self.error_format(&format["--error-format=".len()..]);
+            }
+
+            "--lang" | "-lang" => {
+                *index += 1;
+
+                if *index >= self.args.len() {
+                    self.report_error(&message("cli.missing_quoted_argument", &[&arg]));
+                }
+
+                self.options.language = match Language::from_code(&self.args[*index]) {
+                    Some(language) => language,
+                    None => {
+                        self.report_error(&message("cli.unknown_language", &[&self.args[*index]]));
+
+                        Language::default()
+                    }
+                };
+
+                *index += 1;
+            }
+
             "--executable" | "-executable" => {
                 *index += 1;
                 self.options.executable = true;
@@ -254,13 +394,13 @@ impl Cli {
                 let mut file: PathBuf = PathBuf::from(path);
 
                 if file.is_dir() {
-                    self.report_error(&format!("\"{}\" is a directory", path));
+                    self.report_error(&message("cli.directory", &[&path]));
                 } else if file.extension().is_none() {
-                    self.report_error(&format!("\"{}\" does not have extension.", path));
+                    self.report_error(&message("cli.without_extension", &[&path]));
                 } else if file.extension().unwrap() != "th" {
-                    self.report_error(&format!("\"{}\" is not a Thrush file.", path));
+                    self.report_error(&message("cli.not_thrush_file", &[&path]));
                 } else if file.file_name().is_none() {
-                    self.report_error(&format!("\"{}\" does not have a name.", path));
+                    self.report_error(&message("cli.without_name", &[&path]));
                 }
 
                 if path.chars().filter(|ch| *ch == '.').count() > 2 && file.canonicalize().is_ok() {
@@ -275,7 +415,7 @@ impl Cli {
                     .count()
                     > 1
                 {
-                    self.report_error("Compile two or more \"main.th\" file don't allowed.");
+                    self.report_error(&message("cli.several_mains", &[]));
                 }
 
                 let is_main: bool = file.file_name().unwrap().to_string_lossy().trim() == "main.th";
@@ -302,10 +442,24 @@ impl Cli {
         index
     }
 
+    fn error_format(&self, format: &str) -> ErrorFormat {
+        match format {
+            "human" => ErrorFormat::Human,
+            "json" => ErrorFormat::Json,
+            any => {
+                self.report_error(&message("cli.unknown_error_format", &[&any]));
+
+                ErrorFormat::default()
+            }
+        }
+    }
+
     fn report_error(&self, msg: &str) {
         println!(
             "{} {}",
-            style("ERROR").bold().fg(Color::Rgb(255, 51, 51)),
+            style(message("cli.error", &[]))
+                .bold()
+                .fg(Color::Rgb(255, 51, 51)),
             style(msg).bold()
         );
 
@@ -315,19 +469,19 @@ impl Cli {
     fn help(&self) {
         println!(
             "\n{}\n",
-            style("The Thrush Compiler")
+            style(message("cli.help.title", &[]))
                 .bold()
                 .fg(Color::Rgb(141, 141, 142))
         );
 
         println!(
             "{} {} {}\n",
-            style("Usage:").bold(),
+            style(message("cli.help.usage", &[])).bold(),
             style("thrushc").bold().fg(Color::Rgb(141, 141, 142)),
             style("[--flags] [file]").bold()
         );
 
-        println!("{}", style("Available Commands:\n").bold());
+        println!("{}\n", style(message("cli.help.commands", &[])).bold());
 
         println!(
             "{} ({} | {} | {}) {}",
@@ -335,7 +489,7 @@ impl Cli {
             style("help").bold().fg(Color::Rgb(141, 141, 142)),
             style("-h").bold().fg(Color::Rgb(141, 141, 142)),
             style("--help").bold().fg(Color::Rgb(141, 141, 142)),
-            style("Show help message.").bold()
+            style(message("cli.help.help", &[])).bold()
         );
 
         println!(
@@ -344,14 +498,14 @@ impl Cli {
             style("version").bold().fg(Color::Rgb(141, 141, 142)),
             style("-v").bold().fg(Color::Rgb(141, 141, 142)),
             style("--version").bold().fg(Color::Rgb(141, 141, 142)),
-            style("Show the version.").bold()
+            style(message("cli.help.version", &[])).bold()
         );
 
         println!(
             "{} ({}) {}",
             style("•").bold(),
             style("targets").bold().fg(Color::Rgb(141, 141, 142)),
-            style("Print the list of supported targets machines.").bold()
+            style(message("cli.help.targets", &[])).bold()
         );
 
         println!(
@@ -359,17 +513,33 @@ impl Cli {
             style("•").bold(),
             style("native-target").bold().fg(Color::Rgb(141, 141, 142)),
             style("-nt").bold().fg(Color::Rgb(141, 141, 142)),
-            style("Print the native target of this machine.").bold()
+            style(message("cli.help.native_target", &[])).bold()
+        );
+
+        println!(
+            "{} ({}) {}",
+            style("•").bold(),
+            style("explain [code]").bold().fg(Color::Rgb(141, 141, 142)),
+            style(message("cli.help.explain", &[])).bold()
+        );
+
+        println!(
+            "{} ({}) {}",
+            style("•").bold(),
+            style("fix [files] [--dry-run]")
+                .bold()
+                .fg(Color::Rgb(141, 141, 142)),
+            style(message("cli.help.fix", &[])).bold()
         );
 
-        println!("{}", style("\nAvailable Flags:\n").bold());
+        println!("\n{}\n", style(message("cli.help.flags", &[])).bold());
 
         println!(
             "{} ({} | {}) {}",
             style("•").bold(),
             style("--output [str]").bold().fg(Color::Rgb(141, 141, 142)),
             style("-o [str]").bold().fg(Color::Rgb(141, 141, 142)),
-            style("Output file format.").bold()
+            style(message("cli.help.output", &[])).bold()
         );
 
         println!(
@@ -377,7 +547,7 @@ impl Cli {
             style("•").bold(),
             style("--target [str]").bold().fg(Color::Rgb(141, 141, 142)),
             style("-t [str]").bold().fg(Color::Rgb(141, 141, 142)),
-            style("Target architecture for the executable or object file.").bold()
+            style(message("cli.help.target", &[])).bold()
         );
 
         println!(
@@ -389,7 +559,35 @@ impl Cli {
             style("-opt [opt-level]")
                 .bold()
                 .fg(Color::Rgb(141, 141, 142)),
-            style("Optimization level for the executable to emit or object file.").bold()
+            style(message("cli.help.optimization", &[])).bold()
+        );
+
+        println!(
+            "{} ({} | {}) {}",
+            style("•").bold(),
+            style("--passes [pipeline]")
+                .bold()
+                .fg(Color::Rgb(141, 141, 142)),
+            style("-passes [pipeline]")
+                .bold()
+                .fg(Color::Rgb(141, 141, 142)),
+            style(message("cli.help.passes", &[])).bold()
+        );
+
+        println!(
+            "{} ({} | {}) {}",
+            style("•").bold(),
+            style("--print-passes").bold().fg(Color::Rgb(141, 141, 142)),
+            style("-print-passes").bold().fg(Color::Rgb(141, 141, 142)),
+            style(message("cli.help.print_passes", &[])).bold()
+        );
+
+        println!(
+            "{} ({} | {}) {}",
+            style("•").bold(),
+            style("--time-passes").bold().fg(Color::Rgb(141, 141, 142)),
+            style("-time-passes").bold().fg(Color::Rgb(141, 141, 142)),
+            style(message("cli.help.time_passes", &[])).bold()
         );
 
         println!(
@@ -397,7 +595,7 @@ impl Cli {
             style("•").bold(),
             style("--emit").bold().fg(Color::Rgb(141, 141, 142)),
             style("-emit").bold().fg(Color::Rgb(141, 141, 142)),
-            style("Compile the code to Assembler or LLVM IR.").bold()
+            style(message("cli.help.emit", &[])).bold()
         );
 
         println!(
@@ -405,7 +603,7 @@ impl Cli {
             style("•").bold(),
             style("--include").bold().fg(Color::Rgb(141, 141, 142)),
             style("-include").bold().fg(Color::Rgb(141, 141, 142)),
-            style("Include a Native API Code in the IR.").bold()
+            style(message("cli.help.include", &[])).bold()
         );
 
         println!(
@@ -413,7 +611,7 @@ impl Cli {
             style("•").bold(),
             style("--static").bold().fg(Color::Rgb(141, 141, 142)),
             style("-s").bold().fg(Color::Rgb(141, 141, 142)),
-            style("Link the executable statically.").bold()
+            style(message("cli.help.static", &[])).bold()
         );
 
         println!(
@@ -421,7 +619,7 @@ impl Cli {
             style("•").bold(),
             style("--dynamic").bold().fg(Color::Rgb(141, 141, 142)),
             style("-d").bold().fg(Color::Rgb(141, 141, 142)),
-            style("Link the executable dynamically.").bold()
+            style(message("cli.help.dynamic", &[])).bold()
         );
 
         println!(
@@ -429,7 +627,7 @@ impl Cli {
             style("•").bold(),
             style("--executable").bold().fg(Color::Rgb(141, 141, 142)),
             style("-executable").bold().fg(Color::Rgb(141, 141, 142)),
-            style("Compile the code into native executable.").bold()
+            style(message("cli.help.executable", &[])).bold()
         );
 
         println!(
@@ -437,7 +635,7 @@ impl Cli {
             style("•").bold(),
             style("--library").bold().fg(Color::Rgb(141, 141, 142)),
             style("-lib").bold().fg(Color::Rgb(141, 141, 142)),
-            style("Compile to an object file ('*.o').").bold()
+            style(message("cli.help.library", &[])).bold()
         );
 
         println!(
@@ -447,7 +645,7 @@ impl Cli {
                 .bold()
                 .fg(Color::Rgb(141, 141, 142)),
             style("-slib").bold().fg(Color::Rgb(141, 141, 142)),
-            style("Compile to an static library ('*.a').").bold()
+            style(message("cli.help.static_library", &[])).bold()
         );
 
         println!(
@@ -459,7 +657,7 @@ impl Cli {
             style("-reloc [reloc-mode]")
                 .bold()
                 .fg(Color::Rgb(141, 141, 142)),
-            style("Indicate how references to memory addresses are handled.").bold()
+            style(message("cli.help.reloc", &[])).bold()
         );
 
         println!(
@@ -471,8 +669,57 @@ impl Cli {
             style("-codemd [model]")
                 .bold()
                 .fg(Color::Rgb(141, 141, 142)),
-            style("Define how code is organized and accessed in the executable or object file.")
+            style(message("cli.help.code_model", &[])).bold()
+        );
+
+        println!(
+            "{} ({} | {}) {}",
+            style("•").bold(),
+            style("--warn [lint]").bold().fg(Color::Rgb(141, 141, 142)),
+            style("-W [lint]").bold().fg(Color::Rgb(141, 141, 142)),
+            style(message("cli.help.warn", &[])).bold()
+        );
+
+        println!(
+            "{} ({} | {}) {}",
+            style("•").bold(),
+            style("--deny [lint]").bold().fg(Color::Rgb(141, 141, 142)),
+            style("-D [lint]").bold().fg(Color::Rgb(141, 141, 142)),
+            style(message("cli.help.deny", &[])).bold()
+        );
+
+        println!(
+            "{} ({} | {}) {}",
+            style("•").bold(),
+            style("--deny-warnings")
                 .bold()
+                .fg(Color::Rgb(141, 141, 142)),
+            style("-deny-warnings").bold().fg(Color::Rgb(141, 141, 142)),
+            style(message("cli.help.deny_warnings", &[])).bold()
+        );
+
+        println!(
+            "{} ({} | {}) {}",
+            style("•").bold(),
+            style("--error-format [human | json]")
+                .bold()
+                .fg(Color::Rgb(141, 141, 142)),
+            style("-error-format [human | json]")
+                .bold()
+                .fg(Color::Rgb(141, 141, 142)),
+            style(message("cli.help.error_format", &[])).bold()
+        );
+
+        println!(
+            "{} ({} | {}) {}",
+            style("•").bold(),
+            style("--lang [en | es]")
+                .bold()
+                .fg(Color::Rgb(141, 141, 142)),
+            style("-lang [en | es]")
+                .bold()
+                .fg(Color::Rgb(141, 141, 142)),
+            style(message("cli.help.lang", &[])).bold()
         );
 
         println!(
@@ -480,7 +727,7 @@ impl Cli {
             style("•").bold(),
             style("--args [str]").bold().fg(Color::Rgb(141, 141, 142)),
             style("-args [str]").bold().fg(Color::Rgb(141, 141, 142)),
-            style("Pass more arguments to the Backend Compiler.").bold()
+            style(message("cli.help.args", &[])).bold()
         );
 
         process::exit(1);
@@ -1,13 +1,12 @@
 use {
-    super::backend::compiler::options::ThrushFile,
+    super::backend::compiler::options::{ErrorFormat, ThrushFile},
     super::{
         error::{ThrushError, ThrushErrorKind},
-        logging::LogType,
-    },
-    std::{
-        fs::File,
-        io::{BufRead, BufReader},
+        frontend::lexer::Span,
+        logging::{self, LogType},
+        messages::message,
     },
+    std::fs,
     stylic::{style, Stylize},
 };
 
@@ -17,60 +16,407 @@ pub struct Diagnostic {
     buffer: String,
     drawer: String,
     lines: Vec<String>,
+    source: String,
+    format: ErrorFormat,
 }
 
 impl Diagnostic {
-    pub fn new(thrush_file: &ThrushFile) -> Self {
-        let file: File = File::open(&thrush_file.path).unwrap();
-        let lines: Vec<String> = BufReader::new(file)
-            .lines()
-            .map(|line| line.unwrap().to_string())
-            .collect();
+    pub fn new(thrush_file: &ThrushFile, format: ErrorFormat) -> Self {
+        Self::from_source(
+            thrush_file,
+            fs::read_to_string(&thrush_file.path).unwrap(),
+            format,
+        )
+    }
+
+    pub fn from_source(thrush_file: &ThrushFile, source: String, format: ErrorFormat) -> Self {
+        let lines: Vec<String> = source.lines().map(|line| line.to_string()).collect();
 
         Self {
             thrush_file: thrush_file.clone(),
             buffer: String::new(),
             drawer: String::new(),
             lines,
+            source,
+            format,
         }
     }
 
+    pub fn report_all(&mut self, errors: Vec<ThrushError>, warnings: Vec<ThrushError>) -> bool {
+        /*
+            The errors and the warnings of all the phases are reported together, sorted by their
+            position in the source code. An error found twice is only reported once.
+        */
+
+        let mut reports: Vec<(ThrushError, LogType)> =
+            Vec::with_capacity(errors.len() + warnings.len());
+
+        errors
+            .into_iter()
+            .map(|error| (error, LogType::ERROR))
+            .chain(warnings.into_iter().map(|warning| (warning, LogType::WARN)))
+            .for_each(|report| {
+                if report.0 != ThrushError::None && !reports.iter().any(|other| other.0 == report.0)
+                {
+                    reports.push(report);
+                }
+            });
+
+        reports.sort_by_key(|(error, _)| self.position(error));
+
+        let errors: usize = reports
+            .iter()
+            .filter(|(_, log_type)| *log_type == LogType::ERROR)
+            .count();
+
+        let warnings: usize = reports.len() - errors;
+
+        if self.format == ErrorFormat::Json {
+            reports
+                .iter()
+                .for_each(|(error, log_type)| self.emit_json(error, log_type));
+
+            return errors > 0;
+        }
+
+        reports
+            .into_iter()
+            .for_each(|(error, log_type)| self.report(&error, log_type));
+
+        if errors > 0 || warnings > 0 {
+            let errors: String = if errors == 1 {
+                message("diagnostic.one_error", &[])
+            } else {
+                message("diagnostic.errors", &[&errors])
+            };
+
+            let warnings: String = if warnings == 1 {
+                message("diagnostic.one_warning", &[])
+            } else {
+                message("diagnostic.warnings", &[&warnings])
+            };
+
+            println!("{}\n", style(format!("{}, {}", errors, warnings)).bold());
+        }
+
+        errors > 0
+    }
+
     pub fn report(&mut self, error: &ThrushError, log_type: LogType) {
-        if let ThrushError::Parse(
-            ThrushErrorKind::ParsedNumber
-            | ThrushErrorKind::UnreachableNumber
-            | ThrushErrorKind::SyntaxError
-            | ThrushErrorKind::UnreachableVariable
-            | ThrushErrorKind::ObjectNotDefined
-            | ThrushErrorKind::VariableNotDeclared,
-            title,
-            help,
-            line,
-        ) = error
-        {
-            self.print_report(title, help, *line, log_type);
-        } else if let ThrushError::Lex(
-            ThrushErrorKind::SyntaxError
-            | ThrushErrorKind::ParsedNumber
-            | ThrushErrorKind::UnreachableNumber
-            | ThrushErrorKind::UnknownChar,
-            title,
-            help,
+        match error {
+            ThrushError::Parse(kind, title, help, line)
+            | ThrushError::Lex(kind, title, help, line)
+            | ThrushError::Scope(kind, title, help, line) => {
+                self.print_report(&heading(kind, title), help, *line, log_type);
+            }
+
+            ThrushError::Spanned(error, span, labels, _) => match error.as_ref() {
+                ThrushError::Parse(kind, title, help, _)
+                | ThrushError::Lex(kind, title, help, _)
+                | ThrushError::Scope(kind, title, help, _) => {
+                    self.print_spanned_report(&heading(kind, title), help, *span, labels, log_type)
+                }
+                error => self.report(error, log_type),
+            },
+
+            ThrushError::Instantiation(error, instance, line) => {
+                if let ThrushError::Parse(kind, title, help, definition) = error.as_ref() {
+                    self.print_report(&heading(kind, title), help, *line, log_type);
+                    self.print_report(
+                        &message("diagnostic.instantiation", &[instance]),
+                        &message("diagnostic.from_generic_line", &[]),
+                        *definition,
+                        LogType::INFO,
+                    );
+                } else {
+                    self.report(error, log_type);
+                    self.print_report(
+                        &message("diagnostic.instantiation", &[instance]),
+                        &message("diagnostic.from_instantiation_line", &[]),
+                        *line,
+                        LogType::INFO,
+                    );
+                }
+            }
+
+            ThrushError::None => {}
+        }
+    }
+
+    fn emit_json(&self, error: &ThrushError, log_type: &LogType) {
+        /*
+            One object per line in the stderr, nothing else is written there in this mode:
+
+            {"code", "severity", "message", "help", "file", "line", "column", "spans", "fixes"}
+        */
+
+        let Some((kind, title, help)) = contents(error) else {
+            return;
+        };
+
+        let (line, column): (usize, usize) = match error {
+            ThrushError::Spanned(..) => self.position(error),
+            error => self.location(self.line_span(error.line()).start),
+        };
+
+        let severity: &str = match log_type {
+            LogType::ERROR => "error",
+            LogType::WARN => "warning",
+            LogType::INFO => "note",
+        };
+
+        eprintln!(
+            "{{\"code\":{},\"severity\":{},\"message\":{},\"help\":{},\"file\":{},\"line\":{},\"column\":{},\"spans\":[{}],\"fixes\":[{}]}}",
+            json_string(kind.code()),
+            json_string(severity),
+            json_string(title),
+            json_string(help),
+            json_string(&self.thrush_file.path.to_string_lossy()),
             line,
-        ) = error
-        {
-            self.print_report(title, help, *line, log_type);
-        } else if let ThrushError::Scope(
-            ThrushErrorKind::UnreachableVariable | ThrushErrorKind::ObjectNotDefined,
-            title,
-            help,
+            column,
+            self.json_spans(error).join(","),
+            self.json_fixes(error).join(",")
+        );
+    }
+
+    fn json_spans(&self, error: &ThrushError) -> Vec<String> {
+        match error {
+            ThrushError::Parse(_, _, _, line)
+            | ThrushError::Lex(_, _, _, line)
+            | ThrushError::Scope(_, _, _, line) => {
+                vec![self.json_span(self.line_span(*line), true, "")]
+            }
+
+            ThrushError::Spanned(_, span, labels, _) => [self.json_span(*span, true, "")]
+                .into_iter()
+                .chain(
+                    labels
+                        .iter()
+                        .map(|(span, message)| self.json_span(*span, false, message)),
+                )
+                .collect(),
+
+            ThrushError::Instantiation(error, instance, line) => {
+                let instantiation: String = message("diagnostic.instantiation", &[instance]);
+
+                if let ThrushError::Parse(_, _, _, definition) = error.as_ref() {
+                    vec![
+                        self.json_span(self.line_span(*line), true, &instantiation),
+                        self.json_span(
+                            self.line_span(*definition),
+                            false,
+                            &message("diagnostic.from_generic_line_json", &[]),
+                        ),
+                    ]
+                } else {
+                    let mut spans: Vec<String> = self.json_spans(error);
+                    spans.push(self.json_span(self.line_span(*line), false, &instantiation));
+                    spans
+                }
+            }
+
+            ThrushError::None => Vec::new(),
+        }
+    }
+
+    fn json_fixes(&self, error: &ThrushError) -> Vec<String> {
+        error
+            .fixes()
+            .iter()
+            .map(|(span, replacement, applicability)| {
+                let (line_start, column_start): (usize, usize) = self.location(span.start);
+                let (line_end, column_end): (usize, usize) = self.location(span.end);
+
+                format!(
+                    "{{\"byte_start\":{},\"byte_end\":{},\"line_start\":{},\"column_start\":{},\"line_end\":{},\"column_end\":{},\"replacement\":{},\"applicability\":{}}}",
+                    span.start,
+                    span.end,
+                    line_start,
+                    column_start,
+                    line_end,
+                    column_end,
+                    json_string(replacement),
+                    json_string(applicability.as_str())
+                )
+            })
+            .collect()
+    }
+
+    fn json_span(&self, span: Span, is_primary: bool, label: &str) -> String {
+        let (line_start, column_start): (usize, usize) = self.location(span.start);
+        let (line_end, column_end): (usize, usize) = self.location(span.end);
+
+        format!(
+            "{{\"byte_start\":{},\"byte_end\":{},\"line_start\":{},\"column_start\":{},\"line_end\":{},\"column_end\":{},\"is_primary\":{},\"label\":{}}}",
+            span.start,
+            span.end,
+            line_start,
+            column_start,
+            line_end,
+            column_end,
+            is_primary,
+            if label.is_empty() {
+                String::from("null")
+            } else {
+                json_string(label)
+            }
+        )
+    }
+
+    fn line_span(&self, line: usize) -> Span {
+        // The errors without span cover the whole line, without the indentation.
+        let line: usize = line.clamp(1, self.lines.len().max(1));
+
+        let start: usize = self
+            .source
+            .split_inclusive('\n')
+            .take(line - 1)
+            .map(|line| line.len())
+            .sum();
+
+        let content: &str = self.lines.get(line - 1).map_or("", |line| line.as_str());
+        let indentation: usize = content.len() - content.trim_start().len();
+
+        Span::new(
+            start + indentation,
+            start + content.trim_end().len().max(indentation),
+        )
+    }
+
+    fn print_spanned_report(
+        &mut self,
+        title: &str,
+        help: &str,
+        span: Span,
+        labels: &[(Span, String)],
+        log_type: LogType,
+    ) {
+        let (line, column): (usize, usize) = self.location(span.start);
+
+        println!(
+            "{} - {}:{}\n",
+            format_args!("{}", style(&self.thrush_file.name).bold().bright_red()),
             line,
-        ) = error
-        {
-            self.print_report(title, help, *line, log_type);
+            column
+        );
+
+        println!("{} {}\n", log_type.to_styled(), title);
+
+        // The gutter is as wide as the greatest line number of the report.
+        let gutter: usize = labels
+            .iter()
+            .map(|label| label.0.end)
+            .chain([span.end])
+            .map(|offset| self.location(offset).0.to_string().len())
+            .max()
+            .unwrap_or(1);
+
+        self.draw_span(span, '^', "", gutter, &log_type);
+
+        labels.iter().for_each(|(span, message)| {
+            self.draw_span(*span, '-', message, gutter, &LogType::INFO);
+        });
+
+        println!(
+            "\n{}{} {}\n",
+            style(message("diagnostic.help", &[])).bold().bright_green(),
+            style(":").bold(),
+            style(help).bold()
+        );
+    }
+
+    fn draw_span(
+        &self,
+        span: Span,
+        marker: char,
+        message: &str,
+        gutter: usize,
+        log_type: &LogType,
+    ) {
+        /*
+            The span is underlined line by line, the first line from the column where it starts,
+            the last one up to the column where it ends.
+        */
+
+        let (start_line, start_column): (usize, usize) = self.location(span.start);
+        let (end_line, end_column): (usize, usize) = self.location(span.end);
+
+        println!("{} |", " ".repeat(gutter));
+
+        for line in start_line..=end_line {
+            let content: &str = self.lines.get(line - 1).map_or("", |line| line.as_str());
+            let length: usize = content.chars().count();
+
+            let from: usize = if line == start_line {
+                start_column
+            } else {
+                content.chars().take_while(|ch| ch.is_whitespace()).count() + 1
+            };
+
+            let to: usize = if line == end_line {
+                end_column.saturating_sub(1).max(from)
+            } else {
+                length.max(from)
+            };
+
+            // The tabs are kept, so the marks are aligned with the code.
+            let indent: String = content
+                .chars()
+                .take(from - 1)
+                .map(|ch| if ch == '\t' { '\t' } else { ' ' })
+                .collect();
+
+            let marks: String = marker.to_string().repeat(to - from + 1);
+
+            let marks: String = match log_type {
+                LogType::ERROR => style(marks).bold().bright_red().to_string(),
+                LogType::WARN => style(marks).bold().bright_yellow().to_string(),
+                LogType::INFO => style(marks).bold().to_string(),
+            };
+
+            println!("{:>width$} | {}", line, content, width = gutter);
+
+            if line == end_line && !message.is_empty() {
+                println!(
+                    "{} | {}{} {}",
+                    " ".repeat(gutter),
+                    indent,
+                    marks,
+                    style(message).bold()
+                );
+            } else {
+                println!("{} | {}{}", " ".repeat(gutter), indent, marks);
+            }
+        }
+    }
+
+    fn position(&self, error: &ThrushError) -> (usize, usize) {
+        match error {
+            ThrushError::Spanned(_, span, ..) => self.location(span.start),
+            error => (error.line(), 0),
         }
     }
 
+    fn location(&self, offset: usize) -> (usize, usize) {
+        // (Line, Column) of a byte offset, the columns are counted in characters.
+        let offset: usize = offset.min(self.source.len());
+        let before: &[u8] = &self.source.as_bytes()[..offset];
+
+        let line_start: usize = before
+            .iter()
+            .rposition(|ch| *ch == b'\n')
+            .map_or(0, |position| position + 1);
+
+        let line: usize = before.iter().filter(|ch| **ch == b'\n').count() + 1;
+        let column: usize = String::from_utf8_lossy(&before[line_start..])
+            .trim_end_matches('\r')
+            .chars()
+            .count()
+            + 1;
+
+        (line.min(self.lines.len().max(1)), column)
+    }
+
     fn print_report(&mut self, title: &str, help: &str, line: usize, log_type: LogType) {
         self.print_header(line, title, log_type);
 
@@ -100,7 +446,7 @@ impl Diagnostic {
 
         println!(
             "\n{}{} {}\n",
-            style("Help").bold().bright_green(),
+            style(message("diagnostic.help", &[])).bold().bright_green(),
             style(":").bold(),
             style(help).bold()
         );
@@ -117,6 +463,55 @@ impl Diagnostic {
     }
 }
 
+pub fn report_message(error: &str, format: ErrorFormat) {
+    // The errors out of the source code, like a missing toolchain, don't have a location.
+    if format == ErrorFormat::Json {
+        eprintln!(
+            "{{\"code\":null,\"severity\":\"error\",\"message\":{},\"help\":null,\"file\":null,\"line\":null,\"column\":null,\"spans\":[],\"fixes\":[]}}",
+            json_string(error)
+        );
+
+        return;
+    }
+
+    logging::log(LogType::ERROR, error);
+}
+
+fn contents(error: &ThrushError) -> Option<(&ThrushErrorKind, &str, &str)> {
+    match error {
+        ThrushError::Parse(kind, title, help, _)
+        | ThrushError::Lex(kind, title, help, _)
+        | ThrushError::Scope(kind, title, help, _) => Some((kind, title, help)),
+        ThrushError::Spanned(error, ..) | ThrushError::Instantiation(error, ..) => contents(error),
+        ThrushError::None => None,
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut escaped: String = String::with_capacity(text.len() + 2);
+
+    escaped.push('"');
+
+    text.chars().for_each(|ch| match ch {
+        '"' => escaped.push_str("\\\""),
+        '\\' => escaped.push_str("\\\\"),
+        '\n' => escaped.push_str("\\n"),
+        '\r' => escaped.push_str("\\r"),
+        '\t' => escaped.push_str("\\t"),
+        ch if (ch as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", ch as u32)),
+        ch => escaped.push(ch),
+    });
+
+    escaped.push('"');
+
+    escaped
+}
+
+#[inline]
+fn heading(kind: &ThrushErrorKind, title: &str) -> String {
+    format!("[{}] {}", kind.code(), title)
+}
+
 #[inline]
 pub fn create_panic_message(subject: &str) -> String {
     format!(
@@ -1,13 +1,137 @@
-#[derive(Default, Debug)]
+use super::{
+    frontend::{lexer::Span, lints::Lint},
+    messages::message,
+};
+
+#[derive(Default, Debug, PartialEq)]
 pub enum ThrushError {
     Parse(ThrushErrorKind, String, String, usize),
     Lex(ThrushErrorKind, String, String, usize),
     Scope(ThrushErrorKind, String, String, usize),
+    Instantiation(Box<ThrushError>, String, usize),
+    // (Error, Span of the error, Secondary labels (Span, Message),
+    //  Fixes (Span, Replacement, Applicability))
+    Spanned(
+        Box<ThrushError>,
+        Span,
+        Vec<(Span, String)>,
+        Vec<(Span, String, Applicability)>,
+    ),
     #[default]
     None,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Applicability {
+    // The fix is certainly what was meant, `thrushc fix` applies it.
+    MachineApplicable,
+    // The fix is a guess, like a close name, it's only shown in the help.
+    MaybeIncorrect,
+}
+
+impl Applicability {
+    #[inline]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Applicability::MachineApplicable => "MachineApplicable",
+            Applicability::MaybeIncorrect => "MaybeIncorrect",
+        }
+    }
+}
+
+impl ThrushError {
+    pub fn spanned(self, span: Span) -> ThrushError {
+        // The first span is the most precise, the outer ones only cover more code.
+        match self {
+            ThrushError::Spanned(..) | ThrushError::None => self,
+            error => ThrushError::Spanned(Box::new(error), span, Vec::new(), Vec::new()),
+        }
+    }
+
+    pub fn label(self, span: Span, message: String) -> ThrushError {
+        match self {
+            ThrushError::Spanned(error, primary, mut labels, fixes) => {
+                labels.push((span, message));
+                ThrushError::Spanned(error, primary, labels, fixes)
+            }
+
+            error => error,
+        }
+    }
+
+    pub fn suggestion(self, span: Span, replacement: &str) -> ThrushError {
+        // The suggestion is written in the help, and kept as a fix that the tools can apply.
+        let error: ThrushError = match self {
+            ThrushError::Parse(kind, title, help, line) => ThrushError::Parse(
+                kind,
+                title,
+                format!(
+                    "{} {}",
+                    help,
+                    message("diagnostic.did_you_mean", &[&replacement])
+                ),
+                line,
+            ),
+            error => error,
+        };
+
+        error
+            .spanned(span)
+            .fix_with(span, replacement.to_string(), Applicability::MaybeIncorrect)
+    }
+
+    #[inline]
+    pub fn fix(self, span: Span, replacement: String) -> ThrushError {
+        self.fix_with(span, replacement, Applicability::MachineApplicable)
+    }
+
+    pub fn fix_with(
+        self,
+        span: Span,
+        replacement: String,
+        applicability: Applicability,
+    ) -> ThrushError {
+        // A fix replaces the code of the span, an empty span inserts the code at its position.
+        match self {
+            ThrushError::Spanned(error, primary, labels, mut fixes) => {
+                fixes.push((span, replacement, applicability));
+                ThrushError::Spanned(error, primary, labels, fixes)
+            }
+
+            error => error,
+        }
+    }
+
+    pub fn fixes(&self) -> &[(Span, String, Applicability)] {
+        match self {
+            ThrushError::Spanned(_, _, _, fixes) => fixes,
+            ThrushError::Instantiation(error, ..) => error.fixes(),
+            _ => &[],
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        match self {
+            ThrushError::Parse(_, _, _, line)
+            | ThrushError::Lex(_, _, _, line)
+            | ThrushError::Scope(_, _, _, line)
+            | ThrushError::Instantiation(_, _, line) => *line,
+            ThrushError::Spanned(error, ..) => error.line(),
+            ThrushError::None => 0,
+        }
+    }
+}
+
+/*
+
+    ERROR CODES
+
+    Every kind of error has a stable code, `thrushc explain <code>` prints its explanation.
+    The codes are never reused, the new kinds take the next free code.
+
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ThrushErrorKind {
     SyntaxError,
     UnreachableNumber,
@@ -17,4 +141,126 @@ pub enum ThrushErrorKind {
     ObjectNotDefined,
     VariableNotDefined,
     VariableNotDeclared,
+    UnexpectedEnd,
+    TypeMismatch,
+    TypeChecking,
+    UnknownType,
+    UnknownField,
+    UnknownFunction,
+    UnknownMethod,
+    UnknownTrait,
+    UnknownVariant,
+    UnknownCapture,
+    UnknownLint,
+    DuplicatedDefinition,
+    AmbiguousMethod,
+    SignatureMismatch,
+    MissingMethod,
+    UnsatisfiedBound,
+    MissingField,
+    InvalidField,
+    NullValue,
+    NonExhaustiveMatch,
+    UnreachablePattern,
+    MissingReturn,
+    MissingEntrypoint,
+    EscapingClosure,
+    InvalidCapture,
+    UndeclaredLabel,
+    ShadowedLabel,
+    TypeAnnotationsNeeded,
+    UnreachableDeallocation,
+    MovedClosure,
+    Lint(Lint),
+}
+
+impl ThrushErrorKind {
+    pub fn code(&self) -> &'static str {
+        // The errors are `E0001`, `E0002`, ... and the warnings of the lints `W0001`, `W0002`, ...
+        match self {
+            ThrushErrorKind::SyntaxError => "E0001",
+            ThrushErrorKind::UnreachableNumber => "E0002",
+            ThrushErrorKind::ParsedNumber => "E0003",
+            ThrushErrorKind::UnknownChar => "E0004",
+            ThrushErrorKind::UnreachableVariable => "E0005",
+            ThrushErrorKind::ObjectNotDefined => "E0006",
+            ThrushErrorKind::VariableNotDefined => "E0007",
+            ThrushErrorKind::VariableNotDeclared => "E0008",
+            ThrushErrorKind::UnexpectedEnd => "E0009",
+            ThrushErrorKind::TypeMismatch => "E0010",
+            ThrushErrorKind::TypeChecking => "E0011",
+            ThrushErrorKind::UnknownType => "E0012",
+            ThrushErrorKind::UnknownField => "E0013",
+            ThrushErrorKind::UnknownFunction => "E0014",
+            ThrushErrorKind::UnknownMethod => "E0015",
+            ThrushErrorKind::UnknownTrait => "E0016",
+            ThrushErrorKind::UnknownVariant => "E0017",
+            ThrushErrorKind::UnknownCapture => "E0018",
+            ThrushErrorKind::UnknownLint => "E0019",
+            ThrushErrorKind::DuplicatedDefinition => "E0020",
+            ThrushErrorKind::AmbiguousMethod => "E0021",
+            ThrushErrorKind::SignatureMismatch => "E0022",
+            ThrushErrorKind::MissingMethod => "E0023",
+            ThrushErrorKind::UnsatisfiedBound => "E0024",
+            ThrushErrorKind::MissingField => "E0025",
+            ThrushErrorKind::InvalidField => "E0026",
+            ThrushErrorKind::NullValue => "E0027",
+            ThrushErrorKind::NonExhaustiveMatch => "E0028",
+            ThrushErrorKind::UnreachablePattern => "E0029",
+            ThrushErrorKind::MissingReturn => "E0030",
+            ThrushErrorKind::MissingEntrypoint => "E0031",
+            ThrushErrorKind::EscapingClosure => "E0032",
+            ThrushErrorKind::InvalidCapture => "E0033",
+            ThrushErrorKind::UndeclaredLabel => "E0034",
+            ThrushErrorKind::ShadowedLabel => "E0035",
+            ThrushErrorKind::TypeAnnotationsNeeded => "E0036",
+            ThrushErrorKind::UnreachableDeallocation => "E0037",
+            ThrushErrorKind::MovedClosure => "E0038",
+            ThrushErrorKind::Lint(lint) => lint.code(),
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<ThrushErrorKind> {
+        match code.trim().to_uppercase().as_str() {
+            "E0001" => Some(ThrushErrorKind::SyntaxError),
+            "E0002" => Some(ThrushErrorKind::UnreachableNumber),
+            "E0003" => Some(ThrushErrorKind::ParsedNumber),
+            "E0004" => Some(ThrushErrorKind::UnknownChar),
+            "E0005" => Some(ThrushErrorKind::UnreachableVariable),
+            "E0006" => Some(ThrushErrorKind::ObjectNotDefined),
+            "E0007" => Some(ThrushErrorKind::VariableNotDefined),
+            "E0008" => Some(ThrushErrorKind::VariableNotDeclared),
+            "E0009" => Some(ThrushErrorKind::UnexpectedEnd),
+            "E0010" => Some(ThrushErrorKind::TypeMismatch),
+            "E0011" => Some(ThrushErrorKind::TypeChecking),
+            "E0012" => Some(ThrushErrorKind::UnknownType),
+            "E0013" => Some(ThrushErrorKind::UnknownField),
+            "E0014" => Some(ThrushErrorKind::UnknownFunction),
+            "E0015" => Some(ThrushErrorKind::UnknownMethod),
+            "E0016" => Some(ThrushErrorKind::UnknownTrait),
+            "E0017" => Some(ThrushErrorKind::UnknownVariant),
+            "E0018" => Some(ThrushErrorKind::UnknownCapture),
+            "E0019" => Some(ThrushErrorKind::UnknownLint),
+            "E0020" => Some(ThrushErrorKind::DuplicatedDefinition),
+            "E0021" => Some(ThrushErrorKind::AmbiguousMethod),
+            "E0022" => Some(ThrushErrorKind::SignatureMismatch),
+            "E0023" => Some(ThrushErrorKind::MissingMethod),
+            "E0024" => Some(ThrushErrorKind::UnsatisfiedBound),
+            "E0025" => Some(ThrushErrorKind::MissingField),
+            "E0026" => Some(ThrushErrorKind::InvalidField),
+            "E0027" => Some(ThrushErrorKind::NullValue),
+            "E0028" => Some(ThrushErrorKind::NonExhaustiveMatch),
+            "E0029" => Some(ThrushErrorKind::UnreachablePattern),
+            "E0030" => Some(ThrushErrorKind::MissingReturn),
+            "E0031" => Some(ThrushErrorKind::MissingEntrypoint),
+            "E0032" => Some(ThrushErrorKind::EscapingClosure),
+            "E0033" => Some(ThrushErrorKind::InvalidCapture),
+            "E0034" => Some(ThrushErrorKind::UndeclaredLabel),
+            "E0035" => Some(ThrushErrorKind::ShadowedLabel),
+            "E0036" => Some(ThrushErrorKind::TypeAnnotationsNeeded),
+            "E0037" => Some(ThrushErrorKind::UnreachableDeallocation),
+            "E0038" => Some(ThrushErrorKind::MovedClosure),
+            code => Lint::from_code(code).map(ThrushErrorKind::Lint),
+        }
+    }
 }
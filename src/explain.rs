@@ -0,0 +1,36 @@
+use {
+    super::{error::ThrushErrorKind, messages::message},
+    stylic::{style, Color, Stylize},
+};
+
+pub fn explain(kind: ThrushErrorKind) {
+    /*
+        (Explanation, Example)
+
+        The explanation and the example of a code live in the catalogue of messages, as
+        `<code>.explanation` and `<code>.example`. The first line of the explanation is its title,
+        the example shows the code with the error and the code without it.
+    */
+
+    let code: &str = kind.code();
+
+    let summary: String = message(&format!("{}.explanation", code), &[]);
+    let example: String = message(&format!("{}.example", code), &[]);
+
+    println!(
+        "\n{} {}\n",
+        style(code).bold().fg(Color::Rgb(141, 141, 142)),
+        style(summary.lines().next().unwrap_or_default()).bold()
+    );
+
+    summary
+        .lines()
+        .skip(1)
+        .for_each(|line| println!("{}", line));
+
+    println!("\n{}\n", style(message("explain.example", &[])).bold());
+
+    example.lines().for_each(|line| println!("    {}", line));
+
+    println!();
+}
@@ -0,0 +1,274 @@
+use {
+    super::{
+        backend::{
+            compiler::options::{ErrorFormat, ThrushFile},
+            instruction::Instruction,
+        },
+        diagnostic,
+        error::{Applicability, ThrushError},
+        frontend::{
+            lexer::{Lexer, Span, Token},
+            lints::LintLevels,
+            parser::Parser,
+        },
+    },
+    std::fs,
+    stylic::{style, Color, Stylize},
+};
+
+/*
+
+    FIXES
+
+    `thrushc fix` applies the fixes of the errors and the warnings in place, `--dry-run` prints
+    them as an unified diff without touch the files.
+
+    Only the machine applicable fixes are applied, the guesses like the close names of an
+    unknown name are left in the help of the diagnostic.
+
+    The fixes that overlap an earlier one are skipped, running the command again applies them.
+
+*/
+
+const CONTEXT: usize = 3;
+
+type Change = (usize, usize, Vec<(Span, String)>);
+
+pub fn fix(files: &[ThrushFile], lints: &LintLevels, dry_run: bool, format: ErrorFormat) -> bool {
+    // A file that can't be read or written is reported, the rest of the files are still fixed.
+    let mut has_errors: bool = false;
+
+    files.iter().for_each(|file| {
+        let source: String = match fs::read_to_string(&file.path) {
+            Ok(source) => source,
+            Err(error) => {
+                diagnostic::report_message(
+                    &format!(
+                        "\"{}\" can't be read: {}.",
+                        file.path.to_string_lossy(),
+                        error
+                    ),
+                    format,
+                );
+
+                has_errors = true;
+                return;
+            }
+        };
+
+        let fixes: Vec<(Span, String)> = collect(&source, file, lints);
+
+        if fixes.is_empty() {
+            return;
+        }
+
+        if dry_run {
+            print!("{}", diff(&file.path.to_string_lossy(), &source, &fixes));
+
+            return;
+        }
+
+        if let Err(error) = fs::write(&file.path, apply(&source, &fixes)) {
+            diagnostic::report_message(
+                &format!(
+                    "\"{}\" can't be written: {}.",
+                    file.path.to_string_lossy(),
+                    error
+                ),
+                format,
+            );
+
+            has_errors = true;
+            return;
+        }
+
+        println!(
+            "{} {} ({} fix{})",
+            style("Fixed").bold().fg(Color::Rgb(141, 141, 142)),
+            &file.path.to_string_lossy(),
+            fixes.len(),
+            if fixes.len() == 1 { "" } else { "es" }
+        );
+    });
+
+    has_errors
+}
+
+fn collect(source: &str, file: &ThrushFile, lints: &LintLevels) -> Vec<(Span, String)> {
+    let mut lexer: Lexer = Lexer::new(source.as_bytes());
+    let (tokens, mut errors): (&[Token], Vec<ThrushError>) = lexer.lex();
+
+    let mut parser: Parser = Parser::new(tokens, file, lints);
+    let (_, parser_errors, warnings): (&[Instruction], Vec<ThrushError>, Vec<ThrushError>) =
+        parser.start();
+
+    errors.extend(parser_errors);
+    errors.extend(warnings);
+
+    let mut fixes: Vec<(Span, String)> = errors
+        .iter()
+        .flat_map(|error| error.fixes().iter())
+        .filter(|(_, _, applicability)| *applicability == Applicability::MachineApplicable)
+        .filter(|(span, _, _)| span.start <= span.end && span.end <= source.len())
+        .map(|(span, replacement, _)| (*span, replacement.clone()))
+        .collect();
+
+    fixes.sort_by_key(|(span, _)| (span.start, span.end));
+    fixes.dedup();
+
+    let mut applicable: Vec<(Span, String)> = Vec::with_capacity(fixes.len());
+
+    fixes.into_iter().for_each(|fix| {
+        if !matches!(applicable.last(), Some((last, _)) if last.end > fix.0.start) {
+            applicable.push(fix);
+        }
+    });
+
+    applicable
+}
+
+fn apply(source: &str, fixes: &[(Span, String)]) -> String {
+    let mut fixed: String = String::with_capacity(source.len());
+    let mut position: usize = 0;
+
+    fixes.iter().for_each(|(span, replacement)| {
+        fixed.push_str(&source[position..span.start]);
+        fixed.push_str(replacement);
+        position = span.end;
+    });
+
+    fixed.push_str(&source[position..]);
+
+    fixed
+}
+
+fn diff(path: &str, source: &str, fixes: &[(Span, String)]) -> String {
+    /*
+        The changes are the lines touched by the fixes, the changes closer than the context
+        lines share the same hunk.
+
+        Change (usize, usize, Vec<(Span, String)>)
+                ^^^^^  ^^^^^  ^^^^^^^^^^^^^^^^^^^
+               First Line - Last Line - Fixes
+    */
+
+    let starts: Vec<usize> = [0]
+        .into_iter()
+        .chain(
+            source
+                .match_indices('\n')
+                .map(|(position, _)| position + 1)
+                .filter(|start| *start < source.len()),
+        )
+        .collect();
+
+    let line_of = |offset: usize| starts.partition_point(|start| *start <= offset) - 1;
+    let end_of = |line: usize| starts.get(line + 1).copied().unwrap_or(source.len());
+
+    let lines: Vec<&str> = (0..starts.len())
+        .map(|line| source[starts[line]..end_of(line)].trim_end_matches('\n'))
+        .collect();
+
+    let mut changes: Vec<Change> = Vec::new();
+
+    fixes.iter().for_each(|(span, replacement)| {
+        let (first, last): (usize, usize) = (line_of(span.start), line_of(span.end));
+
+        match changes.last_mut() {
+            Some(change) if change.1 >= first => {
+                change.1 = change.1.max(last);
+                change.2.push((*span, replacement.clone()));
+            }
+            _ => changes.push((first, last, vec![(*span, replacement.clone())])),
+        }
+    });
+
+    let mut hunks: Vec<Vec<(usize, usize, Vec<String>)>> = Vec::new();
+
+    for (first, last, fixes) in changes.iter() {
+        let start: usize = starts[*first];
+        let end: usize = end_of(*last);
+
+        let fixes: Vec<(Span, String)> = fixes
+            .iter()
+            .map(|(span, replacement)| {
+                (
+                    Span::new(span.start - start, span.end - start),
+                    replacement.clone(),
+                )
+            })
+            .collect();
+
+        let new_lines: Vec<String> = apply(&source[start..end], &fixes)
+            .split_inclusive('\n')
+            .map(|line| line.trim_end_matches('\n').to_string())
+            .collect();
+
+        match hunks.last_mut() {
+            Some(hunk) if hunk.last().unwrap().1 + 2 * CONTEXT >= *first => {
+                hunk.push((*first, *last, new_lines))
+            }
+            _ => hunks.push(vec![(*first, *last, new_lines)]),
+        }
+    }
+
+    let mut output: String = format!("--- a/{}\n+++ b/{}\n", path, path);
+    let mut offset: isize = 0;
+
+    // The last line without a line break is marked, so `patch` can apply the diff.
+    let end_marker = |line: usize| {
+        if line == lines.len() - 1 && !source.ends_with('\n') {
+            "\\ No newline at end of file\n"
+        } else {
+            ""
+        }
+    };
+
+    hunks.iter().for_each(|hunk| {
+        let from: usize = hunk[0].0.saturating_sub(CONTEXT);
+        let to: usize = (hunk.last().unwrap().1 + CONTEXT).min(lines.len() - 1);
+
+        let old_count: usize = to - from + 1;
+        let new_count: isize = old_count as isize
+            + hunk
+                .iter()
+                .map(|(first, last, new_lines)| {
+                    new_lines.len() as isize - (last - first + 1) as isize
+                })
+                .sum::<isize>();
+
+        output.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            from + 1,
+            old_count,
+            from as isize + offset + 1,
+            new_count
+        ));
+
+        let mut line: usize = from;
+
+        hunk.iter().for_each(|(first, last, new_lines)| {
+            (line..*first).for_each(|line| {
+                output.push_str(&format!(" {}\n{}", lines[line], end_marker(line)))
+            });
+
+            (*first..=*last).for_each(|line| {
+                output.push_str(&format!("-{}\n{}", lines[line], end_marker(line)))
+            });
+
+            new_lines
+                .iter()
+                .for_each(|new_line| output.push_str(&format!("+{}\n", new_line)));
+            output.push_str(end_marker(*last));
+
+            line = last + 1;
+        });
+
+        (line..=to)
+            .for_each(|line| output.push_str(&format!(" {}\n{}", lines[line], end_marker(line))));
+
+        offset += new_count - old_count as isize;
+    });
+
+    output
+}
@@ -0,0 +1,167 @@
+use super::super::backend::instruction::Instruction;
+
+/*
+
+    CAPTURE ANALYSIS
+
+    Walks the body of a lambda and collects, in order of first use, every variable
+    referenced but not declared inside of the lambda. The parser resolves the names
+    against the enclosing scopes to build the environment of the closure.
+
+*/
+
+pub fn analyze<'instr>(
+    body: &Instruction<'instr>,
+    params: &[Instruction<'instr>],
+) -> Vec<&'instr str> {
+    let mut declared: Vec<&'instr str> = params
+        .iter()
+        .filter_map(|param| match param {
+            Instruction::Param { name, .. } => Some(*name),
+            _ => None,
+        })
+        .collect();
+
+    let mut used: Vec<&'instr str> = Vec::new();
+
+    walk(body, &mut declared, &mut used);
+
+    used
+}
+
+fn walk<'instr>(
+    instr: &Instruction<'instr>,
+    declared: &mut Vec<&'instr str>,
+    used: &mut Vec<&'instr str>,
+) {
+    match instr {
+        Instruction::RefVar { name, .. } => reference(name, declared, used),
+        Instruction::Indexe { origin, .. } => reference(origin, declared, used),
+        Instruction::Property { name, .. } => reference(name, declared, used),
+
+        Instruction::MutVar { name, value, .. } => {
+            reference(name, declared, used);
+            walk(value, declared, used);
+        }
+
+        Instruction::MutProperty { name, value, .. } => {
+            reference(name, declared, used);
+            walk(value, declared, used);
+        }
+
+        Instruction::MethodCall { receiver, args, .. } => {
+            reference(receiver, declared, used);
+            args.iter().for_each(|arg| walk(arg, declared, used));
+        }
+
+        Instruction::IndirectCall { name, args, .. } => {
+            reference(name, declared, used);
+            args.iter().for_each(|arg| walk(arg, declared, used));
+        }
+
+        Instruction::Closure { captures, .. } => captures
+            .iter()
+            .for_each(|capture| reference(capture.0, declared, used)),
+
+        Instruction::Var { name, value, .. } => {
+            walk(value, declared, used);
+            declared.push(*name);
+        }
+
+        Instruction::ForLoop {
+            variable,
+            cond,
+            actions,
+            block,
+            ..
+        } => {
+            [variable, cond, actions]
+                .into_iter()
+                .flatten()
+                .for_each(|instr| walk(instr, declared, used));
+
+            walk(block, declared, used);
+        }
+
+        Instruction::ForRange {
+            variable,
+            start,
+            end,
+            step,
+            block,
+            ..
+        } => {
+            walk(start, declared, used);
+            walk(end, declared, used);
+
+            if let Some(step) = step {
+                walk(step, declared, used);
+            }
+
+            declared.push(*variable);
+            walk(block, declared, used);
+        }
+
+        Instruction::ForEach {
+            variable,
+            iterable,
+            block,
+            ..
+        } => {
+            walk(iterable, declared, used);
+            declared.push(*variable);
+            walk(block, declared, used);
+        }
+
+        Instruction::Match { value, arms, .. } => {
+            walk(value, declared, used);
+
+            arms.iter().for_each(|arm| {
+                declared.extend(arm.1.iter().flatten());
+                walk(&arm.2, declared, used);
+            });
+        }
+
+        Instruction::If {
+            cond,
+            block,
+            otherwise,
+            ..
+        } => {
+            walk(cond, declared, used);
+            walk(block, declared, used);
+
+            if let Some(otherwise) = otherwise {
+                walk(otherwise, declared, used);
+            }
+        }
+
+        Instruction::Block { stmts } => stmts.iter().for_each(|stmt| walk(stmt, declared, used)),
+
+        Instruction::Call { args, .. }
+        | Instruction::EnumValue { args, .. }
+        | Instruction::StructLiteral { fields: args, .. }
+        | Instruction::Println(args)
+        | Instruction::Print(args) => args.iter().for_each(|arg| walk(arg, declared, used)),
+
+        Instruction::Binary { left, right, .. } => {
+            walk(left, declared, used);
+            walk(right, declared, used);
+        }
+
+        Instruction::Return(value, _)
+        | Instruction::Yield { value, .. }
+        | Instruction::Unary { value, .. }
+        | Instruction::Propagate { value, .. }
+        | Instruction::Unwrap { value, .. }
+        | Instruction::Group { instr: value, .. } => walk(value, declared, used),
+
+        _ => {}
+    }
+}
+
+fn reference<'instr>(name: &'instr str, declared: &[&'instr str], used: &mut Vec<&'instr str>) {
+    if !declared.contains(&name) && !used.contains(&name) {
+        used.push(name);
+    }
+}
@@ -0,0 +1,136 @@
+use super::{super::backend::instruction::Instruction, lexer::TokenKind};
+
+/*
+
+    CONTROL FLOW ANALYSIS
+
+    Answers questions about the paths of the control flow of a statement, without
+    evaluate anything that isn't a constant.
+
+    - Diverges: no path continues after the statement (return, break, continue).
+    - Always returns: every path ends with a return, or never ends.
+
+*/
+
+pub fn diverges(instr: &Instruction) -> bool {
+    match instr {
+        Instruction::Return(..) | Instruction::Break { .. } | Instruction::Continue { .. } => true,
+        Instruction::Block { stmts } => stmts.iter().any(diverges),
+
+        Instruction::If {
+            block,
+            otherwise: Some(otherwise),
+            ..
+        } => diverges(block) && diverges(otherwise),
+
+        Instruction::Match { arms, .. } => arms.iter().all(|arm| diverges(&arm.2)),
+
+        _ => is_infinite_loop(instr),
+    }
+}
+
+pub fn always_returns(instr: &Instruction) -> bool {
+    match instr {
+        Instruction::Return(..) => true,
+        Instruction::Block { stmts } => stmts.iter().any(always_returns),
+
+        Instruction::If {
+            block,
+            otherwise: Some(otherwise),
+            ..
+        } => always_returns(block) && always_returns(otherwise),
+
+        Instruction::Match { arms, .. } => arms.iter().all(|arm| always_returns(&arm.2)),
+
+        _ => is_infinite_loop(instr),
+    }
+}
+
+pub fn constant_condition(instr: &Instruction) -> Option<bool> {
+    match instr {
+        Instruction::Boolean(value) => Some(*value),
+        Instruction::Group { instr, .. } => constant_condition(instr),
+
+        Instruction::Unary {
+            op: TokenKind::Bang,
+            value,
+            ..
+        } => constant_condition(value).map(|value| !value),
+
+        Instruction::Binary {
+            left, op, right, ..
+        } => match (op, constant_condition(left), constant_condition(right)) {
+            (TokenKind::And, Some(false), _) | (TokenKind::And, _, Some(false)) => Some(false),
+            (TokenKind::Or, Some(true), _) | (TokenKind::Or, _, Some(true)) => Some(true),
+            (TokenKind::And | TokenKind::Or, Some(left), Some(right)) => Some(left && right),
+            (TokenKind::EqEq, Some(left), Some(right)) => Some(left == right),
+            (TokenKind::BangEq, Some(left), Some(right)) => Some(left != right),
+
+            _ => constant_comparison(left, op, right),
+        },
+
+        _ => None,
+    }
+}
+
+fn constant_comparison(left: &Instruction, op: &TokenKind, right: &Instruction) -> Option<bool> {
+    let (Instruction::Integer(_, left, _), Instruction::Integer(_, right, _)) = (left, right)
+    else {
+        return None;
+    };
+
+    match op {
+        TokenKind::EqEq => Some(left == right),
+        TokenKind::BangEq => Some(left != right),
+        TokenKind::Less => Some(left < right),
+        TokenKind::LessEq => Some(left <= right),
+        TokenKind::Greater => Some(left > right),
+        TokenKind::GreaterEq => Some(left >= right),
+
+        _ => None,
+    }
+}
+
+fn is_infinite_loop(instr: &Instruction) -> bool {
+    let Instruction::ForLoop {
+        cond, block, label, ..
+    } = instr
+    else {
+        return false;
+    };
+
+    let is_always_true: bool = match cond {
+        Some(cond) => constant_condition(cond) == Some(true),
+        None => true,
+    };
+
+    is_always_true && !breaks(block, *label, false)
+}
+
+fn breaks(instr: &Instruction, label: Option<&str>, is_nested: bool) -> bool {
+    match instr {
+        Instruction::Break { label: target } => match target {
+            Some(target) => label == Some(*target),
+            None => !is_nested,
+        },
+
+        Instruction::Block { stmts } => stmts.iter().any(|stmt| breaks(stmt, label, is_nested)),
+
+        Instruction::If {
+            block, otherwise, ..
+        } => {
+            breaks(block, label, is_nested)
+                || otherwise
+                    .as_ref()
+                    .is_some_and(|otherwise| breaks(otherwise, label, is_nested))
+        }
+
+        Instruction::Match { arms, .. } => arms.iter().any(|arm| breaks(&arm.2, label, is_nested)),
+
+        Instruction::ForLoop { block, .. }
+        | Instruction::ForRange { block, .. }
+        | Instruction::ForEach { block, .. } => breaks(block, label, true),
+
+        _ => false,
+    }
+}
@@ -0,0 +1,434 @@
+use {
+    super::super::{
+        backend::instruction::Instruction,
+        error::{ThrushError, ThrushErrorKind},
+        frontend::lexer::Span,
+        messages::message,
+    },
+    std::collections::HashMap,
+};
+
+/*
+
+    DEFINITE INITIALIZATION ANALYSIS
+
+    Walks the body of a function following every path of the control flow and checks that
+    the variables declared without value are assigned on all the paths that reach a use.
+
+    The state of a path keeps every variable that may be uninitialized with the reason of it,
+    the reason grows with the branches that don't assign the variable. A path that doesn't
+    continue (return, break, continue) has no state.
+
+*/
+
+// (Reason, Span of the declaration)
+type Uninitialized = (String, Span);
+
+type State<'instr> = Option<HashMap<&'instr str, Uninitialized>>;
+
+// (State of the path, Reason if the variable isn't assigned in this path)
+type Branch<'instr> = (State<'instr>, String);
+
+pub fn analyze(body: &Instruction<'_>) -> Vec<ThrushError> {
+    let mut analysis: Analysis = Analysis {
+        errors: Vec::new(),
+        loops: Vec::new(),
+        line: 0,
+    };
+
+    analysis.walk(body, &mut Some(HashMap::new()));
+
+    analysis.errors
+}
+
+struct Analysis<'instr> {
+    errors: Vec<ThrushError>,
+    loops: Vec<(Option<&'instr str>, Vec<Branch<'instr>>)>,
+    line: usize,
+}
+
+impl<'instr> Analysis<'instr> {
+    fn walk(&mut self, instr: &Instruction<'instr>, state: &mut State<'instr>) {
+        match instr {
+            Instruction::Var {
+                name,
+                value,
+                line,
+                span,
+                only_comptime,
+                ..
+            } => {
+                self.line = *line;
+                self.walk(value, state);
+
+                if let Some(vars) = state {
+                    if matches!(value.as_ref(), Instruction::Null) && !only_comptime {
+                        vars.insert(
+                            *name,
+                            (message("E0008.declared_without_value", &[&line]), *span),
+                        );
+                    } else {
+                        vars.remove(*name);
+                    }
+                }
+            }
+
+            Instruction::MutVar { name, value, .. } => {
+                self.walk(value, state);
+
+                if let Some(vars) = state {
+                    vars.remove(*name);
+                }
+            }
+
+            Instruction::RefVar {
+                name, line, span, ..
+            } => {
+                self.line = *line;
+                self.read(name, Some(*span), state);
+            }
+
+            Instruction::Indexe { origin: name, .. } | Instruction::Property { name, .. } => {
+                self.read(name, None, state)
+            }
+
+            Instruction::MutProperty { name, value, .. } => {
+                self.read(name, None, state);
+                self.walk(value, state);
+            }
+
+            Instruction::MethodCall {
+                receiver: name,
+                args,
+                ..
+            }
+            | Instruction::IndirectCall { name, args, .. } => {
+                self.read(name, None, state);
+                args.iter().for_each(|arg| self.walk(arg, state));
+            }
+
+            Instruction::Closure { captures, .. } => captures
+                .iter()
+                .for_each(|capture| self.read(capture.0, None, state)),
+
+            Instruction::Block { stmts } => {
+                let mut shadowed: Vec<(&str, Option<Uninitialized>)> = Vec::new();
+
+                stmts.iter().for_each(|stmt| {
+                    if let Instruction::Var { name, .. } = stmt {
+                        shadowed.push((*name, previous(state, name)));
+                    }
+
+                    self.walk(stmt, state);
+                });
+
+                restore(state, shadowed);
+            }
+
+            Instruction::If {
+                cond,
+                block,
+                otherwise,
+                ..
+            } => {
+                self.walk(cond, state);
+
+                let line: usize = self.line;
+
+                let mut then_state: State = state.clone();
+                let mut else_state: State = state.clone();
+
+                self.walk(block, &mut then_state);
+
+                let else_reason: String = match otherwise {
+                    Some(otherwise) => {
+                        self.walk(otherwise, &mut else_state);
+                        message("E0008.unassigned_else", &[&line])
+                    }
+
+                    None => message("E0008.missing_else", &[&line]),
+                };
+
+                *state = join(vec![
+                    (then_state, message("E0008.unassigned_if", &[&line])),
+                    (else_state, else_reason),
+                ]);
+            }
+
+            Instruction::Match { value, arms, .. } => {
+                self.walk(value, state);
+
+                let line: usize = self.line;
+
+                let branches: Vec<Branch> = arms
+                    .iter()
+                    .enumerate()
+                    .map(|(index, arm)| {
+                        let mut arm_state: State = state.clone();
+
+                        let shadowed: Vec<(&str, Option<Uninitialized>)> = arm
+                            .1
+                            .iter()
+                            .flatten()
+                            .map(|binding| (*binding, declare(&mut arm_state, binding)))
+                            .collect();
+
+                        self.walk(&arm.2, &mut arm_state);
+
+                        restore(&mut arm_state, shadowed);
+
+                        (
+                            arm_state,
+                            message("E0008.unassigned_arm", &[&(index + 1), &line]),
+                        )
+                    })
+                    .collect();
+
+                *state = join(branches);
+            }
+
+            Instruction::ForLoop {
+                variable,
+                cond,
+                actions,
+                block,
+                label,
+            } => {
+                let mut shadowed: Vec<(&str, Option<Uninitialized>)> = Vec::new();
+
+                if let Some(variable) = variable {
+                    if let Instruction::Var { name, .. } = variable.as_ref() {
+                        shadowed.push((*name, previous(state, name)));
+                    }
+
+                    self.walk(variable, state);
+                }
+
+                if let Some(cond) = cond {
+                    self.walk(cond, state);
+                }
+
+                let line: usize = self.line;
+
+                let mut body_state: State = state.clone();
+
+                self.loops.push((*label, Vec::new()));
+                self.walk(block, &mut body_state);
+
+                if let Some(actions) = actions {
+                    self.walk(actions, &mut body_state);
+                }
+
+                let mut exits: Vec<Branch> = self.loops.pop().unwrap().1;
+
+                // Only the loops without condition are infinite, the rest may run zero times.
+                if cond.is_some() {
+                    let reason: String = message("E0008.loop_may_not_run", &[&line]);
+
+                    exits.push((state.take(), reason.clone()));
+                    exits.push((body_state, reason));
+                }
+
+                *state = join(exits);
+
+                restore(state, shadowed);
+            }
+
+            Instruction::ForRange {
+                variable,
+                start,
+                end,
+                step,
+                block,
+                label,
+                ..
+            } => {
+                self.walk(start, state);
+                self.walk(end, state);
+
+                if let Some(step) = step {
+                    self.walk(step, state);
+                }
+
+                self.walk_loop(variable, block, label, state);
+            }
+
+            Instruction::ForEach {
+                variable,
+                iterable,
+                block,
+                label,
+                ..
+            } => {
+                self.walk(iterable, state);
+                self.walk_loop(variable, block, label, state);
+            }
+
+            Instruction::Break { label } => {
+                let line: usize = self.line;
+
+                let target: Option<&mut (Option<&str>, Vec<Branch>)> = match label {
+                    Some(label) => self
+                        .loops
+                        .iter_mut()
+                        .rev()
+                        .find(|target| target.0 == Some(*label)),
+                    None => self.loops.last_mut(),
+                };
+
+                if let Some(target) = target {
+                    target.1.push((
+                        state.take(),
+                        message("E0008.break_before_assignment", &[&line]),
+                    ));
+                }
+
+                *state = None;
+            }
+
+            Instruction::Continue { .. } => *state = None,
+
+            Instruction::Return(value, _) => {
+                self.walk(value, state);
+                *state = None;
+            }
+
+            Instruction::Call { args, .. }
+            | Instruction::EnumValue { args, .. }
+            | Instruction::StructLiteral { fields: args, .. }
+            | Instruction::Println(args)
+            | Instruction::Print(args) => args.iter().for_each(|arg| self.walk(arg, state)),
+
+            Instruction::Binary {
+                left, right, line, ..
+            } => {
+                self.line = *line;
+                self.walk(left, state);
+                self.walk(right, state);
+            }
+
+            Instruction::Unary { value, line, .. } | Instruction::Unwrap { value, line, .. } => {
+                self.line = *line;
+                self.walk(value, state);
+            }
+
+            Instruction::Yield { value, .. }
+            | Instruction::Propagate { value, .. }
+            | Instruction::Group { instr: value, .. } => self.walk(value, state),
+
+            _ => {}
+        }
+    }
+
+    fn walk_loop(
+        &mut self,
+        variable: &'instr str,
+        block: &Instruction<'instr>,
+        label: &Option<&'instr str>,
+        state: &mut State<'instr>,
+    ) {
+        let line: usize = self.line;
+
+        let mut body_state: State = state.clone();
+        let shadowed: Option<Uninitialized> = declare(&mut body_state, variable);
+
+        self.loops.push((*label, Vec::new()));
+        self.walk(block, &mut body_state);
+
+        restore(&mut body_state, vec![(variable, shadowed)]);
+
+        let mut exits: Vec<Branch> = self.loops.pop().unwrap().1;
+        let reason: String = message("E0008.loop_may_not_run", &[&line]);
+
+        exits.push((state.take(), reason.clone()));
+        exits.push((body_state, reason));
+
+        *state = join(exits);
+    }
+
+    fn read(&mut self, name: &str, span: Option<Span>, state: &mut State<'instr>) {
+        let Some(vars) = state else {
+            return;
+        };
+
+        // The variable is reported once, the next uses are on the same path.
+        if let Some((reason, declaration)) = vars.remove(name) {
+            let error: ThrushError = ThrushError::Parse(
+                ThrushErrorKind::VariableNotDeclared,
+                message("E0008.title", &[]),
+                message("E0008.uninitialized_variable", &[&name, &reason]),
+                self.line,
+            );
+
+            self.errors.push(match span {
+                Some(span) => error
+                    .spanned(span)
+                    .label(declaration, message("E0008.declared_here", &[])),
+                None => error,
+            });
+        }
+    }
+}
+
+fn join(branches: Vec<Branch<'_>>) -> State<'_> {
+    let branches: Vec<(HashMap<&str, Uninitialized>, String)> = branches
+        .into_iter()
+        .filter_map(|(state, reason)| state.map(|vars| (vars, reason)))
+        .collect();
+
+    if branches.is_empty() {
+        return None;
+    }
+
+    let mut joined: HashMap<&str, Uninitialized> = HashMap::new();
+
+    branches.iter().for_each(|(vars, branch_reason)| {
+        vars.iter().for_each(|(name, (reason, span))| {
+            if joined.contains_key(name) {
+                return;
+            }
+
+            let is_assigned_elsewhere: bool =
+                branches.iter().any(|other| !other.0.contains_key(name));
+
+            if is_assigned_elsewhere {
+                joined.insert(
+                    *name,
+                    (message("E0008.list_and", &[&reason, &branch_reason]), *span),
+                );
+            } else {
+                joined.insert(*name, (reason.clone(), *span));
+            }
+        });
+    });
+
+    Some(joined)
+}
+
+#[inline]
+fn previous(state: &State<'_>, name: &str) -> Option<Uninitialized> {
+    state.as_ref().and_then(|vars| vars.get(name).cloned())
+}
+
+#[inline]
+fn declare(state: &mut State<'_>, name: &str) -> Option<Uninitialized> {
+    state.as_mut().and_then(|vars| vars.remove(name))
+}
+
+fn restore<'instr>(state: &mut State<'instr>, shadowed: Vec<(&'instr str, Option<Uninitialized>)>) {
+    let Some(vars) = state else {
+        return;
+    };
+
+    shadowed
+        .into_iter()
+        .rev()
+        .for_each(|(name, previous)| match previous {
+            Some(reason) => {
+                vars.insert(name, reason);
+            }
+            None => {
+                vars.remove(name);
+            }
+        });
+}
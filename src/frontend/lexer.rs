@@ -1,7 +1,8 @@
 use {
     super::super::{
-        backend::compiler::options::ThrushFile, diagnostic::Diagnostic, error::{ThrushError, ThrushErrorKind}, logging::LogType
-    }, ahash::{HashMap, HashMapExt}, core::str, inkwell::{FloatPredicate, IntPredicate}, std::{num::ParseFloatError, process::exit}
+        error::{ThrushError, ThrushErrorKind},
+        messages::message,
+    }, ahash::{HashMap, HashMapExt}, core::str, inkwell::{FloatPredicate, IntPredicate}, std::{mem, num::ParseFloatError}
 };
 
 pub struct Lexer<'a> {
@@ -11,11 +12,10 @@ pub struct Lexer<'a> {
     start: usize,
     current: usize,
     line: usize,
-    diagnostic: Diagnostic
 }
 
 impl<'a> Lexer<'a> {
-    pub fn new(code: &'a [u8], file: &ThrushFile) -> Self {
+    pub fn new(code: &'a [u8]) -> Self {
         Self {
             tokens: Vec::new(),
             errors: Vec::new(),
@@ -23,35 +23,35 @@ impl<'a> Lexer<'a> {
             start: 0,
             current: 0,
             line: 1,
-            diagnostic: Diagnostic::new(file)
         }
     }
 
-    pub fn lex(&mut self) -> &[Token] {
+    pub fn lex(&mut self) -> (&[Token], Vec<ThrushError>) {
         while !self.end() {
             self.start = self.current;
 
             match self.scan() {
                 Ok(()) => {}
-                Err(e) => self.errors.push(e),
+                Err(e) => {
+                    self.errors.push(e.spanned(self.span()));
+
+                    // The parser skips the statement of an error token, the error is already reported.
+                    self.make(TokenKind::Error);
+                }
             }
         }
 
-        if !self.errors.is_empty() {
-            self.errors.iter().for_each(|error| {
-                self.diagnostic.report(error, LogType::ERROR);
-            });
-       
-            exit(1);
-        };
+        self.start = self.current;
 
         self.tokens.push(Token {
             lexeme: None,
             kind: TokenKind::Eof,
-            line: self.line
+            line: self.line,
+            span: self.span(),
+            column: self.column(),
         });
 
-        self.tokens.as_slice()
+        (self.tokens.as_slice(), mem::take(&mut self.errors))
     }
 
     fn scan(&mut self) -> Result<(), ThrushError> {
@@ -63,7 +63,16 @@ impl<'a> Lexer<'a> {
             b'{' => self.make(TokenKind::LBrace),
             b'}' => self.make(TokenKind::RBrace),
             b',' => self.make(TokenKind::Comma),
-            b'.' if self.char_match(b'.') && self.char_match(b'.') => self.make(TokenKind::Pass),
+            b'?' => self.make(TokenKind::Question),
+            b'.' if self.char_match(b'.') => {
+                if self.char_match(b'.') {
+                    self.make(TokenKind::Pass)
+                } else if self.char_match(b'=') {
+                    self.make(TokenKind::DotDotEq)
+                } else {
+                    self.make(TokenKind::DotDot)
+                }
+            }
             b'.' => self.make(TokenKind::Dot),
             b'%' => self.make(TokenKind::Arith),
             b'*' => self.make(TokenKind::Star),
@@ -81,10 +90,8 @@ impl<'a> Lexer<'a> {
                     return Err(ThrushError::Lex(
                         ThrushErrorKind::SyntaxError,
         
-                        String::from("Syntax Error"),
-                        String::from(
-                            "Unterminated multiline comment. Did you forget to close the string with a '*/'?",
-                        ),
+                        message("E0001.title", &[]),
+                        message("E0001.unterminated_comment", &[]),
                         self.line,
                     ));
                 }
@@ -94,6 +101,7 @@ impl<'a> Lexer<'a> {
             b'/' => self.make(TokenKind::Slash),
             b';' => self.make(TokenKind::SemiColon),
             b'-' if self.char_match(b'-') => self.make(TokenKind::MinusMinus),
+            b'-' if self.char_match(b'>') => self.make(TokenKind::Arrow),
             b'-' => self.make(TokenKind::Minus),
             b'+' if self.char_match(b'+') => self.make(TokenKind::PlusPlus),
             b'+' => self.make(TokenKind::Plus),
@@ -102,6 +110,7 @@ impl<'a> Lexer<'a> {
             b'!' if self.char_match(b'=') => self.make(TokenKind::BangEq),
             b'!' => self.make(TokenKind::Bang),
             b'=' if self.char_match(b'=') => self.make(TokenKind::EqEq),
+            b'=' if self.char_match(b'>') => self.make(TokenKind::FatArrow),
             b'=' => self.make(TokenKind::Eq),
             b'<' if self.char_match(b'=') => self.make(TokenKind::LessEq),
             b'<' => self.make(TokenKind::Less),
@@ -109,8 +118,10 @@ impl<'a> Lexer<'a> {
             b'>' => self.make(TokenKind::Greater),
             b'|' if self.char_match(b'|') => self.make(TokenKind::Or),
             b'&' if self.char_match(b'&') => self.make(TokenKind::And),
+            b'&' => self.make(TokenKind::Ampersand),
             b' ' | b'\r' | b'\t' => {}
             b'\n' => self.line += 1,
+            b'\'' if self.is_alpha(self.peek()) && self.peek_next() != b'\'' => self.label(),
             b'\'' => self.char()?,
             b'"' => self.string()?,
             b'0'..=b'9' => self.integer_or_float()?,
@@ -118,8 +129,8 @@ impl<'a> Lexer<'a> {
             _ => {
                 return Err(ThrushError::Lex(
                     ThrushErrorKind::UnknownChar,
-                    String::from("Unknown character."),
-                    String::from("Did you provide a valid character?"),
+                    message("E0004.title", &[]),
+                    message("E0004.unknown_character", &[]),
                     self.line,
                 ));
             }
@@ -136,6 +147,7 @@ impl<'a> Lexer<'a> {
 
         match str::from_utf8(&self.code[self.start..self.current]).unwrap() {
             "var" => self.make(TokenKind::Var),
+            "let" => self.make(TokenKind::Let),
             "fn" => self.make(TokenKind::Fn),
             "if" => self.make(TokenKind::If),
             "elif" => self.make(TokenKind::Elif),
@@ -148,6 +160,10 @@ impl<'a> Lexer<'a> {
             "and" => self.make(TokenKind::And),
             "const" => self.make(TokenKind::Const),
             "struct" => self.make(TokenKind::Struct),
+            "trait" => self.make(TokenKind::Trait),
+            "impl" => self.make(TokenKind::Impl),
+            "enum" => self.make(TokenKind::Enum),
+            "match" => self.make(TokenKind::Match),
             "return" => self.make(TokenKind::Return),
             "break" => self.make(TokenKind::Break),
             "continue" => self.make(TokenKind::Continue),
@@ -158,6 +174,7 @@ impl<'a> Lexer<'a> {
             "builtin" => self.make(TokenKind::Builtin),
             "@import" => self.make(TokenKind::Import),
             "@external" => self.make(TokenKind::External),
+            "@allow" => self.make(TokenKind::Allow),
             "null" => self.make(TokenKind::Null),
 
             "i8" => self.make(TokenKind::DataType(DataTypes::I8)),
@@ -180,6 +197,8 @@ impl<'a> Lexer<'a> {
                     kind: TokenKind::Identifier,
                     lexeme: Some(self.lexeme()),
                     line: self.line,
+                    span: self.span(),
+                    column: self.column(),
                 });
             }
         }
@@ -203,10 +222,8 @@ impl<'a> Lexer<'a> {
         if num.is_err() {
             return Err(ThrushError::Parse(
                 ThrushErrorKind::ParsedNumber,
-                String::from("The number is too big for an integer or float."),
-                String::from(
-                    "Did you provide a valid number with the correct format and not out of bounds?",
-                ),
+                message("E0003.number_too_big", &[]),
+                message("E0003.invalid_number", &[]),
                 self.line,
             ));
         }
@@ -215,7 +232,9 @@ impl<'a> Lexer<'a> {
             self.tokens.push(Token {
                 kind: TokenKind::Float(kind.0, *num.as_ref().unwrap(), kind.1),
                 lexeme: None,
-                line: self.line
+                line: self.line,
+                span: self.span(),
+                column: self.column(),
             });
 
             return Ok(());
@@ -224,12 +243,28 @@ impl<'a> Lexer<'a> {
         self.tokens.push(Token {
             kind: TokenKind::Integer(kind.0, num.unwrap(), kind.1),
             lexeme: None,
-            line: self.line
+            line: self.line,
+            span: self.span(),
+            column: self.column(),
         });
 
         Ok(())
     }
 
+    fn label(&mut self) {
+        while self.is_alpha(self.peek()) || self.peek().is_ascii_digit() {
+            self.advance();
+        }
+
+        self.tokens.push(Token {
+            kind: TokenKind::Label,
+            lexeme: Some(String::from_utf8_lossy(&self.code[self.start + 1..self.current]).to_string()),
+            line: self.line,
+            span: self.span(),
+            column: self.column(),
+        });
+    }
+
     fn char(&mut self) -> Result<(), ThrushError> {
         while self.peek() != b'\'' && !self.end() {
             self.advance();
@@ -238,10 +273,8 @@ impl<'a> Lexer<'a> {
         if self.peek() != b'\'' {
             return Err(ThrushError::Lex(
                 ThrushErrorKind::SyntaxError,
-                String::from("Syntax Error"),
-                String::from(
-                    "Unterminated char. Did you forget to close the cjar with a '\''?",
-                ),
+                message("E0001.title", &[]),
+                message("E0001.unterminated_char", &[]),
                 self.line,
             ));
         }
@@ -251,10 +284,8 @@ impl<'a> Lexer<'a> {
         if self.code[self.start + 1..self.current - 1].len() > 1 {
             return Err(ThrushError::Lex(
                 ThrushErrorKind::SyntaxError,
-                String::from("Syntax Error"),
-                String::from(
-                    "A char data type only can contain one character.",
-                ),
+                message("E0001.title", &[]),
+                message("E0001.char_length", &[]),
                 self.line,
             ))
         }
@@ -263,6 +294,8 @@ impl<'a> Lexer<'a> {
             kind: TokenKind::Char,
             lexeme: Some(String::from_utf8_lossy(&self.code[self.start + 1..self.current - 1]).to_string()),
             line: self.line,
+            span: self.span(),
+            column: self.column(),
         });
 
         Ok(())
@@ -278,10 +311,8 @@ impl<'a> Lexer<'a> {
         if self.peek() != b'"' {
             return Err(ThrushError::Lex(
                 ThrushErrorKind::SyntaxError,
-                String::from("Syntax Error"),
-                String::from(
-                    "Unterminated string. Did you forget to close the string with a '\"'?",
-                ),
+                message("E0001.title", &[]),
+                message("E0001.unterminated_string", &[]),
                 self.line,
             ));
         }
@@ -299,6 +330,8 @@ impl<'a> Lexer<'a> {
             kind: TokenKind::String,
             lexeme: Some(string),
             line: self.line,
+            span: self.span(),
+            column: self.column(),
         });
 
         Ok(())
@@ -314,8 +347,8 @@ impl<'a> Lexer<'a> {
             if lexeme.chars().filter(|ch| *ch == '.').count() > 1 {
                 return Err(ThrushError::Lex(
                     ThrushErrorKind::SyntaxError, 
-                    String::from("Float Violated Syntax"), 
-                    String::from("Float's values should be only contain one dot."), 
+                    message("E0001.invalid_float_title", &[]), 
+                    message("E0001.invalid_float", &[]), 
                     self.line
                 ));
             } else if lexeme.parse::<f32>().is_ok() {
@@ -326,8 +359,8 @@ impl<'a> Lexer<'a> {
 
             return Err(ThrushError::Parse(
                 ThrushErrorKind::ParsedNumber,
-                String::from("The number is too big for an float."),
-                String::from("Did you provide a valid number with the correct format and not out of bounds?"),
+                message("E0003.float_too_big", &[]),
+                message("E0003.invalid_number", &[]),
                 self.line,
             ));
             
@@ -341,17 +374,15 @@ impl<'a> Lexer<'a> {
                 -9223372036854775808isize..= 9223372036854775807isize => Ok((DataTypes::I64, false)),
                 _ => Err(ThrushError::Parse(
                     ThrushErrorKind::UnreachableNumber,
-                    String::from("Unreacheable Number."),
-                    String::from("The size is out of bounds of an isize (0 to n)."),
+                    message("E0002.title", &[]),
+                    message("E0002.size_out_of_bounds", &[]),
                     self.line,
                 )),
             },
             Err(_) => Err(ThrushError::Parse(
                 ThrushErrorKind::ParsedNumber,
-                String::from("Unreacheable Number"),
-                String::from(
-                    "Did you provide a valid number with the correct format and not out of bounds?",
-                ),
+                message("E0003.title", &[]),
+                message("E0003.invalid_number", &[]),
                 self.line,
             )),
         }
@@ -404,11 +435,25 @@ impl<'a> Lexer<'a> {
         String::from_utf8_lossy(&self.code[self.start..self.current]).to_string()
     }
 
+    #[inline]
+    fn span(&self) -> Span {
+        Span::new(self.start, self.current)
+    }
+
+    fn column(&self) -> usize {
+        match self.code[..self.start].iter().rposition(|ch| *ch == b'\n') {
+            Some(line_start) => self.start - line_start,
+            None => self.start + 1,
+        }
+    }
+
     fn make(&mut self, kind: TokenKind) {
         self.tokens.push(Token {
             kind,
             lexeme: Some(self.lexeme()),
             line: self.line,
+            span: self.span(),
+            column: self.column(),
         });
     }
 }
@@ -418,6 +463,27 @@ pub struct Token {
     pub lexeme: Option<String>,
     pub kind: TokenKind,
     pub line: usize,
+    pub span: Span,
+    pub column: usize,
+}
+
+// Byte offsets of the source code, the end is exclusive.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    #[inline]
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    #[inline]
+    pub fn to(&self, other: Span) -> Span {
+        Span::new(self.start.min(other.start), self.end.max(other.end))
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -438,11 +504,15 @@ pub enum TokenKind {
     LeftBracket,  // ' ] '
     RightBracket, // ' [ '
     Arith,        // ' % ',
+    Question,     // ' ? '
     Bang,         // ' ! '
+    Ampersand,    // ' & '
     ColonColon,   // ' :: '
     BangEq,    // ' != '
     Eq,           // ' = '
     EqEq,         // ' == '
+    FatArrow,     // ' => '
+    Arrow,        // ' -> '
     Greater,      // ' > '
     GreaterEq, // ' >= '
     Less,         // ' < '
@@ -450,6 +520,8 @@ pub enum TokenKind {
     PlusPlus,     // ' ++ '
     MinusMinus,   // ' -- '
     Pass, // ...
+    DotDot,       // ' .. '
+    DotDotEq,     // ' ..= '
 
     // --- Literals ---
     Identifier,
@@ -458,14 +530,21 @@ pub enum TokenKind {
     DataType(DataTypes),
     String,
     Char,
+    Label,
 
     // --- Keywords ---
     Import,
     External,
+    Allow,
     Builtin,
     Public,
     And,
     Struct,
+    Trait,
+    Impl,
+    Enum,
+    Match,
+    Let,
     Else,
     False,
     Fn,
@@ -487,6 +566,7 @@ pub enum TokenKind {
     While,
     Extends,
 
+    Error,
     Eof,
 }
 
@@ -508,11 +588,15 @@ impl std::fmt::Display for TokenKind {
             TokenKind::LeftBracket => write!(f, "["),
             TokenKind::RightBracket => write!(f, "]"),
             TokenKind::Arith => write!(f, "%"),
+            TokenKind::Question => write!(f, "?"),
             TokenKind::Bang => write!(f, "!"),
             TokenKind::ColonColon => write!(f, "::"),
             TokenKind::BangEq => write!(f, "!="),
             TokenKind::Eq => write!(f, "="),
             TokenKind::EqEq => write!(f, "=="),
+            TokenKind::FatArrow => write!(f, "=>"),
+            TokenKind::Arrow => write!(f, "->"),
+            TokenKind::Ampersand => write!(f, "&"),
             TokenKind::Greater => write!(f, ">"),
             TokenKind::GreaterEq => write!(f, ">="),
             TokenKind::Less => write!(f, "<"),
@@ -522,6 +606,11 @@ impl std::fmt::Display for TokenKind {
             TokenKind::Identifier => write!(f, "Identifier"),
             TokenKind::And => write!(f, "and"),
             TokenKind::Struct => write!(f, "struct"),
+            TokenKind::Trait => write!(f, "trait"),
+            TokenKind::Impl => write!(f, "impl"),
+            TokenKind::Enum => write!(f, "enum"),
+            TokenKind::Match => write!(f, "match"),
+            TokenKind::Let => write!(f, "let"),
             TokenKind::Else => write!(f, "else"),
             TokenKind::False => write!(f, "false"),
             TokenKind::Fn => write!(f, "fn"),
@@ -547,10 +636,15 @@ impl std::fmt::Display for TokenKind {
             TokenKind::Float(datatype, _, _) => write!(f, "{}", datatype),
             TokenKind::String => write!(f, "string"),
             TokenKind::Char => write!(f, "char"),
+            TokenKind::Label => write!(f, "label"),
             TokenKind::Builtin => write!(f, "built-in"),
             TokenKind::External => write!(f, "external"),
             TokenKind::Import => write!(f, "@import"),
+            TokenKind::Allow => write!(f, "@allow"),
             TokenKind::Pass => write!(f, "..."),
+            TokenKind::DotDot => write!(f, ".."),
+            TokenKind::DotDotEq => write!(f, "..="),
+            TokenKind::Error => write!(f, "error"),
             TokenKind::Eof => write!(f, "EOF"),
             TokenKind::DataType(datatype) => write!(f, "{}", datatype),
         }
@@ -635,6 +729,49 @@ impl TokenKind {
         false
     }
 
+    #[inline]
+    pub fn as_operator_method(&self) -> Option<&'static str> {
+        match self {
+            TokenKind::Plus => Some("add"),
+            TokenKind::Minus => Some("sub"),
+            TokenKind::Star => Some("mul"),
+            TokenKind::Slash => Some("div"),
+            TokenKind::EqEq => Some("eq"),
+            TokenKind::BangEq => Some("ne"),
+            TokenKind::Less => Some("lt"),
+            TokenKind::LessEq => Some("le"),
+            TokenKind::Greater => Some("gt"),
+            TokenKind::GreaterEq => Some("ge"),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_reflected_operator_method(&self) -> Option<&'static str> {
+        match self {
+            TokenKind::Plus => Some("radd"),
+            TokenKind::Minus => Some("rsub"),
+            TokenKind::Star => Some("rmul"),
+            TokenKind::Slash => Some("rdiv"),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn is_comparison(&self) -> bool {
+        if let TokenKind::EqEq
+        | TokenKind::BangEq
+        | TokenKind::Less
+        | TokenKind::LessEq
+        | TokenKind::Greater
+        | TokenKind::GreaterEq = self
+        {
+            return true;
+        }
+
+        false
+    }
+
 }
 
 #[derive(Debug, PartialEq, Copy, Clone)]
@@ -656,6 +793,18 @@ pub enum DataTypes {
     // String DataTypes
     String,
 
+    // Enum DataType
+    Enum,
+
+    // Function Pointer DataType
+    Fn,
+
+    // Closure DataType (function pointer + environment)
+    Closure,
+
+    // Struct DataType
+    Struct,
+
     // Void Type
     Void,
 }
@@ -673,6 +822,10 @@ impl std::fmt::Display for DataTypes {
             DataTypes::Bool => write!(f, "bool"),
             DataTypes::String => write!(f, "string"),
             DataTypes::Char => write!(f, "char"),
+            DataTypes::Enum => write!(f, "enum"),
+            DataTypes::Fn => write!(f, "fn"),
+            DataTypes::Closure => write!(f, "closure"),
+            DataTypes::Struct => write!(f, "struct"),
             DataTypes::Void => write!(f, "()"),
         }
     }
@@ -702,6 +855,15 @@ impl DataTypes {
         DataTypes::I64
     }
 
+    #[inline]
+    pub fn max_integer_datatype(self, other: DataTypes) -> DataTypes {
+        if self as u8 >= other as u8 {
+            return self;
+        }
+
+        other
+    }
+
     #[inline]
     pub fn is_signed(&self) -> bool {
         if let DataTypes::I64 | DataTypes::I32  | DataTypes::I16  | DataTypes::I8 = self {
@@ -731,6 +893,20 @@ impl DataTypes {
     }
 
 
+    #[inline]
+    pub fn get_iterable_element(&self) -> Option<DataTypes> {
+        /*
+            The strings are the only collections of the language for now, the loops over a `vec`
+            are deferred until the frontend has a vector type. The code generation of `ForEach`
+            already reads any element through `Vec.size` and `Vec.get_*`.
+        */
+
+        match self {
+            DataTypes::String => Some(DataTypes::Char),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn as_llvm_identifier(&self) -> &str {
         match self {
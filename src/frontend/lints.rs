@@ -0,0 +1,714 @@
+use {
+    super::super::{
+        backend::instruction::Instruction,
+        error::{ThrushError, ThrushErrorKind},
+        frontend::lexer::Span,
+        messages::message,
+    },
+    std::collections::HashMap,
+};
+
+/*
+
+    LINTS
+
+    Every warning of the compiler belongs to a lint with a stable name. The lints can be
+    silenced in the source code with `@allow(name, ...)` before of a declaration or a statement,
+    and their level can be changed in the command line with `-W`, `-D` and `--deny-warnings`.
+
+    The group `unused` contains all the lints about unused code, and `warnings` all the lints.
+
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Lint {
+    UnusedVariable,
+    UnusedParameter,
+    UnusedFunction,
+    DeadStore,
+    Shadowing,
+    UnreachableCode,
+    ConstantCondition,
+}
+
+pub const LINTS: [Lint; 7] = [
+    Lint::UnusedVariable,
+    Lint::UnusedParameter,
+    Lint::UnusedFunction,
+    Lint::DeadStore,
+    Lint::Shadowing,
+    Lint::UnreachableCode,
+    Lint::ConstantCondition,
+];
+
+impl Lint {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Lint::UnusedVariable => "W0001",
+            Lint::UnusedParameter => "W0002",
+            Lint::UnusedFunction => "W0003",
+            Lint::DeadStore => "W0004",
+            Lint::Shadowing => "W0005",
+            Lint::UnreachableCode => "W0006",
+            Lint::ConstantCondition => "W0007",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Lint> {
+        match code {
+            "W0001" => Some(Lint::UnusedVariable),
+            "W0002" => Some(Lint::UnusedParameter),
+            "W0003" => Some(Lint::UnusedFunction),
+            "W0004" => Some(Lint::DeadStore),
+            "W0005" => Some(Lint::Shadowing),
+            "W0006" => Some(Lint::UnreachableCode),
+            "W0007" => Some(Lint::ConstantCondition),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lint::UnusedVariable => "unused_variable",
+            Lint::UnusedParameter => "unused_parameter",
+            Lint::UnusedFunction => "unused_function",
+            Lint::DeadStore => "dead_store",
+            Lint::Shadowing => "shadowing",
+            Lint::UnreachableCode => "unreachable_code",
+            Lint::ConstantCondition => "constant_condition",
+        }
+    }
+}
+
+pub fn resolve(name: &str) -> Option<Vec<Lint>> {
+    match name {
+        "warnings" => Some(LINTS.to_vec()),
+        "unused" => Some(vec![
+            Lint::UnusedVariable,
+            Lint::UnusedParameter,
+            Lint::UnusedFunction,
+            Lint::DeadStore,
+        ]),
+        name => LINTS
+            .iter()
+            .find(|lint| lint.name() == name)
+            .map(|lint| vec![*lint]),
+    }
+}
+
+pub fn warning(
+    lint: Lint,
+    title: &str,
+    help: String,
+    line: usize,
+    span: Span,
+) -> (Lint, ThrushError) {
+    (
+        lint,
+        ThrushError::Parse(
+            ThrushErrorKind::Lint(lint),
+            format!("{} [{}]", title, lint.name()),
+            help,
+            line,
+        )
+        .spanned(span),
+    )
+}
+
+pub fn is_allowed(
+    allowed: &[(Vec<Lint>, usize, usize)],
+    lint: Lint,
+    warning: &ThrushError,
+) -> bool {
+    // The `@allow` attribute covers the lines of the declaration or the statement that follows it.
+    let line: usize = warning.line();
+
+    allowed
+        .iter()
+        .any(|allow| allow.0.contains(&lint) && allow.1 <= line && line <= allow.2)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LintLevel {
+    Warn,
+    Deny,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LintLevels {
+    levels: Vec<(Lint, LintLevel)>,
+    deny_warnings: bool,
+}
+
+impl LintLevels {
+    pub fn set(&mut self, lints: Vec<Lint>, level: LintLevel) {
+        lints.into_iter().for_each(|lint| {
+            self.levels.retain(|(other, _)| *other != lint);
+            self.levels.push((lint, level));
+        });
+    }
+
+    #[inline]
+    pub fn deny_warnings(&mut self) {
+        self.deny_warnings = true;
+    }
+
+    pub fn level(&self, lint: Lint) -> LintLevel {
+        // The level given to a lint by its name has priority over `--deny-warnings`.
+        match self.levels.iter().find(|(other, _)| *other == lint) {
+            Some((_, level)) => *level,
+            None if self.deny_warnings => LintLevel::Deny,
+            None => LintLevel::Warn,
+        }
+    }
+}
+
+/*
+
+    LOCALS LINTS
+
+    Walks the body of a function and reports the variables and the parameters that are never
+    read, the variables that shadow another variable, and the stores whose value is never read.
+
+    The stores are a reaching definitions analysis: the state of a path keeps, for every variable,
+    the stores that may reach the current point. A read marks all of them as used. The loops are
+    walked twice, so the stores of the end of the body reach the reads of the next iteration.
+
+    LOCAL
+
+    (&str, usize, bool, bool, Span)
+     ^^^^  ^^^^^  ^^^^  ^^^^  ^^^^
+    Name - Line - Is Read? - Is Parameter? - Span of the Declaration
+
+    STORE
+
+    (&str, usize, usize, bool, Span)
+     ^^^^  ^^^^^  ^^^^^  ^^^^  ^^^^
+    Name - Line of the Declaration - Line - Is Read? - Span of the Store
+
+*/
+
+type Local<'instr> = (&'instr str, usize, bool, bool, Span);
+type Store<'instr> = (&'instr str, usize, usize, bool, Span);
+
+type State<'instr> = Option<HashMap<&'instr str, Vec<usize>>>;
+
+pub fn analyze<'instr>(
+    body: &Instruction<'instr>,
+    params: &[Instruction<'instr>],
+) -> Vec<(Lint, ThrushError)> {
+    let mut analysis: Analysis = Analysis {
+        warnings: Vec::new(),
+        params: params
+            .iter()
+            .filter_map(|param| match param {
+                Instruction::Param { name, .. } => Some(*name),
+                _ => None,
+            })
+            .collect(),
+        scopes: Vec::new(),
+        stores: Vec::new(),
+        loops: Vec::new(),
+        captured: Vec::new(),
+        repeating: 0,
+    };
+
+    analysis.walk(body, &mut Some(HashMap::new()));
+    analysis.report_dead_stores();
+
+    analysis.warnings
+}
+
+struct Analysis<'instr> {
+    warnings: Vec<(Lint, ThrushError)>,
+    params: Vec<&'instr str>,
+    scopes: Vec<Vec<Local<'instr>>>,
+    stores: Vec<Store<'instr>>,
+    loops: Vec<(Option<&'instr str>, Vec<State<'instr>>, Vec<State<'instr>>)>,
+    captured: Vec<&'instr str>,
+    repeating: usize,
+}
+
+impl<'instr> Analysis<'instr> {
+    fn walk(&mut self, instr: &Instruction<'instr>, state: &mut State<'instr>) {
+        match instr {
+            Instruction::Var {
+                name,
+                value,
+                line,
+                span,
+                only_comptime,
+                ..
+            } => {
+                self.walk(value, state);
+                self.declare(name, *line, *span, *only_comptime);
+
+                if let Some(stores) = state {
+                    if matches!(value.as_ref(), Instruction::Null) {
+                        stores.remove(*name);
+                    } else {
+                        let store: usize = self.store(name, *line, *span);
+                        stores.insert(*name, vec![store]);
+                    }
+                }
+            }
+
+            Instruction::MutVar {
+                name,
+                value,
+                line,
+                span,
+                ..
+            } => {
+                self.walk(value, state);
+
+                if !self.is_local(name) {
+                    return;
+                }
+
+                if let Some(stores) = state {
+                    let store: usize = self.store(name, *line, *span);
+                    stores.insert(*name, vec![store]);
+                }
+            }
+
+            Instruction::RefVar { name, .. }
+            | Instruction::Indexe { origin: name, .. }
+            | Instruction::Property { name, .. } => self.read(name, state),
+
+            Instruction::MutProperty { name, value, .. } => {
+                self.read(name, state);
+                self.walk(value, state);
+            }
+
+            Instruction::MethodCall {
+                receiver: name,
+                args,
+                ..
+            }
+            | Instruction::IndirectCall { name, args, .. } => {
+                self.read(name, state);
+                args.iter().for_each(|arg| self.walk(arg, state));
+            }
+
+            Instruction::Closure { captures, .. } => captures.iter().for_each(|capture| {
+                // The closures can modify the variables captured by reference after of a store.
+                if capture.3 {
+                    self.captured.push(capture.0);
+                }
+
+                self.read(capture.0, state);
+            }),
+
+            Instruction::Block { stmts } => {
+                let mut shadowed: Vec<(&str, Option<Vec<usize>>)> = Vec::new();
+
+                self.scopes.push(Vec::new());
+
+                stmts.iter().for_each(|stmt| {
+                    if let Instruction::Var { name, .. } = stmt {
+                        shadowed.push((*name, previous(state, name)));
+                    }
+
+                    self.walk(stmt, state);
+                });
+
+                self.end_scope();
+
+                restore(state, shadowed);
+            }
+
+            Instruction::If {
+                cond,
+                block,
+                otherwise,
+                ..
+            } => {
+                self.walk(cond, state);
+
+                let mut then_state: State = state.clone();
+
+                self.walk(block, &mut then_state);
+
+                if let Some(otherwise) = otherwise {
+                    self.walk(otherwise, state);
+                }
+
+                *state = join(vec![then_state, state.take()]);
+            }
+
+            Instruction::Match { value, arms, .. } => {
+                self.walk(value, state);
+
+                let branches: Vec<State> = arms
+                    .iter()
+                    .map(|arm| {
+                        let mut arm_state: State = state.clone();
+                        self.walk(&arm.2, &mut arm_state);
+                        arm_state
+                    })
+                    .collect();
+
+                *state = join(branches);
+            }
+
+            Instruction::ForLoop {
+                variable,
+                cond,
+                actions,
+                block,
+                label,
+            } => {
+                let mut shadowed: Vec<(&str, Option<Vec<usize>>)> = Vec::new();
+
+                self.scopes.push(Vec::new());
+
+                if let Some(variable) = variable {
+                    if let Instruction::Var { name, .. } = variable.as_ref() {
+                        shadowed.push((*name, previous(state, name)));
+                    }
+
+                    self.walk(variable, state);
+                }
+
+                self.walk_loop(
+                    cond.as_deref(),
+                    cond.is_some(),
+                    block,
+                    actions.as_deref(),
+                    label,
+                    state,
+                );
+
+                self.end_scope();
+
+                restore(state, shadowed);
+            }
+
+            Instruction::ForRange {
+                start,
+                end,
+                step,
+                block,
+                label,
+                ..
+            } => {
+                self.walk(start, state);
+                self.walk(end, state);
+
+                if let Some(step) = step {
+                    self.walk(step, state);
+                }
+
+                self.walk_loop(None, true, block, None, label, state);
+            }
+
+            Instruction::ForEach {
+                iterable,
+                block,
+                label,
+                ..
+            } => {
+                self.walk(iterable, state);
+                self.walk_loop(None, true, block, None, label, state);
+            }
+
+            Instruction::Break { label } => {
+                if let Some(target) = self.find_loop(label) {
+                    target.1.push(state.take());
+                }
+
+                *state = None;
+            }
+
+            Instruction::Continue { label } => {
+                if let Some(target) = self.find_loop(label) {
+                    target.2.push(state.take());
+                }
+
+                *state = None;
+            }
+
+            Instruction::Return(value, _) => {
+                self.walk(value, state);
+                *state = None;
+            }
+
+            Instruction::Call { args, .. }
+            | Instruction::EnumValue { args, .. }
+            | Instruction::StructLiteral { fields: args, .. }
+            | Instruction::Println(args)
+            | Instruction::Print(args) => args.iter().for_each(|arg| self.walk(arg, state)),
+
+            Instruction::Binary { left, right, .. } => {
+                self.walk(left, state);
+                self.walk(right, state);
+            }
+
+            Instruction::Unary { value, .. }
+            | Instruction::Unwrap { value, .. }
+            | Instruction::Yield { value, .. }
+            | Instruction::Propagate { value, .. }
+            | Instruction::Group { instr: value, .. } => self.walk(value, state),
+
+            _ => {}
+        }
+    }
+
+    fn walk_loop(
+        &mut self,
+        cond: Option<&Instruction<'instr>>,
+        is_conditional: bool,
+        block: &Instruction<'instr>,
+        actions: Option<&Instruction<'instr>>,
+        label: &Option<&'instr str>,
+        state: &mut State<'instr>,
+    ) {
+        /*
+            The loops without condition only exit with a `break`, the rest also exit when the
+            condition is false.
+        */
+
+        let mut head: State = state.take();
+        let mut exits: Vec<State> = Vec::new();
+
+        for pass in 0..2 {
+            if let Some(cond) = cond {
+                self.walk(cond, &mut head);
+            }
+
+            let mut body_state: State = head.clone();
+
+            if pass == 1 {
+                self.repeating += 1;
+            }
+
+            self.loops.push((*label, Vec::new(), Vec::new()));
+            self.walk(block, &mut body_state);
+
+            let (_, breaks, mut continues) = self.loops.pop().unwrap();
+
+            continues.push(body_state);
+
+            let mut end: State = join(continues);
+
+            if let Some(actions) = actions {
+                self.walk(actions, &mut end);
+            }
+
+            if pass == 1 {
+                self.repeating -= 1;
+            }
+
+            exits = breaks;
+
+            if is_conditional {
+                exits.push(head.clone());
+            }
+
+            head = join(vec![head, end]);
+        }
+
+        *state = join(exits);
+    }
+
+    fn find_loop(
+        &mut self,
+        label: &Option<&str>,
+    ) -> Option<&mut (Option<&'instr str>, Vec<State<'instr>>, Vec<State<'instr>>)> {
+        match label {
+            Some(label) => self
+                .loops
+                .iter_mut()
+                .rev()
+                .find(|target| target.0 == Some(*label)),
+            None => self.loops.last_mut(),
+        }
+    }
+
+    fn declare(&mut self, name: &'instr str, line: usize, span: Span, only_comptime: bool) {
+        let is_parameter: bool =
+            only_comptime && self.scopes.len() == 1 && self.params.contains(&name);
+
+        if !only_comptime && self.repeating == 0 {
+            if let Some(shadowed) = self.find_local(name) {
+                let (shadowed_line, shadowed_span): (usize, Span) = (shadowed.1, shadowed.4);
+
+                let (lint, shadowing): (Lint, ThrushError) = warning(
+                    Lint::Shadowing,
+                    &message("W0005.title", &[]),
+                    message("W0005.shadowing", &[&name, &shadowed_line]),
+                    line,
+                    span,
+                );
+
+                self.warnings.push((
+                    lint,
+                    shadowing.label(shadowed_span, message("W0005.shadowed_here", &[])),
+                ));
+            }
+        }
+
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push((name, line, false, is_parameter, span));
+        }
+    }
+
+    fn end_scope(&mut self) {
+        let Some(scope) = self.scopes.pop() else {
+            return;
+        };
+
+        if self.repeating > 0 {
+            return;
+        }
+
+        scope
+            .iter()
+            .filter(|local| !local.2 && !local.0.starts_with('_') && local.0 != "this")
+            .for_each(|local| {
+                let (lint, title, help): (Lint, String, String) = if local.3 {
+                    (
+                        Lint::UnusedParameter,
+                        message("W0002.title", &[]),
+                        message("W0002.unused_parameter", &[&local.0, &local.0]),
+                    )
+                } else {
+                    (
+                        Lint::UnusedVariable,
+                        message("W0001.title", &[]),
+                        message("W0001.unused_variable", &[&local.0, &local.0]),
+                    )
+                };
+
+                self.warnings
+                    .push(warning(lint, &title, help, local.1, local.4));
+            });
+    }
+
+    fn read(&mut self, name: &str, state: &mut State<'instr>) {
+        if let Some(local) = self
+            .scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.iter_mut().rev().find(|local| local.0 == name))
+        {
+            local.2 = true;
+        }
+
+        let Some(stores) = state else {
+            return;
+        };
+
+        if let Some(reaching) = stores.get(name) {
+            reaching
+                .iter()
+                .for_each(|store| self.stores[*store].3 = true);
+        }
+    }
+
+    fn store(&mut self, name: &'instr str, line: usize, span: Span) -> usize {
+        // The second walk of a loop finds the same stores of the first one.
+        if let Some(position) = self
+            .stores
+            .iter()
+            .position(|store| store.0 == name && store.2 == line)
+        {
+            return position;
+        }
+
+        let declaration: usize = self.find_local(name).map_or(0, |local| local.1);
+
+        self.stores.push((name, declaration, line, false, span));
+
+        self.stores.len() - 1
+    }
+
+    fn report_dead_stores(&mut self) {
+        /*
+            The variables without reads are reported as unused, only the stores of the variables
+            that are read somewhere are reported.
+        */
+
+        let dead_stores: Vec<(&str, usize, Span)> = self
+            .stores
+            .iter()
+            .filter(|store| {
+                !store.3
+                    && !store.0.starts_with('_')
+                    && !self.captured.contains(&store.0)
+                    && self
+                        .stores
+                        .iter()
+                        .any(|other| other.0 == store.0 && other.1 == store.1 && other.3)
+            })
+            .map(|store| (store.0, store.2, store.4))
+            .collect();
+
+        dead_stores.into_iter().for_each(|(name, line, span)| {
+            self.warnings.push(warning(
+                Lint::DeadStore,
+                &message("W0004.title", &[]),
+                message("W0004.dead_store", &[&name]),
+                line,
+                span,
+            ));
+        });
+    }
+
+    #[inline]
+    fn find_local(&self, name: &str) -> Option<&Local<'instr>> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.iter().rev().find(|local| local.0 == name))
+    }
+
+    #[inline]
+    fn is_local(&self, name: &str) -> bool {
+        self.find_local(name).is_some()
+    }
+}
+
+fn join(branches: Vec<State<'_>>) -> State<'_> {
+    let mut joined: State = None;
+
+    branches.into_iter().flatten().for_each(|stores| {
+        let joined: &mut HashMap<&str, Vec<usize>> = joined.get_or_insert_with(HashMap::new);
+
+        stores.into_iter().for_each(|(name, reaching)| {
+            let all: &mut Vec<usize> = joined.entry(name).or_default();
+
+            reaching.into_iter().for_each(|store| {
+                if !all.contains(&store) {
+                    all.push(store);
+                }
+            });
+        });
+    });
+
+    joined
+}
+
+#[inline]
+fn previous(state: &State<'_>, name: &str) -> Option<Vec<usize>> {
+    state.as_ref().and_then(|stores| stores.get(name).cloned())
+}
+
+fn restore<'instr>(state: &mut State<'instr>, shadowed: Vec<(&'instr str, Option<Vec<usize>>)>) {
+    let Some(stores) = state else {
+        return;
+    };
+
+    shadowed
+        .into_iter()
+        .rev()
+        .for_each(|(name, previous)| match previous {
+            Some(reaching) => {
+                stores.insert(name, reaching);
+            }
+            None => {
+                stores.remove(name);
+            }
+        });
+}
@@ -1,5 +1,10 @@
+mod captures;
+mod control_flow;
+mod initialization;
 pub mod lexer;
+pub mod lints;
 mod objects;
 pub mod parser;
 mod scoper;
+mod suggestions;
 mod type_checking;
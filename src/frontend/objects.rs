@@ -1,7 +1,8 @@
 use {
     super::{
-        super::backend::instruction::Instruction,
+        super::backend::instruction::{EnumVariants, Instruction, StructFields},
         super::error::{ThrushError, ThrushErrorKind},
+        super::messages::message,
         lexer::DataTypes,
     },
     ahash::AHashMap as HashMap,
@@ -11,22 +12,89 @@ use {
 
     LOCALS OBJECTS
 
-    (DataTypes, bool, bool, bool, usize)---------> Number the References
-     ^^^^^^^|   ^^^|    |____   |_______
-    Main Type - Is Null? - is_freeded - Free Only
+    (DataTypes, bool, bool, bool, usize, &str)---------> Type Name (enums)
+     ^^^^^^^|   ^^^|    |____   |_______  |_______
+    Main Type - Is Null? - is_freeded - Free Only - Number the References
 
     GLOBALS OBJECTS
 
-    (DataTypes, Vec<DataTypes>, bool, bool)
+    (DataTypes, Vec<DataTypes>, bool, bool, &str)--------> Type Name (function signature)
      ^^^^^^^|   ^^^|^^^^^^^^^^  ^|^^   ^^^ -------
     Main Type - Param Types? - Is Function? - Ignore Params?
 
+    ENUMS OBJECTS
+
+    Vec<(&str, Vec<DataTypes>)>
+         ^^^^  ^^^^^^^^^^^^^^
+    Variant Name - Payload Types
+
+    SIGNATURES OBJECTS
+
+    (Vec<(DataTypes, &str)>, DataTypes, &str)
+     ^^^^^^^^^^^^^^^^^^^^^   ^^^^^^^^^  ^^^^
+    Params (Type, Type Name) - Return Type - Return Type Name
+
+    GENERICS OBJECTS
+
+    (usize, Vec<&str>, Vec<GenericType>, GenericType, usize, Vec<(&str, &str)>)
+     ^^^^^  ^^^^^^^^^  ^^^^^^^^^^^^^^^^  ^^^^^^^^^^^  ^^^^^  ^^^^^^^^^^^^^^^^^
+    Fn Token Position - Type Parameters - Params - Return Type - Line - Bounds (Type Parameter, Trait)
+
+    (DataTypes, &str, Option<&str>)
+     ^^^^^^^^^  ^^^^  ^^^^^^^^^^^^
+    Type - Type Name - Type Parameter (if the type is generic)
+
+    STRUCTS OBJECTS
+
+    Vec<(&str, DataTypes, &str)>
+         ^^^^  ^^^^^^^^^  ^^^^
+    Field Name - Type - Type Name
+
+    TRAITS OBJECTS
+
+    Vec<(&str, Signature)>
+         ^^^^  ^^^^^^^^^
+    Method Name - Signature (without the receiver)
+
+    METHODS OBJECTS
+
+    (&str, &str, &str)
+     ^^^^  ^^^^  ^^^^
+    Struct - Trait (empty if is inherent) - Method Name ---> Mangled Name
+
+    ASSOCIATED FUNCTIONS OBJECTS
+
+    (&str, &str)
+     ^^^^  ^^^^
+    Struct - Function Name ---> Mangled Name
+
 */
 
-type Locals<'instr> = Vec<HashMap<&'instr str, (DataTypes, bool, bool, bool, usize)>>;
-type Globals<'instr> = HashMap<&'instr str, (DataTypes, Vec<DataTypes>, bool, bool)>;
+type Locals<'instr> = Vec<HashMap<&'instr str, (DataTypes, bool, bool, bool, usize, &'instr str)>>;
+type Globals<'instr> = HashMap<&'instr str, (DataTypes, Vec<DataTypes>, bool, bool, &'instr str)>;
+type Enums<'instr> = HashMap<&'instr str, EnumVariants<'instr>>;
+type Signatures<'instr> = HashMap<&'instr str, Signature<'instr>>;
+type Generics<'instr> = HashMap<&'instr str, Generic<'instr>>;
+type Bindings<'instr> = HashMap<&'instr str, (DataTypes, &'instr str)>;
+type Structs<'instr> = HashMap<&'instr str, StructFields<'instr>>;
+type Traits<'instr> = HashMap<&'instr str, Trait<'instr>>;
+type Methods<'instr> = HashMap<(&'instr str, &'instr str, &'instr str), &'instr str>;
+type Associated<'instr> = HashMap<(&'instr str, &'instr str), &'instr str>;
+
+pub type Trait<'instr> = Vec<(&'instr str, Signature<'instr>)>;
+
+pub type Signature<'instr> = (Vec<(DataTypes, &'instr str)>, DataTypes, &'instr str);
+pub type GenericType<'instr> = (DataTypes, &'instr str, Option<&'instr str>);
+pub type Generic<'instr> = (
+    usize,
+    Vec<&'instr str>,
+    Vec<GenericType<'instr>>,
+    GenericType<'instr>,
+    usize,
+    Vec<(&'instr str, &'instr str)>,
+);
 
-type FoundObject = (
+pub type FoundObject<'instr> = (
     DataTypes,      // Main Type
     bool,           // is null?
     bool,           // is freeded?
@@ -34,11 +102,23 @@ type FoundObject = (
     bool,           // ignore the params if is a function?
     Vec<DataTypes>, // params types
     usize,          // Number the references
+    &'instr str,    // Type name (enums, function signatures)
 );
 
 pub struct ParserObjects<'instr> {
     locals: Locals<'instr>,
     globals: Globals<'instr>,
+    enums: Enums<'instr>,
+    signatures: Signatures<'instr>,
+    interned: HashMap<String, &'instr str>,
+    enclosing: Vec<Locals<'instr>>,
+    generics: Generics<'instr>,
+    instantiations: Vec<(Bindings<'instr>, Locals<'instr>, Vec<Locals<'instr>>)>,
+    structs: Structs<'instr>,
+    traits: Traits<'instr>,
+    methods: Methods<'instr>,
+    associated: Associated<'instr>,
+    implementations: Vec<(&'instr str, &'instr str)>,
 }
 
 impl<'instr> ParserObjects<'instr> {
@@ -46,6 +126,17 @@ impl<'instr> ParserObjects<'instr> {
         Self {
             locals: vec![HashMap::new()],
             globals: HashMap::new(),
+            enums: HashMap::new(),
+            signatures: HashMap::new(),
+            interned: HashMap::new(),
+            enclosing: Vec::new(),
+            generics: HashMap::new(),
+            instantiations: Vec::new(),
+            structs: HashMap::new(),
+            traits: HashMap::new(),
+            methods: HashMap::new(),
+            associated: HashMap::new(),
+            implementations: Vec::new(),
         }
     }
 
@@ -53,21 +144,22 @@ impl<'instr> ParserObjects<'instr> {
         &mut self,
         name: &'instr str,
         line: usize,
-    ) -> Result<FoundObject, ThrushError> {
+    ) -> Result<FoundObject<'instr>, ThrushError> {
         for scope in self.locals.iter_mut().rev() {
             if scope.contains_key(name) {
                 // DataTypes, bool <- (is_null), bool <- (is_freeded), usize <- (number of references)
-                let mut var: (DataTypes, bool, bool, bool, usize) = *scope.get(name).unwrap();
+                let mut var: (DataTypes, bool, bool, bool, usize, &str) = *scope.get(name).unwrap();
 
                 var.4 += 1; // <---------------------- Update Reference Counter (+1)
                 scope.insert(name, var); // ------^^^^^^
 
-                return Ok((var.0, var.1, var.2, false, false, Vec::new(), var.4));
+                return Ok((var.0, var.1, var.2, false, false, Vec::new(), var.4, var.5));
             }
         }
 
         if self.globals.contains_key(name) {
-            let global: &(DataTypes, Vec<DataTypes>, bool, bool) = self.globals.get(name).unwrap();
+            let global: &(DataTypes, Vec<DataTypes>, bool, bool, &str) =
+                self.globals.get(name).unwrap();
 
             let mut possible_params: Vec<DataTypes> = Vec::new();
 
@@ -82,16 +174,18 @@ impl<'instr> ParserObjects<'instr> {
                 global.3,
                 possible_params,
                 0,
+                global.4,
             ));
         }
 
+        if let Some(var) = self.find_enclosing(name) {
+            return Ok((var.0, var.1, var.2, false, false, Vec::new(), var.4, var.5));
+        }
+
         Err(ThrushError::Parse(
             ThrushErrorKind::ObjectNotDefined,
-            String::from("Object don't Found"),
-            format!(
-                "Object with name \"{}\" is don't in this scope or the global scope.",
-                name
-            ),
+            message("E0006.title", &[]),
+            message("E0006.undefined_object", &[&name]),
             line,
         ))
     }
@@ -106,11 +200,64 @@ impl<'instr> ParserObjects<'instr> {
         self.locals.pop();
     }
 
+    #[inline]
+    pub fn begin_lambda_scope(&mut self) {
+        let enclosing: Locals = std::mem::replace(&mut self.locals, vec![HashMap::new()]);
+        self.enclosing.push(enclosing);
+    }
+
+    #[inline]
+    pub fn end_lambda_scope(&mut self) {
+        self.locals = self.enclosing.pop().unwrap();
+    }
+
+    pub fn begin_instantiation(&mut self, bindings: Bindings<'instr>) {
+        let locals: Locals = std::mem::replace(&mut self.locals, vec![HashMap::new()]);
+        let enclosing: Vec<Locals> = std::mem::take(&mut self.enclosing);
+
+        self.instantiations.push((bindings, locals, enclosing));
+    }
+
+    pub fn end_instantiation(&mut self) {
+        let (_, locals, enclosing) = self.instantiations.pop().unwrap();
+
+        self.locals = locals;
+        self.enclosing = enclosing;
+    }
+
+    #[inline]
+    pub fn get_binding(&self, name: &str) -> Option<(DataTypes, &'instr str)> {
+        self.instantiations
+            .last()
+            .and_then(|instantiation| instantiation.0.get(name).copied())
+    }
+
+    #[inline]
+    pub fn get_enclosing(&self, name: &str) -> Option<(DataTypes, &'instr str)> {
+        self.find_enclosing(name).map(|var| (var.0, var.5))
+    }
+
+    fn find_enclosing(
+        &self,
+        name: &str,
+    ) -> Option<(DataTypes, bool, bool, bool, usize, &'instr str)> {
+        self.enclosing
+            .iter()
+            .rev()
+            .flat_map(|locals| locals.iter().rev())
+            .find_map(|scope| scope.get(name).copied())
+    }
+
+    #[inline]
+    pub fn current_scope(&self) -> usize {
+        self.locals.len() - 1
+    }
+
     pub fn insert_new_local(
         &mut self,
         scope_pos: usize,
         name: &'instr str,
-        value: (DataTypes, bool, bool, bool, usize),
+        value: (DataTypes, bool, bool, bool, usize, &'instr str),
     ) {
         self.locals[scope_pos].insert(name, value);
     }
@@ -118,18 +265,235 @@ impl<'instr> ParserObjects<'instr> {
     pub fn insert_new_global(
         &mut self,
         name: &'instr str,
-        value: (DataTypes, Vec<DataTypes>, bool, bool),
+        value: (DataTypes, Vec<DataTypes>, bool, bool, &'instr str),
     ) {
         self.globals.insert(name, value);
     }
 
+    pub fn insert_new_enum(&mut self, name: &'instr str, variants: EnumVariants<'instr>) {
+        self.enums.insert(name, variants);
+    }
+
+    #[inline]
+    pub fn get_enum(&self, name: &str) -> Option<&EnumVariants<'instr>> {
+        self.enums.get(name)
+    }
+
+    pub fn insert_new_generic(&mut self, name: &'instr str, generic: Generic<'instr>) {
+        self.generics.insert(name, generic);
+    }
+
+    #[inline]
+    pub fn get_generic(&self, name: &str) -> Option<&Generic<'instr>> {
+        self.generics.get(name)
+    }
+
+    pub fn insert_new_struct(&mut self, name: &'instr str, fields: StructFields<'instr>) {
+        self.structs.insert(name, fields);
+    }
+
+    #[inline]
+    pub fn get_struct(&self, name: &str) -> Option<&StructFields<'instr>> {
+        self.structs.get(name)
+    }
+
+    pub fn insert_new_trait(&mut self, name: &'instr str, methods: Trait<'instr>) {
+        self.traits.insert(name, methods);
+    }
+
+    #[inline]
+    pub fn get_trait(&self, name: &str) -> Option<&Trait<'instr>> {
+        self.traits.get(name)
+    }
+
+    pub fn insert_new_method(
+        &mut self,
+        owner: (&'instr str, &'instr str),
+        name: &'instr str,
+        mangled: &'instr str,
+    ) {
+        self.methods.insert((owner.0, owner.1, name), mangled);
+    }
+
+    #[inline]
+    pub fn get_method(&self, owner: (&str, &str), name: &str) -> Option<&'instr str> {
+        self.methods.get(&(owner.0, owner.1, name)).copied()
+    }
+
+    pub fn find_methods(&self, struct_name: &str, name: &str) -> Vec<&'instr str> {
+        // The inherent methods shadow the methods of the traits.
+        if let Some(mangled) = self.methods.get(&(struct_name, "", name)) {
+            return vec![*mangled];
+        }
+
+        self.methods
+            .iter()
+            .filter(|(method, _)| method.0 == struct_name && method.2 == name)
+            .map(|(_, mangled)| *mangled)
+            .collect()
+    }
+
+    pub fn insert_new_associated(
+        &mut self,
+        struct_name: &'instr str,
+        name: &'instr str,
+        mangled: &'instr str,
+    ) {
+        self.associated.insert((struct_name, name), mangled);
+    }
+
+    #[inline]
+    pub fn get_associated(&self, struct_name: &str, name: &str) -> Option<&'instr str> {
+        self.associated.get(&(struct_name, name)).copied()
+    }
+
+    #[inline]
+    pub fn has_member(&self, struct_name: &str, name: &str) -> bool {
+        self.methods.contains_key(&(struct_name, "", name))
+            || self.associated.contains_key(&(struct_name, name))
+    }
+
+    pub fn insert_new_implementation(
+        &mut self,
+        struct_name: &'instr str,
+        trait_name: &'instr str,
+    ) -> bool {
+        if self.implements(struct_name, trait_name) {
+            return false;
+        }
+
+        self.implementations.push((struct_name, trait_name));
+
+        true
+    }
+
+    #[inline]
+    pub fn is_result(&self, name: &str) -> bool {
+        name.starts_with("Result<") && self.enums.contains_key(name)
+    }
+
+    #[inline]
+    pub fn is_optional(&self, name: &str) -> bool {
+        name.ends_with('?') && self.enums.contains_key(name)
+    }
+
+    pub fn get_local_type_name(&self, name: &str) -> Option<&'instr str> {
+        self.locals
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name))
+            .map(|var| var.5)
+    }
+
+    pub fn names(&self) -> Vec<&'instr str> {
+        // The names of the values visible from here, for the suggestions of the unknown names.
+        self.locals
+            .iter()
+            .chain(self.enclosing.iter().flatten())
+            .flat_map(|scope| scope.keys())
+            .chain(self.globals.keys())
+            .chain(self.generics.keys())
+            .copied()
+            .collect()
+    }
+
+    pub fn type_names(&self) -> Vec<&'instr str> {
+        self.enums
+            .keys()
+            .chain(self.structs.keys())
+            .copied()
+            .chain(
+                self.instantiations
+                    .last()
+                    .into_iter()
+                    .flat_map(|instantiation| instantiation.0.keys().copied()),
+            )
+            .collect()
+    }
+
+    #[inline]
+    pub fn implements(&self, struct_name: &str, trait_name: &str) -> bool {
+        self.implementations
+            .iter()
+            .any(|implementation| implementation.0 == struct_name && implementation.1 == trait_name)
+    }
+
+    pub fn intern(&mut self, name: String) -> &'instr str {
+        if let Some(interned) = self.interned.get(&name) {
+            return interned;
+        }
+
+        let interned: &'static str = Box::leak(name.clone().into_boxed_str());
+
+        self.interned.insert(name, interned);
+
+        interned
+    }
+
+    pub fn intern_signature(
+        &mut self,
+        kind: DataTypes,
+        params: Vec<(DataTypes, &'instr str)>,
+        return_kind: DataTypes,
+        return_type_name: &'instr str,
+        ignore_more_params: bool,
+    ) -> &'instr str {
+        let type_name = |kind: &DataTypes, name: &str| -> String {
+            if name.is_empty() {
+                kind.to_string()
+            } else {
+                name.to_string()
+            }
+        };
+
+        let mut signature: String = format!(
+            "{}({}",
+            if kind == DataTypes::Closure {
+                "fn[]"
+            } else {
+                "fn"
+            },
+            params
+                .iter()
+                .map(|param| type_name(&param.0, param.1))
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+
+        if ignore_more_params {
+            signature.push_str(if params.is_empty() { "..." } else { ", ..." });
+        }
+
+        signature.push(')');
+
+        if return_kind != DataTypes::Void {
+            signature.push_str(&format!(
+                " -> {}",
+                type_name(&return_kind, return_type_name)
+            ));
+        }
+
+        let signature: &str = self.intern(signature);
+
+        self.signatures
+            .entry(signature)
+            .or_insert((params, return_kind, return_type_name));
+
+        signature
+    }
+
+    #[inline]
+    pub fn get_signature(&self, name: &str) -> Option<&Signature<'instr>> {
+        self.signatures.get(name)
+    }
+
     #[inline]
     pub fn modify_deallocation(&mut self, name: &'instr str, free_only: bool, freeded: bool) {
         for scope in self.locals.iter_mut().rev() {
             if scope.contains_key(name) {
                 // DataTypes, bool <- (is_null), bool <- (is_freeded), bool <- (free_only), usize <- (number of references)
 
-                let mut local_object: (DataTypes, bool, bool, bool, usize) =
+                let mut local_object: (DataTypes, bool, bool, bool, usize, &str) =
                     *scope.get(name).unwrap();
 
                 local_object.1 = freeded;
@@ -146,12 +510,8 @@ impl<'instr> ParserObjects<'instr> {
         let mut frees: Vec<Instruction> = Vec::new();
 
         self.locals[in_scope_pos].iter_mut().for_each(|stmt| {
-            if let (_, (DataTypes::String, false, false, free_only, 0)) = stmt {
-                frees.push(Instruction::Free {
-                    name: stmt.0,
-                    is_string: true,
-                    free_only: *free_only,
-                });
+            if let Some(free) = deallocator(stmt.0, stmt.1) {
+                frees.push(free);
 
                 stmt.1 .2 = true;
             }
@@ -160,6 +520,25 @@ impl<'instr> ParserObjects<'instr> {
         frees
     }
 
+    pub fn create_early_deallocators(&self) -> Vec<Instruction<'instr>> {
+        /*
+            The early exits (`?`) free the live objects of every scope of the function,
+            but they're still alive in the path that continues.
+        */
+
+        self.create_jump_deallocators(0)
+    }
+
+    pub fn create_jump_deallocators(&self, from_scope: usize) -> Vec<Instruction<'instr>> {
+        // The jumps out of a loop (`break`, `continue`) only free the scopes that they exit.
+        self.locals
+            .iter()
+            .skip(from_scope)
+            .flat_map(|scope| scope.iter())
+            .filter_map(|stmt| deallocator(stmt.0, stmt.1))
+            .collect()
+    }
+
     pub fn decrease_local_references(&mut self) {
         self.locals.iter_mut().for_each(|scope| {
             scope.values_mut().for_each(|variable| {
@@ -170,3 +549,26 @@ impl<'instr> ParserObjects<'instr> {
         });
     }
 }
+
+fn deallocator<'instr>(
+    name: &'instr str,
+    object: &(DataTypes, bool, bool, bool, usize, &'instr str),
+) -> Option<Instruction<'instr>> {
+    match object {
+        (DataTypes::String, false, false, free_only, 0, _) => Some(Instruction::Free {
+            name,
+            is_string: true,
+            free_only: *free_only,
+            is_closure: false,
+        }),
+
+        (DataTypes::Closure, false, false, free_only, 0, _) => Some(Instruction::Free {
+            name,
+            is_string: false,
+            free_only: *free_only,
+            is_closure: true,
+        }),
+
+        _ => None,
+    }
+}
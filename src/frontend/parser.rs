@@ -1,82 +1,123 @@
 use {
     super::{
         super::{
-            backend::{compiler::options::ThrushFile, instruction::Instruction},
-            diagnostic::Diagnostic,
+            backend::{compiler::options::ThrushFile, instruction::{Capture, Instruction, MatchArm, StructFields}},
             error::{ThrushError, ThrushErrorKind},
-            logging::{self, LogType},
-        }, lexer::{DataTypes, Token, TokenKind}, objects::ParserObjects, scoper::ThrushScoper, type_checking
+            messages::message,
+        }, captures, control_flow, initialization, lexer::{DataTypes, Span, Token, TokenKind}, lints::{self, Lint, LintLevel, LintLevels}, objects::{FoundObject, Generic, GenericType, ParserObjects, Signature, Trait}, scoper::ThrushScoper, suggestions, type_checking
     },
-    std::{mem, process},
+    std::mem,
 };
 
+// (Pattern token, Position of the variant or None for `_`, Names bound to the payload)
+type Pattern<'instr> = (&'instr Token, Option<usize>, Vec<Option<&'instr str>>);
+
+// (Arguments, Span of each argument, Span of the parentheses)
+type Arguments<'instr> = (Vec<Instruction<'instr>>, Vec<Span>, Span);
+
 pub struct Parser<'instr> {
     stmts: Vec<Instruction<'instr>>,
     errors: Vec<ThrushError>,
+    warnings: Vec<(Lint, ThrushError)>,
+    lints: LintLevels,
+    allowed: Vec<(Vec<Lint>, usize, usize)>,
+    functions: Vec<(&'instr str, usize, Span)>,
+    references: Vec<&'instr str>,
     tokens: &'instr [Token],
     in_function: bool,
     in_type_function: DataTypes,
+    in_type_name_function: &'instr str,
     in_var_type: DataTypes,
+    in_enum_type: &'instr str,
     current: usize,
     objects: ParserObjects<'instr>,
     scope: usize,
     scoper: ThrushScoper<'instr>,
-    diagnostic: Diagnostic,
     has_entry_point: bool,
     is_main: bool,
+    // (Label, Scope of the body)
+    loops: Vec<(Option<&'instr str>, usize)>,
+    lambdas: usize,
+    borrowing_closures: Vec<&'instr str>,
+    moved_closures: Vec<&'instr str>,
+    instances: Vec<&'instr str>,
+    instance: Option<&'instr str>,
+    receiver: Option<(&'instr str, &'instr str)>,
 }
 
 impl<'instr> Parser<'instr> {
-    pub fn new(tokens: &'instr [Token], file: &ThrushFile) -> Self {
+    pub fn new(tokens: &'instr [Token], file: &ThrushFile, lints: &LintLevels) -> Self {
         Self {
             stmts: Vec::new(),
             errors: Vec::new(),
+            warnings: Vec::new(),
+            lints: lints.clone(),
+            allowed: Vec::new(),
+            functions: Vec::new(),
+            references: Vec::new(),
             tokens,
             current: 0,
             in_function: false,
             in_type_function: DataTypes::Void,
+            in_type_name_function: "",
             in_var_type: DataTypes::Void,
+            in_enum_type: "",
             scope: 0,
             has_entry_point: false,
             is_main: file.is_main,
-            scoper: ThrushScoper::new(file),
-            diagnostic: Diagnostic::new(file),
+            loops: Vec::new(),
+            lambdas: 0,
+            borrowing_closures: Vec::new(),
+            moved_closures: Vec::new(),
+            instances: Vec::new(),
+            instance: None,
+            receiver: None,
+            scoper: ThrushScoper::new(),
             objects: ParserObjects::new(),
         }
     }
 
-    pub fn start(&mut self) -> &[Instruction<'instr>] {
-        self.forward_declare_functions();
+    pub fn start(&mut self) -> (&[Instruction<'instr>], Vec<ThrushError>, Vec<ThrushError>) {
+        self.forward_declare();
 
         while !self.end() {
+            let position: usize = self.current;
+
             match self.parse() {
                 Ok(instr) => {
                     self.stmts.push(instr);
                 }
                 Err(e) => {
                     self.errors.push(e);
-                    self.sync();
+                    self.sync(position);
                 }
             }
         }
-        if !self.errors.is_empty() {
-            self.errors.iter().for_each(|error| {
-                self.diagnostic.report(error, LogType::ERROR);
-            });
 
-            process::exit(1);
-        } else if self.is_main && !self.has_entry_point {
-            logging::log(
-                logging::LogType::ERROR,
-                "Missing entrypoint \"fn main() {}\" in main.th file.",
-            );
+        self.unused_functions();
+
+        let mut warnings: Vec<ThrushError> = Vec::with_capacity(self.warnings.len());
+
+        mem::take(&mut self.warnings)
+            .into_iter()
+            .filter(|(lint, warning)| !lints::is_allowed(&self.allowed, *lint, warning))
+            .for_each(|(lint, warning)| match self.lints.level(lint) {
+                LintLevel::Warn => warnings.push(warning),
+                LintLevel::Deny => self.errors.push(warning),
+            });
 
-            process::exit(1);
+        if self.is_main && !self.has_entry_point {
+            self.errors.push(ThrushError::Parse(
+                ThrushErrorKind::MissingEntrypoint,
+                message("E0031.title", &[]),
+                message("E0031.missing_main", &[]),
+                self.peek().line,
+            ));
         }
 
-        self.scoper.analyze();
+        self.errors.extend(self.scoper.analyze());
 
-        self.stmts.as_slice()
+        (self.stmts.as_slice(), mem::take(&mut self.errors), warnings)
     }
 
     fn parse(&mut self) -> Result<Instruction<'instr>, ThrushError> {
@@ -88,8 +129,29 @@ impl<'instr> Parser<'instr> {
             TokenKind::Return => Ok(self.ret()?),
             TokenKind::Public => Ok(self.public()?),
             TokenKind::Var => Ok(self.variable(false)?),
-            TokenKind::For => Ok(self.for_loop()?),
+            TokenKind::For => Ok(self.for_loop(None)?),
+            TokenKind::Label => Ok(self.labeled_loop()?),
+            TokenKind::Break | TokenKind::Continue => Ok(self.loop_jump()?),
             TokenKind::External => Ok(self.external()?),
+            TokenKind::Allow => Ok(self.allow()?),
+            TokenKind::Enum => Ok(self.enum_declaration()?),
+            TokenKind::Struct => Ok(self.struct_declaration()?),
+            TokenKind::Trait => Ok(self.trait_declaration()?),
+            TokenKind::Impl => Ok(self.impl_declaration()?),
+            TokenKind::Match => {
+                let instr: Instruction<'instr> = self.match_expression()?;
+
+                self.match_token(TokenKind::SemiColon)?;
+
+                Ok(instr)
+            }
+            TokenKind::If => {
+                let instr: Instruction<'instr> = self.if_expression()?;
+
+                self.match_token(TokenKind::SemiColon)?;
+
+                Ok(instr)
+            }
             _ => Ok(self.expression()?),
         }
     }
@@ -104,24 +166,24 @@ impl<'instr> Parser<'instr> {
         self.consume(
             TokenKind::LParen,
             ThrushErrorKind::SyntaxError,
-            String::from("Syntax Error"),
-            String::from("Expected '('."),
+            message("E0001.title", &[]),
+            message("E0001.expected_lparen", &[]),
             line,
         )?;
 
         let name: &Token = self.consume(
             TokenKind::String,
             ThrushErrorKind::SyntaxError,
-            String::from("Syntax Error"),
-            String::from("Expected String literal for @external(\"NAME\")."),
+            message("E0001.title", &[]),
+            message("E0001.expected_external_name", &[]),
             line,
         )?;
 
         self.consume(
             TokenKind::RParen,
             ThrushErrorKind::SyntaxError,
-            String::from("Syntax Error"),
-            String::from("Expected ')'."),
+            message("E0001.title", &[]),
+            message("E0001.expected_rparen", &[]),
             line,
         )?;
 
@@ -131,20 +193,145 @@ impl<'instr> Parser<'instr> {
         }
     }
 
-    fn for_loop(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+    fn labeled_loop(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        let label: &Token = self.advance()?;
+        let name: &str = label.lexeme.as_ref().unwrap();
+
+        self.consume(
+            TokenKind::Colon,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_colon_after_label", &[]),
+            label.line,
+        )?;
+
+        if self.peek().kind != TokenKind::For {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.label_without_loop", &[&name, &name]),
+                label.line,
+            )
+            .spanned(label.span));
+        }
+
+        if self.loops.iter().any(|(other, _)| *other == Some(name)) {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::ShadowedLabel,
+                    message("E0035.title", &[]),
+                    message("E0035.shadowed_label", &[&name]),
+                    label.line,
+                )
+                .spanned(label.span),
+            );
+        }
+
+        self.for_loop(Some(name))
+    }
+
+    fn loop_jump(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        let keyword: &Token = self.advance()?;
+
+        let label: Option<&'instr str> = if self.match_token(TokenKind::Label)? {
+            Some(self.previous().lexeme.as_ref().unwrap())
+        } else {
+            None
+        };
+
+        if self.loops.is_empty() {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.jump_outside_loop", &[&keyword.kind]),
+                    keyword.line,
+                )
+                .spanned(keyword.span),
+            );
+        } else if let Some(name) = label {
+            if !self.loops.iter().any(|(other, _)| *other == label) {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::UndeclaredLabel,
+                        message("E0034.title", &[]),
+                        message("E0034.undeclared_label", &[&name]),
+                        keyword.line,
+                    )
+                    .spanned(self.previous().span),
+                );
+            }
+        }
+
+        self.consume(
+            TokenKind::SemiColon,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_semicolon", &[]),
+            keyword.line,
+        )?;
+
+        if keyword.kind == TokenKind::Break {
+            return Ok(Instruction::Break { label });
+        }
+
+        Ok(Instruction::Continue { label })
+    }
+
+    fn jump_deallocators(&self, label: Option<&'instr str>) -> Vec<Instruction<'instr>> {
+        // The scopes from the body of the target loop are exited by the jump.
+        self.loops
+            .iter()
+            .rev()
+            .find(|(other, _)| label.is_none() || *other == label)
+            .map(|(_, scope)| self.objects.create_jump_deallocators(*scope))
+            .unwrap_or_default()
+    }
+
+    fn for_loop(
+        &mut self,
+        label: Option<&'instr str>,
+    ) -> Result<Instruction<'instr>, ThrushError> {
         self.only_advance()?;
 
         let start_line: usize = self.previous().line;
 
+        // `in` is only a keyword between the loop variable and the iterable.
+        if self.peek().kind == TokenKind::Identifier && self.check_next_word("in") {
+            return self.for_in_loop(start_line, label);
+        }
+
         let variable: Instruction<'instr> = self.variable(false)?;
 
+        let cond_start: Span = self.peek().span;
         let cond: Instruction<'instr> = self.expression()?;
+        let cond_span: Span = self.span_from(cond_start);
+
+        match control_flow::constant_condition(&cond) {
+            Some(true) => self.warnings.push(lints::warning(
+                Lint::ConstantCondition,
+                &message("W0007.title", &[]),
+                message("W0007.loop_always_true", &[]),
+                start_line,
+                cond_span,
+            )),
+
+            Some(false) => self.warnings.push(lints::warning(
+                Lint::ConstantCondition,
+                &message("W0007.title", &[]),
+                message("W0007.loop_always_false", &[]),
+                start_line,
+                cond_span,
+            )),
+
+            None => {}
+        }
 
         self.consume(
             TokenKind::SemiColon,
             ThrushErrorKind::SyntaxError,
-            String::from("Syntax Error"),
-            String::from("Expected ';'."),
+            message("E0001.title", &[]),
+            message("E0001.expected_semicolon", &[]),
             start_line,
         )?;
 
@@ -156,156 +343,500 @@ impl<'instr> Parser<'instr> {
             *only_comptime = true;
         }
 
-        let body: Instruction<'instr> = self.block(&mut [variable_clone])?;
+        self.loops.push((label, self.objects.current_scope() + 1));
+
+        let body: Result<Instruction<'instr>, ThrushError> = self.block(&mut [variable_clone]);
+
+        self.loops.pop();
 
         Ok(Instruction::ForLoop {
             variable: Some(Box::new(variable)),
             cond: Some(Box::new(cond)),
             actions: Some(Box::new(actions)),
+            block: Box::new(body?),
+            label,
+        })
+    }
+
+    fn for_in_loop(
+        &mut self,
+        start_line: usize,
+        label: Option<&'instr str>,
+    ) -> Result<Instruction<'instr>, ThrushError> {
+        let name: &Token = self.consume(
+            TokenKind::Identifier,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.expected_variable_name", &[]),
+            message("E0001.expected_for_in_variable", &[]),
+            start_line,
+        )?;
+
+        // Checked by the caller, `for <name> in`.
+        self.only_advance()?;
+
+        let from_start: Span = self.peek().span;
+        let from: Instruction<'instr> = self.expression()?;
+        let from_span: Span = self.span_from(from_start);
+
+        if self.match_token(TokenKind::DotDot)? || self.match_token(TokenKind::DotDotEq)? {
+            let inclusive: bool = self.previous().kind == TokenKind::DotDotEq;
+
+            let to: Instruction<'instr> = self.expression()?;
+
+            // `step` is only a keyword right after the bound of the range.
+            let step: Option<Box<Instruction<'instr>>> = if self.match_word("step")? {
+                let step_start: Span = self.peek().span;
+                let step: Instruction<'instr> = self.expression()?;
+
+                if let Instruction::Integer(_, num, _) = &step {
+                    if *num == 0.0 {
+                        self.errors.push(
+                            ThrushError::Parse(
+                                ThrushErrorKind::SyntaxError,
+                                message("E0001.title", &[]),
+                                message("E0001.zero_range_step", &[]),
+                                start_line,
+                            )
+                            .spanned(self.span_from(step_start)),
+                        );
+                    }
+                }
+
+                Some(Box::new(step))
+            } else {
+                None
+            };
+
+            let from_type: DataTypes = from.get_data_type();
+            let to_type: DataTypes = to.get_data_type();
+
+            let mut bounds_types: Vec<DataTypes> = vec![from_type, to_type];
+
+            if let Some(step) = &step {
+                bounds_types.push(step.get_data_type());
+            }
+
+            bounds_types.iter().for_each(|kind| {
+                if !kind.is_integer() || *kind == DataTypes::Bool || *kind == DataTypes::Char {
+                    self.errors.push(
+                        ThrushError::Parse(
+                            ThrushErrorKind::TypeMismatch,
+                            message("E0010.title", &[]),
+                            message("E0010.non_integer_range", &[&kind]),
+                            start_line,
+                        )
+                        .spanned(self.span_from(from_start)),
+                    );
+                }
+            });
+
+            // The counter is as wide as the widest of the bounds and the step.
+            let kind: DataTypes = bounds_types
+                .iter()
+                .fold(from_type, |kind, other| kind.max_integer_datatype(*other));
+
+            let body: Instruction<'instr> = self.loop_body(name, kind, label)?;
+
+            return Ok(Instruction::ForRange {
+                variable: name.lexeme.as_ref().unwrap(),
+                kind,
+                start: Box::new(from),
+                end: Box::new(to),
+                step,
+                inclusive,
+                block: Box::new(body),
+                label,
+                line: start_line,
+            });
+        }
+
+        if !matches!(
+            from,
+            Instruction::RefVar { .. } | Instruction::String(_, false)
+        ) {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.iterated_expression", &[]),
+                    start_line,
+                )
+                .spanned(from_span),
+            );
+        }
+
+        let kind: DataTypes = match from.get_data_type().get_iterable_element() {
+            Some(kind) => kind,
+            None => {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::TypeMismatch,
+                        message("E0010.title", &[]),
+                        message("E0010.not_iterable", &[&from.get_data_type()]),
+                        start_line,
+                    )
+                    .spanned(from_span),
+                );
+
+                DataTypes::Void
+            }
+        };
+
+        let body: Instruction<'instr> = self.loop_body(name, kind, label)?;
+
+        Ok(Instruction::ForEach {
+            variable: name.lexeme.as_ref().unwrap(),
+            kind,
+            iterable: Box::new(from),
             block: Box::new(body),
+            label,
         })
     }
 
+    fn loop_body(
+        &mut self,
+        variable: &'instr Token,
+        kind: DataTypes,
+        label: Option<&'instr str>,
+    ) -> Result<Instruction<'instr>, ThrushError> {
+        if self.peek().kind != TokenKind::LBrace {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.expected_loop_body", &[]),
+                variable.line,
+            )
+            .spanned(self.peek().span));
+        }
+
+        let name: &str = variable.lexeme.as_ref().unwrap();
+
+        self.objects.begin_local_scope();
+        self.loops.push((label, self.objects.current_scope() + 1));
+
+        self.objects.insert_new_local(
+            self.objects.current_scope(),
+            name,
+            (kind, false, false, false, 0, ""),
+        );
+
+        let body: Result<Instruction<'instr>, ThrushError> = self.block(&mut [Instruction::Var {
+            name,
+            kind,
+            value: Box::new(Instruction::Null),
+            line: variable.line,
+            span: variable.span,
+            only_comptime: true,
+            type_name: "",
+        }]);
+
+        self.loops.pop();
+        self.objects.end_local_scope();
+
+        body
+    }
+
     fn variable(&mut self, only_comptime: bool) -> Result<Instruction<'instr>, ThrushError> {
         self.only_advance()?;
 
         let name: &Token = self.consume(
             TokenKind::Identifier,
             ThrushErrorKind::SyntaxError,
-            String::from("Expected variable name"),
-            String::from("Expected var (name)."),
+            message("E0001.expected_variable_name", &[]),
+            message("E0001.expected_variable_declaration", &[]),
             self.previous().line,
         )?;
 
         if self.peek().kind == TokenKind::SemiColon {
             self.only_advance()?;
 
-            self.errors.push(ThrushError::Parse(
-                ThrushErrorKind::SyntaxError,
-                String::from("Syntax Error"),
-                String::from("Expected type for the variable. You forget the `:`."),
-                name.line,
-            ));
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.missing_variable_colon", &[]),
+                    name.line,
+                )
+                .spanned(name.span),
+            );
         } else if self.peek().kind == TokenKind::Colon {
             self.consume(
                 TokenKind::Colon,
                 ThrushErrorKind::SyntaxError,
-                String::from("Expected variable type indicator"),
-                String::from("Expected `var name --> : <-- type = value;`."),
+                message("E0001.expected_variable_colon", &[]),
+                message("E0001.expected_variable_colon_help", &[]),
                 name.line,
             )?;
         }
 
+        let mut type_name: &str = "";
+        let mut missing_type: bool = false;
+        let type_start: Span = self.peek().span;
+
         let kind: DataTypes = match &self.peek().kind {
             TokenKind::DataType(kind) => {
                 if self.previous().kind != TokenKind::Colon {
-                    self.errors.push(ThrushError::Parse(
-                        ThrushErrorKind::SyntaxError,
-                        String::from("Expected variable type indicator"),
-                        String::from("Expected `var name --> : <-- type = value;`."),
-                        name.line,
-                    ));
+                    self.errors.push(
+                        ThrushError::Parse(
+                            ThrushErrorKind::SyntaxError,
+                            message("E0001.expected_variable_colon", &[]),
+                            message("E0001.expected_variable_colon_help", &[]),
+                            name.line,
+                        )
+                        .spanned(self.peek().span)
+                        .fix(Span::new(name.span.end, name.span.end), String::from(":")),
+                    );
                 }
 
                 self.only_advance()?;
 
-                *kind
+                let optional_type: (DataTypes, &str) = self.optional_suffix(*kind, name.line)?;
+
+                type_name = optional_type.1;
+
+                optional_type.0
+            }
+
+            TokenKind::Identifier if self.is_result_type() => {
+                let result_type: (DataTypes, &str) = self.result_type(name.line)?;
+
+                type_name = result_type.1;
+
+                result_type.0
+            }
+
+            TokenKind::Identifier => {
+                if let Some(named_type) = self.named_type() {
+                    self.only_advance()?;
+
+                    type_name = named_type.1;
+
+                    named_type.0
+                } else {
+                    let custom_type: &Token = self.advance()?;
+
+                    type_name = custom_type.lexeme.as_ref().unwrap();
+
+                    if self.objects.get_enum(type_name).is_none() {
+                        let error: ThrushError = self.suggest(
+                            ThrushError::Parse(
+                                ThrushErrorKind::UnknownType,
+                                message("E0012.title", &[]),
+                                message("E0012.undefined_type", &[&type_name]),
+                                name.line,
+                            ),
+                            custom_type,
+                            self.type_names(),
+                        );
+
+                        self.errors.push(error);
+                    }
+
+                    DataTypes::Enum
+                }
+            }
+
+            TokenKind::Fn => {
+                let fn_type: (DataTypes, &str) = self.fn_type(name.line)?;
+
+                type_name = fn_type.1;
+
+                fn_type.0
+            }
+
+            // The error is reported with the value, its type is the missing annotation.
+            TokenKind::Eq if self.previous().kind != TokenKind::Colon => {
+                missing_type = true;
+                DataTypes::Void
             }
 
             _ => {
-                self.errors.push(ThrushError::Parse(
-                    ThrushErrorKind::SyntaxError,
-                    String::from("Syntax Error"),
-                    String::from("Expected type for the variable."),
-                    name.line,
-                ));
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::SyntaxError,
+                        message("E0001.title", &[]),
+                        message("E0001.expected_variable_type", &[]),
+                        name.line,
+                    )
+                    .spanned(self.peek().span),
+                );
 
                 DataTypes::Void
             }
         };
 
+        let type_span: Span = self.span_from(type_start);
+
         if self.peek().kind == TokenKind::SemiColon && kind == DataTypes::Void {
             self.only_advance()?;
 
-            self.errors.push(ThrushError::Parse(
-                ThrushErrorKind::SyntaxError,
-                String::from("Syntax Error"),
-                String::from(
-                    "Variable type is undefined. Did you forget to specify the variable type to undefined variable?",
-                ),
-                name.line,
-            ));
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.undefined_variable_type", &[]),
+                    name.line,
+                )
+                .spanned(name.span),
+            );
         } else if self.peek().kind == TokenKind::SemiColon {
             self.consume(
                 TokenKind::SemiColon,
                 ThrushErrorKind::SyntaxError,
-                String::from("Syntax Error"),
-                String::from("Expected ';'."),
+                message("E0001.title", &[]),
+                message("E0001.expected_semicolon", &[]),
                 name.line,
             )?;
 
-            self.objects.insert_new_local(self.scope, name.lexeme.as_ref().unwrap(), (kind, true, false, false,  0));
+            // The optionals without value are `null`.
+            if self.objects.is_optional(type_name) {
+                self.objects.insert_new_local(
+                    self.scope,
+                    name.lexeme.as_ref().unwrap(),
+                    (kind, false, false, false, 0, type_name),
+                );
+
+                return Ok(Instruction::Var {
+                    name: name.lexeme.as_ref().unwrap(),
+                    kind,
+                    value: Box::new(Instruction::EnumValue {
+                        name: type_name,
+                        tag: 1,
+                        args: Vec::new(),
+                    }),
+                    line: name.line,
+                    span: name.span,
+                    only_comptime,
+                    type_name,
+                });
+            }
+
+            self.objects.insert_new_local(self.scope, name.lexeme.as_ref().unwrap(), (kind, true, false, false,  0, type_name));
 
             return Ok(Instruction::Var {
                 name: name.lexeme.as_ref().unwrap(),
                 kind,
                 value: Box::new(Instruction::Null),
                 line: name.line,
+                span: name.span,
                 only_comptime,
+                type_name,
             });
         }
 
         self.consume(
             TokenKind::Eq,
             ThrushErrorKind::SyntaxError,
-            String::from("Syntax Error"),
-            String::from("Expected '=' for the variable definition."),
+            message("E0001.title", &[]),
+            message("E0001.expected_variable_eq", &[]),
             name.line,
         )?;
 
         self.in_var_type = kind;
 
-        let value: Instruction<'instr> = self.expression()?;
+        let value_start: Span = self.peek().span;
+
+        let in_enum_type: &str = mem::replace(&mut self.in_enum_type, type_name);
+        let value: Result<Instruction<'instr>, ThrushError> = self.expression();
+
+        self.in_enum_type = in_enum_type;
+
+        let value_span: Span = self.span_from(value_start);
+        let value: Instruction<'instr> =
+            self.wrap_optional(type_name, value?, value_span, name.line);
         let value_type: DataTypes = value.get_data_type();
 
-        if let Err(e) = type_checking::check_type(
+        if missing_type {
+            let error: ThrushError = self.missing_type(name, &value, value_span);
+            self.errors.push(error);
+        } else if let Err(e) = type_checking::check_type(
             value_type,
             kind,
             name.line,
-            String::from("Type Mismatch"),
-            format!(
-                "Type mismatch. Expected '{}' but found '{}'.",
-                kind, value_type
-            ),
+            message("E0010.title", &[]),
+            message("E0010.expected_found", &[&kind, &value_type]),
         ) {
-            self.errors.push(e);
+            self.errors.push(
+                e.spanned(value_span)
+                    .label(type_span, message("E0010.expected_due_to_type", &[])),
+            );
+        } else if matches!(
+            kind,
+            DataTypes::Enum | DataTypes::Fn | DataTypes::Closure | DataTypes::Struct
+        ) && value.get_type_name() != type_name
+        {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::TypeMismatch,
+                    message("E0010.title", &[]),
+                    message(
+                        "E0010.expected_found",
+                        &[&type_name, &value.get_type_name()],
+                    ),
+                    name.line,
+                )
+                .spanned(value_span)
+                .label(type_span, message("E0010.expected_due_to_type", &[])),
+            );
         }
 
+        // Without annotation the variable takes the type of the value, so its uses don't report more errors.
+        let (kind, type_name): (DataTypes, &str) = if missing_type {
+            (value_type, value.get_type_name())
+        } else {
+            (kind, type_name)
+        };
+
         self.objects.insert_new_local(
             self.scope,
             name.lexeme.as_ref().unwrap(),
-            (kind, false, false, false, 0),
+            (kind, false, false, false, 0, type_name),
         );
 
+        self.moved_closures
+            .retain(|moved| *moved != name.lexeme.as_ref().unwrap());
+
         if let Instruction::RefVar { kind, .. } = &value {
             if kind == &DataTypes::String {
                 self.objects.modify_deallocation(name.lexeme.as_ref().unwrap(), true, false);
             }
         }
 
+        // Copying a closure moves the ownership of the environment to the new variable.
+        if let Instruction::RefVar {
+            name: source,
+            kind: DataTypes::Closure,
+            ..
+        } = &value
+        {
+            self.move_closure(source);
+
+            if self.borrowing_closures.contains(source) {
+                self.borrowing_closures.push(name.lexeme.as_ref().unwrap());
+            }
+        }
+
+        if let Instruction::Closure { captures, .. } = &value {
+            if captures.iter().any(|capture| capture.3) {
+                self.borrowing_closures.push(name.lexeme.as_ref().unwrap());
+            }
+        }
+
         let var: Instruction<'_> = Instruction::Var {
             name: name.lexeme.as_ref().unwrap(),
             kind,
             value: Box::new(value),
             line: name.line,
+            span: name.span,
             only_comptime,
+            type_name,
         };
 
         self.consume(
             TokenKind::SemiColon,
             ThrushErrorKind::SyntaxError,
-            String::from("Syntax Error"),
-            String::from("Expected ';'."),
+            message("E0001.title", &[]),
+            message("E0001.expected_semicolon", &[]),
             name.line,
         )?;
 
@@ -322,36 +853,117 @@ impl<'instr> Parser<'instr> {
         }
     }
 
-    fn ret(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+    fn allow(&mut self) -> Result<Instruction<'instr>, ThrushError> {
         self.only_advance()?;
 
         let line: usize = self.previous().line;
 
-        if !self.in_function {
-            self.errors.push(ThrushError::Parse(
+        self.consume(
+            TokenKind::LParen,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_allow_lparen", &[]),
+            line,
+        )?;
+
+        let mut allowed: Vec<Lint> = Vec::new();
+
+        while !self.match_token(TokenKind::RParen)? {
+            if self.match_token(TokenKind::Comma)? {
+                continue;
+            }
+
+            let lint: &Token = self.consume(
+                TokenKind::Identifier,
                 ThrushErrorKind::SyntaxError,
-                String::from("Syntax Error"),
-                String::from("Return statement outside of function. Invoke this keyword in scope of function."),
-                line
+                message("E0001.title", &[]),
+                message("E0001.expected_lint_name", &[]),
+                line,
+            )?;
+
+            match lints::resolve(lint.lexeme.as_ref().unwrap()) {
+                Some(lints) => allowed.extend(lints),
+                None => self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::UnknownLint,
+                        message("E0019.title", &[]),
+                        message("E0019.unknown_lint", &[&lint.lexeme.as_ref().unwrap()]),
+                        line,
+                    )
+                    .spanned(lint.span),
+                ),
+            }
+        }
+
+        let start: usize = self.peek().line;
+        let instr: Instruction<'instr> = self.parse()?;
+
+        self.allowed.push((allowed, start, self.previous().line));
+
+        Ok(instr)
+    }
+
+    fn unused_functions(&mut self) {
+        let unused: Vec<(&str, usize, Span)> = self
+            .functions
+            .iter()
+            .filter(|function| {
+                !function.0.starts_with('_') && !self.references.contains(&function.0)
+            })
+            .copied()
+            .collect();
+
+        unused.iter().for_each(|(name, line, span)| {
+            self.warnings.push(lints::warning(
+                Lint::UnusedFunction,
+                &message("W0003.title", &[]),
+                message("W0003.unused_function", &[&name]),
+                *line,
+                *span,
             ));
+        });
+    }
+
+    fn ret(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        self.only_advance()?;
+
+        let line: usize = self.previous().line;
+        let start: Span = self.previous().span;
+
+        if !self.in_function {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.return_outside_function", &[]),
+                    line,
+                )
+                .spanned(self.previous().span),
+            );
         }
 
         if self.peek().kind == TokenKind::SemiColon {
             self.consume(
                 TokenKind::SemiColon,
                 ThrushErrorKind::SyntaxError,
-                String::from("Syntax Error"),
-                String::from("Expected ';'."),
+                message("E0001.title", &[]),
+                message("E0001.expected_semicolon", &[]),
                 line,
             )?;
 
             if self.in_type_function != DataTypes::Void {
-                self.errors.push(ThrushError::Parse(
-                    ThrushErrorKind::SyntaxError,
-                    String::from("Syntax Error"),
-                    format!("Missing return statement with correctly type '{}', you should rewrite for return with type '{}'.", self.in_type_function, self.in_type_function),
-                    line,
-                ));
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::SyntaxError,
+                        message("E0001.title", &[]),
+                        message(
+                            "E0001.return_without_value",
+                            &[&self.in_type_function, &self.in_type_function],
+                        ),
+                        line,
+                    )
+                    .spanned(self.span_from(start)),
+                );
             }
 
             return Ok(Instruction::Return(
@@ -360,40 +972,96 @@ impl<'instr> Parser<'instr> {
             ));
         }
 
-        let value: Instruction<'instr> = self.expression()?;
+        let value_start: Span = self.peek().span;
+
+        let in_enum_type: &str = mem::replace(&mut self.in_enum_type, self.in_type_name_function);
+        let value: Result<Instruction<'instr>, ThrushError> = self.expression();
+
+        self.in_enum_type = in_enum_type;
+
+        let value: Instruction<'instr> = value?;
+        let value_span: Span = self.span_from(value_start);
 
         if let Instruction::RefVar { name, kind, .. } = value {
-            if kind == DataTypes::String {
+            if kind == DataTypes::String || kind == DataTypes::Closure {
                 self.objects.modify_deallocation(name, false, true);
             }
         }
 
+        let value: Instruction<'instr> =
+            self.wrap_optional(self.in_type_name_function, value, value_span, line);
+
+        let is_borrowing_closure: bool = match &value {
+            Instruction::RefVar {
+                name,
+                kind: DataTypes::Closure,
+                ..
+            } => self.borrowing_closures.contains(name),
+            Instruction::Closure { captures, .. } => captures.iter().any(|capture| capture.3),
+            _ => false,
+        };
+
+        if is_borrowing_closure {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::EscapingClosure,
+                    message("E0032.title", &[]),
+                    message("E0032.escaping_closure", &[]),
+                    line,
+                )
+                .spanned(value_span),
+            );
+        }
+
         if self.in_type_function == DataTypes::Void && value.get_data_type() != DataTypes::Void {
-            self.errors.push(ThrushError::Parse(
-                ThrushErrorKind::SyntaxError,
-                String::from("Syntax Error"),
-                format!("Missing function type indicator with type '{}', you should add a correct function type indicator with type '{}'.", value.get_data_type(), value.get_data_type()),
-                line,
-            ));
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message(
+                        "E0001.return_value_in_void_function",
+                        &[&value.get_data_type(), &value.get_data_type()],
+                    ),
+                    line,
+                )
+                .spanned(value_span),
+            );
         }
 
         type_checking::check_type(
             value.get_data_type(),
             self.in_type_function,
             line,
-            String::from("Type Mismatch"),
-            format!(
-                "Type mismatch. Expected '{}' but found '{}'.",
-                self.in_type_function,
-                value.get_data_type(),
+            message("E0010.title", &[]),
+            message(
+                "E0010.expected_found",
+                &[&self.in_type_function, &value.get_data_type()],
             ),
-        )?;
+        )
+        .map_err(|error| error.spanned(value_span))?;
+
+        if self.in_type_function == DataTypes::Enum
+            && value.get_type_name() != self.in_type_name_function
+        {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::TypeMismatch,
+                    message("E0010.title", &[]),
+                    message(
+                        "E0010.expected_found",
+                        &[&self.in_type_name_function, &value.get_type_name()],
+                    ),
+                    line,
+                )
+                .spanned(value_span),
+            );
+        }
 
         self.consume(
             TokenKind::SemiColon,
             ThrushErrorKind::SyntaxError,
-            String::from("Syntax Error"),
-            String::from("Expected ';'."),
+            message("E0001.title", &[]),
+            message("E0001.expected_semicolon", &[]),
             line,
         )?;
 
@@ -410,32 +1078,91 @@ impl<'instr> Parser<'instr> {
 
         let mut stmts: Vec<Instruction> = Vec::new();
         let mut was_emited_deallocators: bool = false;
+        let mut diverged_at: Option<usize> = None;
+        let mut is_unreachable_reported: bool = false;
 
         for instr in with_instrs.iter_mut() {
             stmts.push(mem::take(instr));
         }
 
         while !self.match_token(TokenKind::RBrace)? {
-            let instr: Instruction<'instr> = self.parse()?;
+            let position: usize = self.current;
+            let start: Span = self.peek().span;
+
+            let instr: Instruction<'instr> = match self.parse() {
+                Ok(instr) => instr,
+                Err(error) => {
+                    self.errors.push(error);
+                    self.sync(position);
+
+                    // The error of the end of the code is already reported.
+                    if self.end() {
+                        return Err(ThrushError::None);
+                    }
+
+                    continue;
+                }
+            };
+
             let line: usize = self.previous().line;
 
-            if instr.is_return() {
-                if instr.is_indexe_return_of_string() {
-                    self.errors.push(ThrushError::Parse(
-                        ThrushErrorKind::SyntaxError,
-                        String::from("Unreacheable Deallocation"),
-                        String::from("In this point the correctly deallocation is imposible. The char should be stored in a variable and pass it variable to the return."),
+            // Only the first unreachable statement of the block is reported.
+            if let Some(exit_line) = diverged_at {
+                if !is_unreachable_reported {
+                    self.warnings.push(lints::warning(
+                        Lint::UnreachableCode,
+                        &message("W0006.title", &[]),
+                        message("W0006.unreachable_code", &[&exit_line]),
                         line,
+                        self.span_from(start),
                     ));
+
+                    is_unreachable_reported = true;
+                }
+            } else if control_flow::diverges(&instr) {
+                diverged_at = Some(line);
+            }
+
+            if instr.is_expression() {
+                if self.peek().kind == TokenKind::RBrace
+                    && self.previous().kind != TokenKind::SemiColon
+                {
+                    stmts.push(Instruction::Yield {
+                        kind: instr.get_data_type(),
+                        value: Box::new(instr),
+                    });
+
+                    continue;
+                }
+
+                self.match_token(TokenKind::SemiColon)?;
+            }
+
+            if instr.is_return() {
+                if instr.is_indexe_return_of_string() {
+                    self.errors.push(
+                        ThrushError::Parse(
+                            ThrushErrorKind::UnreachableDeallocation,
+                            message("E0037.title", &[]),
+                            message("E0037.unstored_char", &[]),
+                            line,
+                        )
+                        .spanned(self.span_from(start)),
+                    );
                 }
 
-                let deallocators: Vec<Instruction<'_>> = self.objects.create_deallocators(self.scope);
+                let deallocators: Vec<Instruction<'_>> =
+                    self.objects.create_deallocators(self.scope);
 
                 stmts.extend(deallocators);
 
                 was_emited_deallocators = true;
             }
 
+            if let Instruction::Break { label } | Instruction::Continue { label } = &instr {
+                stmts.extend(self.jump_deallocators(*label));
+            }
+
             stmts.push(instr)
         }
 
@@ -459,153 +1186,147 @@ impl<'instr> Parser<'instr> {
         self.only_advance()?;
 
         if self.scope != 0 {
-            self.errors.push(ThrushError::Parse(
-                ThrushErrorKind::SyntaxError,
-                String::from("Syntax Error"),
-                String::from(
-                    "The functions must go in the global scope. Rewrite it in the global scope.",
-                ),
-                self.previous().line,
-            ));
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.nested_function", &[]),
+                    self.previous().line,
+                )
+                .spanned(self.previous().span),
+            );
         }
 
         self.in_function = true;
+        self.moved_closures.clear();
 
         let name: &Token = self.consume(
             TokenKind::Identifier,
             ThrushErrorKind::SyntaxError,
-            String::from("Expected function name"),
-            String::from("Expected a name to the function."),
+            message("E0001.expected_function_name", &[]),
+            message("E0001.expected_function_name_help", &[]),
             self.previous().line,
         )?;
 
-        if name.lexeme.as_ref().unwrap() == "main" && self.is_main {
+        let mut function_name: &'instr str = name.lexeme.as_ref().unwrap();
+
+        if let Some(owner) = self.receiver {
+            function_name = self.mangle_method(owner, function_name);
+        }
+
+        if self.peek().kind == TokenKind::Less {
+            match self.instance.take() {
+                Some(instance) => {
+                    while !self.match_token(TokenKind::Greater)? {
+                        self.only_advance()?;
+                    }
+
+                    function_name = instance;
+                }
+                None => return self.skip_generic_function(name.line),
+            }
+        }
+
+        // The methods and the instances of the generics are used through their mangled names.
+        if !is_public
+            && !is_external
+            && function_name != "main"
+            && function_name == name.lexeme.as_ref().unwrap()
+        {
+            self.functions.push((function_name, name.line, name.span));
+        }
+
+        if function_name == "main" && self.is_main {
             if self.has_entry_point {
-                self.errors.push(ThrushError::Parse(
-                    ThrushErrorKind::SyntaxError,
-                    String::from("Duplicated EntryPoint"),
-                    String::from("The language not support two entrypoints, remove one."),
-                    name.line,
-                ));
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::DuplicatedDefinition,
+                        message("E0020.duplicated_entrypoint_title", &[]),
+                        message("E0020.duplicated_entrypoint", &[]),
+                        name.line,
+                    )
+                    .spanned(name.span),
+                );
             }
 
             self.consume(
                 TokenKind::LParen,
                 ThrushErrorKind::SyntaxError,
-                String::from("Syntax Error"),
-                String::from("Expected '('."),
+                message("E0001.title", &[]),
+                message("E0001.expected_lparen", &[]),
                 name.line,
             )?;
 
             self.consume(
                 TokenKind::RParen,
                 ThrushErrorKind::SyntaxError,
-                String::from("Syntax Error"),
-                String::from("Expected ')'."),
+                message("E0001.title", &[]),
+                message("E0001.expected_rparen", &[]),
                 name.line,
             )?;
 
             if self.peek().kind != TokenKind::LBrace {
-                self.errors.push(ThrushError::Parse(
-                    ThrushErrorKind::SyntaxError,
-                    String::from("Syntax Error"),
-                    String::from("Expected '{'."),
-                    self.peek().line,
-                ));
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::SyntaxError,
+                        message("E0001.title", &[]),
+                        message("E0001.expected_lbrace", &[]),
+                        self.peek().line,
+                    )
+                    .spanned(self.peek().span),
+                );
             }
 
             if self.peek().kind == TokenKind::LBrace {
                 self.has_entry_point = true;
 
+                let body: Instruction<'instr> = self.block(&mut [])?;
+
+                self.errors.extend(initialization::analyze(&body));
+                self.warnings.extend(lints::analyze(&body, &[]));
+
                 return Ok(Instruction::EntryPoint {
-                    body: Box::new(self.block(&mut [])?),
+                    body: Box::new(body),
                 });
             } else {
-                self.errors.push(ThrushError::Parse(
-                    ThrushErrorKind::SyntaxError,
-                    String::from("Syntax Error"),
-                    String::from("Expected 'block ({ ... })' for the function body."),
-                    self.peek().line,
-                ));
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::SyntaxError,
+                        message("E0001.title", &[]),
+                        message("E0001.expected_function_body", &[]),
+                        self.peek().line,
+                    )
+                    .spanned(self.peek().span),
+                );
             }
         }
 
         self.consume(
             TokenKind::LParen,
             ThrushErrorKind::SyntaxError,
-            String::from("Syntax Error"),
-            String::from("Expected '('."),
+            message("E0001.title", &[]),
+            message("E0001.expected_lparen", &[]),
             name.line,
         )?;
 
-        let mut params: Vec<Instruction<'instr>> = Vec::new();
-
-        while !self.match_token(TokenKind::RParen)? {
-            if self.match_token(TokenKind::Comma)? {
-                continue;
-            }
-
-            if self.match_token(TokenKind::Pass)? {
-                continue;
-            }
-
-            if !self.match_token(TokenKind::Identifier)? {
-                self.errors.push(ThrushError::Parse(
-                    ThrushErrorKind::SyntaxError,
-                    String::from("Syntax Error"),
-                    String::from("Expected argument name."),
-                    name.line,
-                ));
-            }
-
-            let ident: &str = self.previous().lexeme.as_ref().unwrap();
-
-            if !self.match_token(TokenKind::ColonColon)? {
-                self.errors.push(ThrushError::Parse(
-                    ThrushErrorKind::SyntaxError,
-                    String::from("Syntax Error"),
-                    String::from("Expected '::'."),
-                    name.line,
-                ));
-            }
-
-            let kind: DataTypes = match &self.peek().kind {
-                TokenKind::DataType(kind) => {
-                    self.only_advance()?;
-
-                    *kind
-                }
-                _ => {
-                    self.errors.push(ThrushError::Parse(
-                        ThrushErrorKind::SyntaxError,
-                        String::from("Syntax Error"),
-                        String::from("Expected argument type."),
-                        name.line,
-                    ));
-
-                    DataTypes::Void
-                }
-            };
-
-            params.push(Instruction::Param { name: ident, kind })
-        }
+        let params: Vec<Instruction<'instr>> = self.function_params(name.line)?;
 
         if self.peek().kind == TokenKind::Colon {
             self.consume(
                 TokenKind::Colon,
                 ThrushErrorKind::SyntaxError,
-                String::from("Syntax Error"),
-                String::from("Missing return type. Expected ':' followed by return type."),
+                message("E0001.title", &[]),
+                message("E0001.expected_return_type", &[]),
                 name.line,
             )?;
         }
 
-        let return_kind: Option<DataTypes> = match &self.peek().kind {
-            TokenKind::DataType(kind) => {
-                self.only_advance()?;
-                Some(*kind)
+        let (return_kind, return_type_name): (Option<DataTypes>, &str) = match &self.peek().kind {
+            TokenKind::DataType(_) | TokenKind::Fn | TokenKind::Identifier => {
+                let (kind, type_name): (DataTypes, &str) = self.param_type(name.line)?;
+                (Some(kind), type_name)
             }
-            _ => None,
+            _ => (None, ""),
         };
 
         self.in_type_function = if let Some(kind) = return_kind {
@@ -614,913 +1335,4678 @@ impl<'instr> Parser<'instr> {
             DataTypes::Void
         };
 
+        self.in_type_name_function = return_type_name;
+
         if self.match_token(TokenKind::SemiColon)? {
             self.in_function = false;
 
             return Ok(Instruction::Function {
-                name: name.lexeme.as_ref().unwrap(),
+                name: function_name,
                 external_name,
                 params,
                 body: None,
                 return_kind,
+                return_type_name,
                 is_public,
                 is_external,
             });
         }
 
-        let body: Box<Instruction> = Box::new(self.block(&mut [])?);
+        let body: Box<Instruction> = Box::new(self.function_body(&params)?);
 
         self.in_function = false;
 
         Ok(Instruction::Function {
-            name: name.lexeme.as_ref().unwrap(),
+            name: function_name,
             external_name,
             params,
             body: Some(body),
             return_kind,
+            return_type_name,
             is_public,
             is_external,
         })
     }
 
-    fn print(&mut self) -> Result<Instruction<'instr>, ThrushError> {
-        self.only_advance()?;
+    fn skip_generic_function(&mut self, line: usize) -> Result<Instruction<'instr>, ThrushError> {
+        /*
+            The generic functions are parsed again on each instantiation with the type
+            parameters bound, see `Parser::instantiate`.
+        */
 
-        let start: &Token = self.consume(
-            TokenKind::LParen,
-            ThrushErrorKind::SyntaxError,
-            String::from("Syntax Error"),
-            String::from("Expected '('."),
-            self.previous().line,
-        )?;
+        while self.peek().kind != TokenKind::LBrace {
+            if self.match_token(TokenKind::SemiColon)? {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::SyntaxError,
+                        message("E0001.title", &[]),
+                        message("E0001.generic_without_body", &[]),
+                        line,
+                    )
+                    .spanned(self.previous().span),
+                );
 
-        let mut args: Vec<Instruction<'instr>> = Vec::with_capacity(24);
+                self.in_function = false;
 
-        while !self.match_token(TokenKind::RParen)? {
-            if self.match_token(TokenKind::Comma)? {
-                continue;
+                return Ok(Instruction::Null);
             }
 
-            args.push(self.expression()?);
+            self.only_advance()?;
         }
 
-        self.parse_string_formatted(&args, start.line, true);
+        self.skip_block(line)?;
+
+        self.in_function = false;
+
+        Ok(Instruction::Null)
+    }
 
+    fn skip_block(&mut self, line: usize) -> Result<(), ThrushError> {
         self.consume(
-            TokenKind::SemiColon,
+            TokenKind::LBrace,
             ThrushErrorKind::SyntaxError,
-            String::from("Syntax Error"),
-            String::from("Expected ';'."),
-            start.line,
+            message("E0001.title", &[]),
+            message("E0001.expected_lbrace", &[]),
+            line,
         )?;
 
-        Ok(Instruction::Print(args))
-    }
+        let mut depth: usize = 1;
 
-    fn println(&mut self) -> Result<Instruction<'instr>, ThrushError> {
-        self.only_advance()?;
+        while depth > 0 {
+            match self.advance()?.kind {
+                TokenKind::LBrace => depth += 1,
+                TokenKind::RBrace => depth -= 1,
+                _ => {}
+            }
+        }
 
-        let start: &Token = self.consume(
-            TokenKind::LParen,
-            ThrushErrorKind::SyntaxError,
-            String::from("Syntax Error"),
-            String::from("Expected '('."),
-            self.previous().line,
-        )?;
+        Ok(())
+    }
 
-        let mut args: Vec<Instruction<'instr>> = Vec::new();
+    fn function_params(&mut self, line: usize) -> Result<Vec<Instruction<'instr>>, ThrushError> {
+        let mut params: Vec<Instruction<'instr>> = Vec::new();
 
         while !self.match_token(TokenKind::RParen)? {
             if self.match_token(TokenKind::Comma)? {
                 continue;
             }
 
-            args.push(self.expression()?);
-        }
+            if self.match_token(TokenKind::Pass)? {
+                continue;
+            }
 
-        self.parse_string_formatted(&args, start.line, false);
+            if self.match_token(TokenKind::This)? {
+                match self.receiver {
+                    Some(owner) if params.is_empty() => params.push(Instruction::Param {
+                        name: self.previous().lexeme.as_ref().unwrap(),
+                        kind: DataTypes::Struct,
+                        type_name: owner.0,
+                        span: self.previous().span,
+                    }),
+                    _ => self.errors.push(
+                        ThrushError::Parse(
+                            ThrushErrorKind::SyntaxError,
+                            message("E0001.title", &[]),
+                            message("E0001.misplaced_this", &[]),
+                            line,
+                        )
+                        .spanned(self.previous().span),
+                    ),
+                }
 
-        self.consume(
-            TokenKind::SemiColon,
-            ThrushErrorKind::SyntaxError,
-            String::from("Syntax Error"),
-            String::from("Expected ';'."),
-            start.line,
-        )?;
+                continue;
+            }
 
-        Ok(Instruction::Println(args))
-    }
+            if !self.match_token(TokenKind::Identifier)? {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::SyntaxError,
+                        message("E0001.title", &[]),
+                        message("E0001.expected_parameter_name", &[]),
+                        line,
+                    )
+                    .spanned(self.peek().span),
+                );
+            }
 
-    fn expression(&mut self) -> Result<Instruction<'instr>, ThrushError> {
-        let instr: Instruction = self.or()?;
+            let ident: &str = self.previous().lexeme.as_ref().unwrap();
+            let span: Span = self.previous().span;
 
-        self.objects.decrease_local_references();
+            if !self.match_token(TokenKind::ColonColon)? {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::SyntaxError,
+                        message("E0001.title", &[]),
+                        message("E0001.expected_colon_colon", &[]),
+                        line,
+                    )
+                    .spanned(self.peek().span),
+                );
+            }
 
-        Ok(instr)
+            let type_start: Span = self.peek().span;
+            let (kind, type_name): (DataTypes, &str) = self.param_type(line)?;
+
+            if kind == DataTypes::Enum {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::SyntaxError,
+                        message("E0001.title", &[]),
+                        message("E0001.unpassable_parameter_type", &[&type_name]),
+                        line,
+                    )
+                    .spanned(self.span_from(type_start)),
+                );
+            }
+
+            params.push(Instruction::Param {
+                name: ident,
+                kind,
+                type_name,
+                span,
+            })
+        }
+
+        Ok(params)
     }
 
-    fn or(&mut self) -> Result<Instruction<'instr>, ThrushError> {
-        let mut instr: Instruction<'_> = self.and()?;
+    fn function_body(
+        &mut self,
+        params: &[Instruction<'instr>],
+    ) -> Result<Instruction<'instr>, ThrushError> {
+        self.objects.begin_local_scope();
 
-        while self.match_token(TokenKind::Or)? {
-            let op: &TokenKind = &self.previous().kind;
-            let right: Instruction<'instr> = self.and()?;
+        let scope: usize = self.objects.current_scope();
+        let line: usize = self.peek().line;
 
-            type_checking::check_binary_instr(
-                op,
-                &instr.get_data_type(),
-                &right.get_data_type(),
-                self.previous().line,
-            )?;
+        let mut declarations: Vec<Instruction<'instr>> = Vec::with_capacity(params.len());
 
-            instr = Instruction::Binary {
-                left: Box::new(instr),
-                op,
-                right: Box::new(right),
-                kind: DataTypes::Bool,
-                line: self.previous().line,
+        params.iter().for_each(|param| {
+            if let Instruction::Param {
+                name,
+                kind,
+                type_name,
+                span,
+            } = param
+            {
+                self.objects.insert_new_local(
+                    scope,
+                    name,
+                    (*kind, false, false, false, 0, *type_name),
+                );
+
+                declarations.push(Instruction::Var {
+                    name,
+                    kind: *kind,
+                    value: Box::new(Instruction::Null),
+                    line,
+                    span: *span,
+                    only_comptime: true,
+                    type_name,
+                });
             }
-        }
-
-        Ok(instr)
-    }
+        });
 
-    fn and(&mut self) -> Result<Instruction<'instr>, ThrushError> {
-        let mut instr: Instruction<'_> = self.equality()?;
+        let body: Result<Instruction<'instr>, ThrushError> = self.block(&mut declarations);
 
-        while self.match_token(TokenKind::And)? {
-            let op: &TokenKind = &self.previous().kind;
-            let right: Instruction<'_> = self.equality()?;
+        self.objects.end_local_scope();
 
-            type_checking::check_binary_instr(
-                op,
-                &instr.get_data_type(),
-                &right.get_data_type(),
-                self.previous().line,
-            )?;
+        if let Ok(body) = &body {
+            self.errors.extend(initialization::analyze(body));
+            self.warnings.extend(lints::analyze(body, params));
 
-            instr = Instruction::Binary {
-                left: Box::new(instr),
-                op,
-                right: Box::new(right),
-                kind: DataTypes::Bool,
-                line: self.previous().line,
+            if self.in_type_function != DataTypes::Void && !control_flow::always_returns(body) {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::MissingReturn,
+                        message("E0030.title", &[]),
+                        message("E0030.missing_return", &[&self.in_type_function]),
+                        self.previous().line,
+                    )
+                    .spanned(self.previous().span),
+                );
             }
         }
 
-        Ok(instr)
+        body
     }
 
-    fn equality(&mut self) -> Result<Instruction<'instr>, ThrushError> {
-        let mut instr: Instruction<'_> = self.comparison()?;
+    fn param_type(&mut self, line: usize) -> Result<(DataTypes, &'instr str), ThrushError> {
+        match &self.peek().kind {
+            TokenKind::DataType(kind) => {
+                self.only_advance()?;
 
-        while self.match_token(TokenKind::BangEq)? || self.match_token(TokenKind::EqEq)? {
-            let op: &TokenKind = &self.previous().kind;
-            let right: Instruction<'_> = self.comparison()?;
+                self.optional_suffix(*kind, line)
+            }
 
-            type_checking::check_binary_instr(
-                op,
-                &instr.get_data_type(),
-                &right.get_data_type(),
-                self.previous().line,
-            )?;
+            TokenKind::Fn => self.fn_type(line),
 
-            instr = Instruction::Binary {
-                left: Box::from(instr),
-                op,
-                right: Box::from(right),
-                kind: DataTypes::Bool,
-                line: self.previous().line,
+            TokenKind::Identifier if self.is_result_type() => self.result_type(line),
+
+            _ => {
+                if let Some(named_type) = self.named_type() {
+                    self.only_advance()?;
+
+                    return Ok(named_type);
+                }
+
+                if self.peek().kind == TokenKind::Identifier
+                    && self
+                        .objects
+                        .get_enum(self.peek().lexeme.as_ref().unwrap())
+                        .is_none()
+                {
+                    let custom_type: &Token = self.advance()?;
+
+                    let error: ThrushError = self.suggest(
+                        ThrushError::Parse(
+                            ThrushErrorKind::UnknownType,
+                            message("E0012.title", &[]),
+                            message(
+                                "E0012.undefined_type",
+                                &[&custom_type.lexeme.as_ref().unwrap()],
+                            ),
+                            line,
+                        ),
+                        custom_type,
+                        self.type_names(),
+                    );
+
+                    self.errors.push(error);
+
+                    return Ok((DataTypes::Void, ""));
+                }
+
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::SyntaxError,
+                        message("E0001.title", &[]),
+                        message("E0001.expected_parameter_type", &[]),
+                        line,
+                    )
+                    .spanned(self.peek().span),
+                );
+
+                Ok((DataTypes::Void, ""))
             }
         }
-
-        Ok(instr)
     }
 
-    fn comparison(&mut self) -> Result<Instruction<'instr>, ThrushError> {
-        let mut instr: Instruction<'_> = self.term()?;
-
-        while self.match_token(TokenKind::Greater)?
-            || self.match_token(TokenKind::GreaterEq)?
-            || self.match_token(TokenKind::Less)?
-            || self.match_token(TokenKind::LessEq)?
-        {
-            let op: &TokenKind = &self.previous().kind;
-            let right: Instruction<'_> = self.term()?;
+    fn fn_type(&mut self, line: usize) -> Result<(DataTypes, &'instr str), ThrushError> {
+        self.only_advance()?;
 
-            type_checking::check_binary_instr(
-                op,
-                &instr.get_data_type(),
-                &right.get_data_type(),
-                self.previous().line,
+        let kind: DataTypes = if self.match_token(TokenKind::LeftBracket)? {
+            self.consume(
+                TokenKind::RightBracket,
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.expected_closure_type_rbracket", &[]),
+                line,
             )?;
 
-            instr = Instruction::Binary {
-                left: Box::from(instr),
-                op,
-                right: Box::from(right),
-                kind: DataTypes::Bool,
-                line: self.previous().line,
-            };
-        }
+            DataTypes::Closure
+        } else {
+            DataTypes::Fn
+        };
 
-        Ok(instr)
-    }
+        self.consume(
+            TokenKind::LParen,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_function_type_lparen", &[]),
+            line,
+        )?;
 
-    fn term(&mut self) -> Result<Instruction<'instr>, ThrushError> {
-        let mut instr: Instruction<'_> = self.unary()?;
+        let mut params: Vec<(DataTypes, &'instr str)> = Vec::new();
 
-        while self.match_token(TokenKind::Plus)?
-            || self.match_token(TokenKind::Minus)?
-            || self.match_token(TokenKind::Slash)?
-            || self.match_token(TokenKind::Star)?
-        {
-            let op: &TokenKind = &self.previous().kind;
-            let right: Instruction<'_> = self.unary()?;
+        while !self.match_token(TokenKind::RParen)? {
+            if self.match_token(TokenKind::Comma)? {
+                continue;
+            }
 
-            let left_type: DataTypes = instr.get_data_type();
-            let right_type: DataTypes = right.get_data_type();
+            params.push(self.param_type(line)?);
+        }
 
-            let kind: DataTypes = if left_type.is_integer() && right_type.is_integer() {
-                left_type.calculate_integer_datatype(right_type)
+        let return_start: Span = self.peek().span;
+
+        let (return_kind, return_type_name): (DataTypes, &str) =
+            if self.match_token(TokenKind::Arrow)? {
+                self.param_type(line)?
             } else {
-                self.in_var_type
+                (DataTypes::Void, "")
             };
 
-            type_checking::check_binary_instr(
-                op,
-                &instr.get_data_type(),
-                &right.get_data_type(),
-                self.previous().line,
-            )?;
-
-            instr = Instruction::Binary {
-                left: Box::from(instr),
-                op,
-                right: Box::from(right),
-                kind,
-                line: self.previous().line,
-            };
+        if return_kind == DataTypes::Struct {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.function_type_returns_struct", &[]),
+                    line,
+                )
+                .spanned(self.span_from(return_start)),
+            );
         }
 
-        Ok(instr)
+        Ok((
+            kind,
+            self.objects
+                .intern_signature(kind, params, return_kind, return_type_name, false),
+        ))
     }
 
-    fn unary(&mut self) -> Result<Instruction<'instr>, ThrushError> {
-        if self.match_token(TokenKind::Bang)? {
-            let line: usize = self.previous().line;
-
-            let op: &TokenKind = &self.previous().kind;
-            let value: Instruction<'instr> = self.primary()?;
+    fn lambda(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        self.only_advance()?;
 
-            type_checking::check_unary_instr(op, &value.get_data_type(), self.previous().line)?;
+        let line: usize = self.previous().line;
+        let keyword: Span = self.previous().span;
 
-            return Ok(Instruction::Unary {
-                op,
-                value: Box::from(value),
-                kind: DataTypes::Bool,
-                line,
-            });
-        } else if self.match_token(TokenKind::PlusPlus)?
-            | self.match_token(TokenKind::MinusMinus)?
-            | self.match_token(TokenKind::Minus)?
-        {
-            let line: usize = self.previous().line;
+        // (Name, By reference, Span of the capture)
+        let mut capture_list: Vec<(&'instr str, bool, Span)> = Vec::new();
+        let mut is_closure: bool = false;
 
-            let op: &TokenKind = &self.previous().kind;
-            let mut value: Instruction<'instr> = self.primary()?;
+        if self.match_token(TokenKind::LeftBracket)? {
+            is_closure = true;
 
-            if let Instruction::Integer(_, _, is_signed) = &mut value {
-                if *op == TokenKind::Minus {
-                    *is_signed = true;
-                    return Ok(value);
+            while !self.match_token(TokenKind::RightBracket)? {
+                if self.match_token(TokenKind::Comma)? {
+                    continue;
                 }
-            }
 
-            let value_type: &DataTypes = &value.get_data_type();
+                let by_reference: bool = self.match_token(TokenKind::Ampersand)?;
 
-            type_checking::check_unary_instr(op, value_type, self.previous().line)?;
+                let capture: &Token = self.consume(
+                    TokenKind::Identifier,
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.expected_capture_name", &[]),
+                    line,
+                )?;
 
-            return Ok(Instruction::Unary {
-                op,
-                value: Box::from(value),
-                kind: *value_type,
-                line,
-            });
+                capture_list.push((capture.lexeme.as_ref().unwrap(), by_reference, capture.span));
+            }
         }
 
-        let instr: Instruction<'_> = self.primary()?;
-
-        Ok(instr)
-    }
-
-    fn primary(&mut self) -> Result<Instruction<'instr>, ThrushError> {
-        let primary: Instruction = match &self.peek().kind {
-            TokenKind::LParen => {
-                let line: usize = self.peek().line;
+        self.consume(
+            TokenKind::LParen,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_lambda_lparen", &[]),
+            line,
+        )?;
 
-                self.only_advance()?;
+        let params: Vec<Instruction<'instr>> = self.function_params(line)?;
 
-                let instr: Instruction<'instr> = self.expression()?;
-                let kind: DataTypes = instr.get_data_type();
+        let return_start: Span = self.peek().span;
 
-                if !instr.is_binary() {
-                    self.errors.push(ThrushError::Parse(
-                        ThrushErrorKind::SyntaxError,
-                        String::from("Syntax Error"),
-                        String::from(
-                            "Group the expressions \"(...)\" is only allowed if contain binary expressions.",
-                        ),
-                        line,
-                    ));
-                }
+        let (return_kind, return_type_name): (DataTypes, &str) =
+            if self.match_token(TokenKind::Colon)? {
+                self.param_type(line)?
+            } else {
+                (DataTypes::Void, "")
+            };
 
-                self.consume(
-                    TokenKind::RParen,
+        if return_kind == DataTypes::Struct {
+            self.errors.push(
+                ThrushError::Parse(
                     ThrushErrorKind::SyntaxError,
-                    String::from("Syntax Error"),
-                    String::from("Expected ')'."),
+                    message("E0001.title", &[]),
+                    message("E0001.lambda_returns_struct", &[]),
                     line,
-                )?;
+                )
+                .spanned(self.span_from(return_start)),
+            );
+        }
 
-                return Ok(Instruction::Group {
-                    instr: Box::new(instr),
-                    kind,
-                });
-            }
+        if self.peek().kind != TokenKind::LBrace {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.expected_lambda_body", &[]),
+                self.peek().line,
+            )
+            .spanned(self.peek().span));
+        }
 
-            TokenKind::String => {
-                let current: &Token = self.advance()?;
+        let name: &str = self.objects.intern(format!("lambda.{}", self.lambdas));
 
-                Instruction::String(
-                    current.lexeme.as_ref().unwrap().to_string(),
-                    current.lexeme.as_ref().unwrap().contains("{}"),
-                )
-            }
-            TokenKind::Char => {
-                Instruction::Char(self.advance()?.lexeme.as_ref().unwrap().as_bytes()[0])
-            }
+        self.lambdas += 1;
 
-            kind => match kind {
-                TokenKind::Integer(kind, num, is_signed) => {
-                    self.only_advance()?;
+        let in_function: bool = self.in_function;
+        let in_type_function: DataTypes = self.in_type_function;
+        let in_type_name_function: &str = mem::take(&mut self.in_type_name_function);
+        let loops: Vec<(Option<&str>, usize)> = mem::take(&mut self.loops);
+        let moved_closures: Vec<&str> = self.moved_closures.clone();
 
-                    let instr: Instruction<'instr> = match kind {
-                        DataTypes::I8 => Instruction::Integer(DataTypes::I8, *num, *is_signed),
-                        DataTypes::I16 => Instruction::Integer(DataTypes::I16, *num, *is_signed),
-                        DataTypes::I32 => Instruction::Integer(DataTypes::I32, *num, *is_signed),
-                        DataTypes::I64 => Instruction::Integer(DataTypes::I64, *num, *is_signed),
-                        _ => unreachable!(),
-                    };
+        self.in_function = true;
+        self.in_type_function = return_kind;
 
-                    if self.match_token(TokenKind::PlusPlus)?
-                        | self.match_token(TokenKind::MinusMinus)?
-                    {
-                        type_checking::check_unary_instr(
-                            &self.previous().kind,
-                            kind,
-                            self.previous().line,
-                        )?;
+        self.objects.begin_lambda_scope();
 
-                        return Ok(Instruction::Unary {
-                            op: &self.previous().kind,
-                            value: Box::from(instr),
-                            kind: *kind,
-                            line: self.previous().line,
-                        });
-                    }
+        let body: Result<Instruction<'instr>, ThrushError> = self.function_body(&params);
 
-                    instr
-                }
+        let mut environment: Vec<Capture<'instr>> = Vec::new();
 
-                TokenKind::Float(kind, num, is_signed) => {
-                    self.only_advance()?;
+        if let Ok(body) = &body {
+            captures::analyze(body, &params).iter().for_each(|captured| {
+                if let Some((kind, type_name)) = self.objects.get_enclosing(captured) {
+                    let by_reference: bool = capture_list
+                        .iter()
+                        .any(|capture| capture.0 == *captured && capture.1);
 
-                    let instr: Instruction<'instr> = match kind {
-                        DataTypes::F32 => Instruction::Float(DataTypes::F32, *num, *is_signed),
-                        DataTypes::F64 => Instruction::Float(DataTypes::F64, *num, *is_signed),
-                        _ => unreachable!(),
-                    };
+                    environment.push((*captured, kind, type_name, by_reference));
+                }
+            });
+        }
 
-                    if self.match_token(TokenKind::PlusPlus)?
-                        | self.match_token(TokenKind::MinusMinus)?
-                    {
-                        type_checking::check_unary_instr(
-                            &self.previous().kind,
-                            kind,
-                            self.previous().line,
-                        )?;
+        capture_list.iter().for_each(|capture| {
+            if self.objects.get_enclosing(capture.0).is_none() {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::UnknownCapture,
+                        message("E0018.title", &[]),
+                        message("E0018.unknown_capture", &[&capture.0]),
+                        line,
+                    )
+                    .spanned(capture.2),
+                );
+            }
+        });
 
-                        return Ok(Instruction::Unary {
-                            op: &self.previous().kind,
-                            value: Box::from(instr),
-                            kind: *kind,
-                            line: self.previous().line,
-                        });
-                    }
+        self.objects.end_lambda_scope();
 
-                    instr
-                }
+        self.in_function = in_function;
+        self.in_type_function = in_type_function;
+        self.in_type_name_function = in_type_name_function;
+        self.loops = loops;
+        self.moved_closures = moved_closures;
 
-                TokenKind::Identifier => {
-                    let current: &Token = self.peek();
-                    let line: usize = self.peek().line;
+        let body: Box<Instruction<'instr>> = Box::new(body?);
 
-                    // type is_null, is_function, ignore_more_params?, ?params
-                    let var: (DataTypes, bool, bool, bool, bool, Vec<DataTypes>, usize) =
-                        self.objects.get_object(current.lexeme.as_ref().unwrap(), line)?;
+        let return_kind: Option<DataTypes> = if return_kind != DataTypes::Void {
+            Some(return_kind)
+        } else {
+            None
+        };
 
-                    let name: &str = current.lexeme.as_ref().unwrap();
+        let params_types: Vec<(DataTypes, &str)> = params
+            .iter()
+            .map(|param| (param.get_data_type(), param.get_type_name()))
+            .collect();
+
+        if !is_closure && environment.is_empty() {
+            let type_name: &str = self.objects.intern_signature(
+                DataTypes::Fn,
+                params_types,
+                return_kind.unwrap_or(DataTypes::Void),
+                return_type_name,
+                false,
+            );
 
-                    self.only_advance()?;
+            self.stmts.push(Instruction::Function {
+                name,
+                external_name: "",
+                params,
+                body: Some(body),
+                return_kind,
+                return_type_name,
+                is_public: false,
+                is_external: false,
+            });
 
-                    if self.peek().kind == TokenKind::LeftBracket {
-                        self.consume(
-                            TokenKind::LeftBracket,
-                            ThrushErrorKind::SyntaxError,
-                            String::from("Syntax Error"),
-                            String::from("Expected '['."),
-                            line,
-                        )?;
+            return Ok(Instruction::FunctionRef { name, type_name });
+        }
 
-                        let expr: Instruction<'instr> = self.primary()?;
+        environment.iter().for_each(|capture| {
+            if matches!(capture.1, DataTypes::Enum | DataTypes::Struct) && !capture.3 {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::InvalidCapture,
+                        message("E0033.title", &[]),
+                        message(
+                            "E0033.capture_by_reference",
+                            &[&capture.1, &capture.0, &capture.0],
+                        ),
+                        line,
+                    )
+                    .spanned(keyword),
+                );
+            }
 
-                        self.consume(
-                            TokenKind::RightBracket,
-                            ThrushErrorKind::SyntaxError,
-                            String::from("Syntax Error"),
-                            String::from("Expected ']'."),
-                            line,
-                        )?;
+            // The environment takes the ownership of the closures captured by value.
+            if capture.1 == DataTypes::Closure && !capture.3 {
+                self.move_closure(capture.0);
+            }
+        });
 
-                        if var.1 {
-                            self.errors.push(ThrushError::Parse(
-                                ThrushErrorKind::VariableNotDeclared,
-                                String::from("Variable Not Declared"),
-                                format!(
-                                    "Variable `{}` is not declared for are use it. Declare the variable before of the use.",
-                                    self.previous().lexeme.as_ref().unwrap(),
-                                ),
-                                line,
-                            ));
-                        }
+        let type_name: &str = self.objects.intern_signature(
+            DataTypes::Closure,
+            params_types,
+            return_kind.unwrap_or(DataTypes::Void),
+            return_type_name,
+            false,
+        );
 
-                        let kind: DataTypes = if var.0 == DataTypes::String {
-                            DataTypes::Char
-                        } else {
-                            todo!()
-                        };
+        self.stmts.push(Instruction::ClosureFunction {
+            name,
+            params,
+            captures: environment.clone(),
+            body,
+            return_kind,
+        });
 
-                        if let Instruction::Integer(_, num, _) = expr {
-                            return Ok(Instruction::Indexe {
-                                origin: name,
-                                index: num as u64,
-                                kind,
-                            });
-                        }
+        Ok(Instruction::Closure {
+            name,
+            captures: environment,
+            type_name,
+        })
+    }
 
-                        self.errors.push(ThrushError::Parse(
-                            ThrushErrorKind::SyntaxError,
-                            String::from("Syntax Error"),
-                            String::from("Expected unsigned number for the build an indexe."),
-                            self.previous().line,
-                        ));
-                    } else if self.peek().kind == TokenKind::Eq {
-                        self.only_advance()?;
+    fn if_expression(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        self.only_advance()?;
 
-                        let expr: Instruction<'instr> = self.expression()?;
+        let line: usize = self.previous().line;
+        let keyword: Span = self.previous().span;
 
-                        if let Err(err) = type_checking::check_type(
-                            expr.get_data_type(),
-                            var.0,
-                            line,
-                            String::from("Type Mismatch"),
-                            format!(
-                                "Type mismatch. Expected '{}' but found '{}'.",
-                                var.0,
-                                expr.get_data_type()
-                            ),
-                        ) {
-                            self.errors.push(err);
-                        }
+        if self.match_token(TokenKind::Let)? {
+            return self.if_let(keyword, line);
+        }
 
-                        self.consume(
-                            TokenKind::SemiColon,
-                            ThrushErrorKind::SyntaxError,
-                            String::from("Syntax Error"),
-                            String::from("Expected ';'."),
-                            line,
-                        )?;
+        if self.narrowed_optional() {
+            return self.if_narrowed(keyword, line);
+        }
 
-                        self.objects.insert_new_local(self.scope, name, (var.0, false, false, false, 0));
+        let cond_start: Span = self.peek().span;
+        let cond: Instruction<'instr> = self.expression()?;
+        let cond_span: Span = self.span_from(cond_start);
+
+        if cond.get_data_type() != DataTypes::Bool {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::TypeMismatch,
+                    message("E0010.title", &[]),
+                    message("E0010.non_bool_if_condition", &[&cond.get_data_type()]),
+                    line,
+                )
+                .spanned(cond_span),
+            );
+        }
 
-                        return Ok(Instruction::MutVar {
-                            name,
-                            value: Box::new(expr),
-                            kind: var.0,
-                        });
+        if self.peek().kind != TokenKind::LBrace {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.expected_if_body", &[]),
+                line,
+            )
+            .spanned(self.peek().span));
+        }
 
-                    } else if self.peek().kind == TokenKind::LParen {
-                        self.only_advance()?;
+        let block: Instruction<'instr> = self.block(&mut [])?;
 
-                        return self.call(name, var, line);
-                    }
+        let otherwise: Option<Instruction<'instr>> = self.else_branch()?;
+        let kind: DataTypes = self.if_type(&block, otherwise.as_ref(), keyword, line);
 
-                    if var.1 {
-                        self.errors.push(ThrushError::Parse(
-                            ThrushErrorKind::VariableNotDeclared,
-                            String::from("Variable Not Declared"),
-                            format!(
-                                "Variable `{}` is not declared for are use it. Declare the variable before of the use.",
-                                name,
-                            ),
-                            line
-                        ));
-                    }
+        Ok(Instruction::If {
+            cond: Box::new(cond),
+            block: Box::new(block),
+            otherwise: otherwise.map(Box::new),
+            kind,
+        })
+    }
 
-                    let refvar: Instruction<'_> = Instruction::RefVar {
-                        name,
-                        line,
-                        kind: var.0,
-                    };
+    fn if_let(&mut self, keyword: Span, line: usize) -> Result<Instruction<'instr>, ThrushError> {
+        /*
+            if let pattern = value { ... } else { ... }
 
-                    if self.match_token(TokenKind::PlusPlus)?
-                        | self.match_token(TokenKind::MinusMinus)?
-                    {
-                        type_checking::check_unary_instr(
-                            &current.kind,
-                            &refvar.get_data_type(),
-                            line,
-                        )?;
+            The pattern depends on the type of the value, so the value is parsed first and then
+            the parser comes back to the pattern. The result is lowered to a match with a `_` arm.
+        */
 
-                        let expr: Instruction<'_> = Instruction::Unary {
-                            op: &current.kind,
-                            value: Box::from(refvar),
-                            kind: DataTypes::I64,
-                            line,
-                        };
+        let pattern_start: usize = self.current;
 
-                        self.consume(
-                            TokenKind::SemiColon,
-                            ThrushErrorKind::SyntaxError,
-                            String::from("Syntax Error"),
-                            String::from("Expected ';'."),
-                            line,
-                        )?;
+        while !self.match_token(TokenKind::Eq)? {
+            if self.peek().kind == TokenKind::LBrace {
+                return Err(ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.expected_if_let_eq", &[]),
+                    line,
+                )
+                .spanned(self.peek().span));
+            }
 
-                        return Ok(expr);
-                    }
+            self.only_advance()?;
+        }
 
-                    refvar
-                }
+        let value_start: Span = self.peek().span;
+        let value: Instruction<'instr> = self.expression()?;
 
-                TokenKind::Pass => {
-                    self.only_advance()?;
-                    Instruction::Pass
-                }
+        if value.get_data_type() != DataTypes::Enum {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::TypeMismatch,
+                message("E0010.title", &[]),
+                message("E0010.non_enum_if_let", &[&value.get_data_type()]),
+                line,
+            )
+            .spanned(self.span_from(value_start)));
+        }
 
-                TokenKind::True => {
-                    self.only_advance()?;
-                    Instruction::Boolean(true)
-                }
+        let value_end: usize = self.current;
 
-                TokenKind::False => {
-                    self.only_advance()?;
-                    Instruction::Boolean(false)
-                }
+        let type_name: &str = value.get_type_name();
+        let variants: Vec<(&str, Vec<DataTypes>)> =
+            self.objects.get_enum(type_name).unwrap().clone();
 
-                _ => {
-                    self.only_advance()?;
+        self.current = pattern_start;
 
-                    self.errors.push(ThrushError::Parse(
-                        ThrushErrorKind::SyntaxError,
-                        String::from("Syntax Error"),
-                        format!(
-                            "Statement `{}` don't allowed.",
-                            self.previous().lexeme.as_ref().unwrap(),
-                        ),
-                        self.previous().line,
-                    ));
+        let (pattern, position, bindings): (&Token, Option<usize>, Vec<Option<&str>>) =
+            self.pattern(type_name, &variants, line)?;
 
-                    Instruction::Null
-                }
-            },
-        };
+        if self.peek().kind != TokenKind::Eq {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.expected_if_let_eq", &[]),
+                pattern.line,
+            )
+            .spanned(self.peek().span));
+        }
 
-        Ok(primary)
+        self.current = value_end;
+
+        if self.peek().kind != TokenKind::LBrace {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.expected_if_body", &[]),
+                line,
+            )
+            .spanned(self.peek().span));
+        }
+
+        let bindings_types: Vec<DataTypes> = position
+            .map(|position| variants[position].1.clone())
+            .unwrap_or_default();
+
+        let block: Instruction<'instr> =
+            self.arm_body(&bindings, &bindings_types, pattern.span, line)?;
+
+        let otherwise: Option<Instruction<'instr>> = self.else_branch()?;
+        let kind: DataTypes = self.if_type(&block, otherwise.as_ref(), keyword, line);
+
+        Ok(Instruction::Match {
+            value: Box::new(value),
+            type_name,
+            arms: vec![
+                (position.map(|position| position as u32), bindings, block),
+                (
+                    None,
+                    Vec::new(),
+                    otherwise.unwrap_or(Instruction::Block { stmts: Vec::new() }),
+                ),
+            ],
+            kind,
+        })
     }
 
-    fn consume(
+    fn else_branch(&mut self) -> Result<Option<Instruction<'instr>>, ThrushError> {
+        if self.peek().kind == TokenKind::Elif {
+            Ok(Some(self.if_expression()?))
+        } else if self.match_token(TokenKind::Else)? {
+            match self.peek().kind {
+                TokenKind::If => Ok(Some(self.if_expression()?)),
+                TokenKind::LBrace => Ok(Some(self.block(&mut [])?)),
+                _ => Err(ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.expected_else_body", &[]),
+                    self.previous().line,
+                )
+                .spanned(self.peek().span)),
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn if_type(
         &mut self,
-        kind: TokenKind,
-        error_kind: ThrushErrorKind,
-        error_title: String,
-        help: String,
+        block: &Instruction<'instr>,
+        otherwise: Option<&Instruction<'instr>>,
+        span: Span,
         line: usize,
-    ) -> Result<&'instr Token, ThrushError> {
-        if self.peek().kind == kind {
-            return self.advance();
-        }
+    ) -> DataTypes {
+        match otherwise {
+            Some(otherwise) => self.branches_type(block, otherwise, span, line),
+            None => {
+                if block.get_data_type() != DataTypes::Void {
+                    self.errors.push(
+                        ThrushError::Parse(
+                            ThrushErrorKind::SyntaxError,
+                            message("E0001.title", &[]),
+                            message("E0001.if_value_without_else", &[]),
+                            line,
+                        )
+                        .spanned(span),
+                    );
+                }
 
-        Err(ThrushError::Parse(error_kind, error_title, help, line))
+                DataTypes::Void
+            }
+        }
     }
 
-    fn call(
+    fn branches_type(
         &mut self,
-        name: &'instr str,
-        object:  (
-            DataTypes,      // Main Type
-            bool,           // is null?
-            bool,           // is freeded?
-            bool,           // is function?
-            bool,           // ignore the params if is a function?
-            Vec<DataTypes>, // params types
-            usize,          // Number the references
-        ),
+        a: &Instruction<'instr>,
+        b: &Instruction<'instr>,
+        span: Span,
         line: usize,
-    ) -> Result<Instruction<'instr>, ThrushError> {
-        if !object.3 {
+    ) -> DataTypes {
+        match type_checking::check_branches_type(a.get_data_type(), b.get_data_type(), line) {
+            Ok(kind) => {
+                if matches!(
+                    kind,
+                    DataTypes::Enum | DataTypes::Fn | DataTypes::Closure | DataTypes::Struct
+                ) && a.get_type_name() != b.get_type_name()
+                {
+                    self.errors.push(
+                        ThrushError::Parse(
+                            ThrushErrorKind::TypeMismatch,
+                            message("E0010.title", &[]),
+                            message(
+                                "E0010.branch_types",
+                                &[&a.get_type_name(), &b.get_type_name()],
+                            ),
+                            line,
+                        )
+                        .spanned(span),
+                    );
+                }
+
+                kind
+            }
+
+            Err(e) => {
+                self.errors.push(e.spanned(span));
+
+                a.get_data_type()
+            }
+        }
+    }
+
+    fn enum_declaration(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        self.only_advance()?;
+
+        let line: usize = self.previous().line;
+
+        if self.in_function {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.nested_enum", &[]),
+                    line,
+                )
+                .spanned(self.previous().span),
+            );
+        }
+
+        let name: &Token = self.consume(
+            TokenKind::Identifier,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.expected_enum_name", &[]),
+            message("E0001.expected_enum_declaration", &[]),
+            line,
+        )?;
+
+        let enum_name: &str = name.lexeme.as_ref().unwrap();
+
+        if self.objects.get_enum(enum_name).is_some() {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::DuplicatedDefinition,
+                    message("E0020.duplicated_enum_title", &[]),
+                    message("E0020.duplicated_enum", &[&enum_name]),
+                    line,
+                )
+                .spanned(name.span),
+            );
+        }
+
+        self.consume(
+            TokenKind::LBrace,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_lbrace", &[]),
+            line,
+        )?;
+
+        let mut variants: Vec<(&'instr str, Vec<DataTypes>)> = Vec::new();
+
+        while !self.match_token(TokenKind::RBrace)? {
+            if self.match_token(TokenKind::Comma)? {
+                continue;
+            }
+
+            let variant: &Token = self.consume(
+                TokenKind::Identifier,
+                ThrushErrorKind::SyntaxError,
+                message("E0001.expected_variant_name", &[]),
+                message("E0001.expected_variant_declaration", &[]),
+                line,
+            )?;
+
+            let variant_name: &str = variant.lexeme.as_ref().unwrap();
+
+            if variants.iter().any(|(name, _)| *name == variant_name) {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::DuplicatedDefinition,
+                        message("E0020.duplicated_variant_title", &[]),
+                        message("E0020.duplicated_variant", &[&enum_name, &variant_name]),
+                        variant.line,
+                    )
+                    .spanned(variant.span),
+                );
+            }
+
+            let mut payload: Vec<DataTypes> = Vec::new();
+
+            if self.match_token(TokenKind::LParen)? {
+                while !self.match_token(TokenKind::RParen)? {
+                    if self.match_token(TokenKind::Comma)? {
+                        continue;
+                    }
+
+                    match &self.peek().kind {
+                        TokenKind::DataType(kind) if *kind != DataTypes::Void => {
+                            self.only_advance()?;
+
+                            payload.push(*kind);
+                        }
+
+                        _ => {
+                            return Err(ThrushError::Parse(
+                                ThrushErrorKind::SyntaxError,
+                                message("E0001.title", &[]),
+                                message(
+                                    "E0001.expected_payload_type",
+                                    &[&enum_name, &variant_name],
+                                ),
+                                variant.line,
+                            )
+                            .spanned(self.peek().span));
+                        }
+                    }
+                }
+            }
+
+            variants.push((variant_name, payload));
+        }
+
+        if variants.is_empty() {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.empty_enum", &[&enum_name]),
+                    line,
+                )
+                .spanned(name.span),
+            );
+        }
+
+        self.match_token(TokenKind::SemiColon)?;
+
+        self.objects.insert_new_enum(enum_name, variants.clone());
+
+        Ok(Instruction::Enum {
+            name: enum_name,
+            variants,
+        })
+    }
+
+    fn enum_value(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        let name: &Token = self.advance()?;
+        let enum_name: &str = name.lexeme.as_ref().unwrap();
+
+        self.only_advance()?;
+
+        let variant: &Token = self.consume(
+            TokenKind::Identifier,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.expected_variant_name", &[]),
+            message("E0001.expected_enum_variant", &[&enum_name]),
+            name.line,
+        )?;
+
+        let variant_name: &str = variant.lexeme.as_ref().unwrap();
+
+        let (tag, payload): (usize, Vec<DataTypes>) = match self.objects.get_enum(enum_name) {
+            Some(variants) => match variants.iter().position(|(name, _)| *name == variant_name) {
+                Some(tag) => (tag, variants[tag].1.clone()),
+                None => {
+                    return Err(self.suggest(
+                        ThrushError::Parse(
+                            ThrushErrorKind::UnknownVariant,
+                            message("E0017.title", &[]),
+                            message("E0017.unknown_variant", &[&enum_name, &variant_name]),
+                            variant.line,
+                        ),
+                        variant,
+                        variants.iter().map(|(name, _)| *name).collect(),
+                    ));
+                }
+            },
+            None => {
+                return Err(self.suggest(
+                    ThrushError::Parse(
+                        ThrushErrorKind::UnknownType,
+                        message("E0012.title", &[]),
+                        message("E0012.undefined_type", &[&enum_name]),
+                        name.line,
+                    ),
+                    name,
+                    self.type_names(),
+                ));
+            }
+        };
+
+        let mut args: Vec<Instruction<'instr>> = Vec::with_capacity(payload.len());
+        let mut spans: Vec<Span> = Vec::with_capacity(payload.len());
+
+        if self.match_token(TokenKind::LParen)? {
+            while !self.match_token(TokenKind::RParen)? {
+                if self.match_token(TokenKind::Comma)? {
+                    continue;
+                }
+
+                let arg_start: Span = self.peek().span;
+
+                args.push(self.expression()?);
+                spans.push(self.span_from(arg_start));
+            }
+        }
+
+        if args.len() != payload.len() {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message(
+                        "E0001.payload_count",
+                        &[&enum_name, &variant_name, &payload.len(), &args.len()],
+                    ),
+                    variant.line,
+                )
+                .spanned(self.span_from(name.span)),
+            );
+        }
+
+        args.iter()
+            .zip(spans.iter())
+            .zip(payload.iter())
+            .for_each(|((arg, span), kind)| {
+                if let Err(e) = type_checking::check_type(
+                    arg.get_data_type(),
+                    *kind,
+                    variant.line,
+                    message("E0010.title", &[]),
+                    message("E0010.expected_found", &[&kind, &arg.get_data_type()]),
+                ) {
+                    self.errors.push(e.spanned(*span));
+                }
+            });
+
+        Ok(Instruction::EnumValue {
+            name: enum_name,
+            tag: tag as u32,
+            args,
+        })
+    }
+
+    fn struct_declaration(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        self.only_advance()?;
+
+        let line: usize = self.previous().line;
+
+        if self.in_function {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.nested_struct", &[]),
+                    line,
+                )
+                .spanned(self.previous().span),
+            );
+        }
+
+        let name: &Token = self.consume(
+            TokenKind::Identifier,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.expected_struct_name", &[]),
+            message("E0001.expected_struct_declaration", &[]),
+            line,
+        )?;
+
+        // The fields were registered in the forward declaration, see `Parser::predefine_struct`.
+        self.skip_block(line)?;
+
+        self.match_token(TokenKind::SemiColon)?;
+
+        let struct_name: &str = name.lexeme.as_ref().unwrap();
+
+        match self.objects.get_struct(struct_name) {
+            Some(fields) => Ok(Instruction::Struct {
+                name: struct_name,
+                fields: fields.clone(),
+            }),
+            None => Ok(Instruction::Null),
+        }
+    }
+
+    fn struct_literal(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        let name: &Token = self.advance()?;
+        let line: usize = name.line;
+        let struct_name: &str = name.lexeme.as_ref().unwrap();
+
+        self.only_advance()?;
+
+        let fields: StructFields = self.objects.get_struct(struct_name).cloned().unwrap();
+        let mut values: Vec<Option<Instruction<'instr>>> = vec![None; fields.len()];
+
+        while !self.match_token(TokenKind::RBrace)? {
+            if self.match_token(TokenKind::Comma)? {
+                continue;
+            }
+
+            let field: &Token = self.consume(
+                TokenKind::Identifier,
+                ThrushErrorKind::SyntaxError,
+                message("E0001.expected_field_name", &[]),
+                message("E0001.expected_struct_literal", &[&struct_name]),
+                line,
+            )?;
+
+            let field_name: &str = field.lexeme.as_ref().unwrap();
+
+            self.consume(
+                TokenKind::Colon,
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.expected_colon", &[]),
+                line,
+            )?;
+
+            let value_start: Span = self.peek().span;
+            let value: Instruction<'instr> = self.expression()?;
+            let value_span: Span = self.span_from(value_start);
+
+            let Some(position) = fields.iter().position(|field| field.0 == field_name) else {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::UnknownField,
+                        message("E0013.title", &[]),
+                        message("E0013.unknown_field", &[&struct_name, &field_name]),
+                        field.line,
+                    )
+                    .spanned(field.span),
+                );
+
+                continue;
+            };
+
+            let (_, kind, type_name): (&str, DataTypes, &str) = fields[position];
+
+            if let Err(e) = type_checking::check_type(
+                value.get_data_type(),
+                kind,
+                field.line,
+                message("E0010.title", &[]),
+                message("E0010.expected_found", &[&kind, &value.get_data_type()]),
+            ) {
+                self.errors.push(e.spanned(value_span));
+            } else if matches!(kind, DataTypes::Fn | DataTypes::Struct)
+                && value.get_type_name() != type_name
+            {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::TypeMismatch,
+                        message("E0010.title", &[]),
+                        message(
+                            "E0010.expected_found",
+                            &[&type_name, &value.get_type_name()],
+                        ),
+                        field.line,
+                    )
+                    .spanned(value_span),
+                );
+            }
+
+            if values[position].is_some() {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::DuplicatedDefinition,
+                        message("E0020.duplicated_field_title", &[]),
+                        message("E0020.field_initialized_twice", &[&field_name]),
+                        field.line,
+                    )
+                    .spanned(field.span),
+                );
+            }
+
+            values[position] = Some(value);
+        }
+
+        fields
+            .iter()
+            .zip(values.iter())
+            .filter(|(_, value)| value.is_none())
+            .for_each(|(field, _)| {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::MissingField,
+                        message("E0025.title", &[]),
+                        message("E0025.missing_field", &[&field.0, &struct_name]),
+                        line,
+                    )
+                    .spanned(name.span),
+                );
+            });
+
+        Ok(Instruction::StructLiteral {
+            name: struct_name,
+            fields: values
+                .into_iter()
+                .map(|value| value.unwrap_or(Instruction::Null))
+                .collect(),
+        })
+    }
+
+    fn trait_declaration(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        self.only_advance()?;
+
+        let line: usize = self.previous().line;
+
+        if self.in_function {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.nested_trait", &[]),
+                    line,
+                )
+                .spanned(self.previous().span),
+            );
+        }
+
+        self.consume(
+            TokenKind::Identifier,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.expected_trait_name", &[]),
+            message("E0001.expected_trait_declaration", &[]),
+            line,
+        )?;
+
+        // The methods were registered in the forward declaration, see `Parser::predefine_trait`.
+        self.skip_block(line)?;
+
+        self.match_token(TokenKind::SemiColon)?;
+
+        Ok(Instruction::Null)
+    }
+
+    fn impl_declaration(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        self.only_advance()?;
+
+        let line: usize = self.previous().line;
+
+        if self.in_function {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.nested_impl", &[]),
+                    line,
+                )
+                .spanned(self.previous().span),
+            );
+        }
+
+        let owner: (&str, &str) = self.impl_header(line)?;
+
+        // The implementation was rejected in the forward declaration, see `Parser::predefine_impl`.
+        if !self.objects.implements(owner.0, owner.1) {
+            self.skip_block(line)?;
+
+            return Ok(Instruction::Null);
+        }
+
+        self.consume(
+            TokenKind::LBrace,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_lbrace", &[]),
+            line,
+        )?;
+
+        self.receiver = Some(owner);
+
+        let methods: Result<(), ThrushError> = self.impl_methods(line);
+
+        self.receiver = None;
+
+        methods?;
+
+        self.match_token(TokenKind::SemiColon)?;
+
+        Ok(Instruction::Null)
+    }
+
+    fn impl_header(&mut self, line: usize) -> Result<(&'instr str, &'instr str), ThrushError> {
+        let name: &Token = self.consume(
+            TokenKind::Identifier,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.expected_impl_name", &[]),
+            message("E0001.expected_impl_declaration", &[]),
+            line,
+        )?;
+
+        let name: &str = name.lexeme.as_ref().unwrap();
+
+        // Inherent implementation, the methods are only owned by the struct.
+        if !self.match_token(TokenKind::For)? {
+            return Ok((name, ""));
+        }
+
+        let struct_name: &Token = self.consume(
+            TokenKind::Identifier,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.expected_struct_name", &[]),
+            message("E0001.expected_trait_impl_declaration", &[]),
+            line,
+        )?;
+
+        Ok((struct_name.lexeme.as_ref().unwrap(), name))
+    }
+
+    fn impl_methods(&mut self, line: usize) -> Result<(), ThrushError> {
+        while !self.match_token(TokenKind::RBrace)? {
+            if self.peek().kind != TokenKind::Fn {
+                return Err(ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.expected_method", &[]),
+                    line,
+                )
+                .spanned(self.peek().span));
+            }
+
+            let method: Instruction<'instr> = self.function(false, false, "")?;
+
+            self.stmts.push(method);
+        }
+
+        Ok(())
+    }
+
+    fn match_expression(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        self.only_advance()?;
+
+        let line: usize = self.previous().line;
+        let keyword: Span = self.previous().span;
+
+        let value_start: Span = self.peek().span;
+        let value: Instruction<'instr> = self.expression()?;
+
+        if value.get_data_type() != DataTypes::Enum {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.match_non_enum", &[&value.get_data_type()]),
+                line,
+            )
+            .spanned(self.span_from(value_start)));
+        }
+
+        let type_name: &str = value.get_type_name();
+        let variants: Vec<(&str, Vec<DataTypes>)> =
+            self.objects.get_enum(type_name).unwrap().clone();
+
+        self.consume(
+            TokenKind::LBrace,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_lbrace", &[]),
+            line,
+        )?;
+
+        let mut arms: Vec<MatchArm<'instr>> = Vec::new();
+        let mut patterns: Vec<Span> = Vec::new();
+        let mut covered: Vec<bool> = vec![false; variants.len()];
+        let mut has_wildcard: bool = false;
+
+        while !self.match_token(TokenKind::RBrace)? {
+            if self.match_token(TokenKind::Comma)? {
+                continue;
+            }
+
+            let (pattern, position, bindings): (&Token, Option<usize>, Vec<Option<&str>>) =
+                self.pattern(type_name, &variants, line)?;
+            let pattern_span: Span = self.span_from(pattern.span);
+
+            let mut tag: Option<u32> = None;
+            let mut bindings_types: Vec<DataTypes> = Vec::new();
+
+            if let Some(position) = position {
+                if has_wildcard || covered[position] {
+                    self.errors.push(
+                        ThrushError::Parse(
+                            ThrushErrorKind::UnreachablePattern,
+                            message("E0029.title", &[]),
+                            message(
+                                "E0029.matched_variant",
+                                &[&type_name, &variants[position].0],
+                            ),
+                            pattern.line,
+                        )
+                        .spanned(pattern_span),
+                    );
+                }
+
+                covered[position] = true;
+                tag = Some(position as u32);
+                bindings_types.clone_from(&variants[position].1);
+            } else {
+                if has_wildcard || covered.iter().all(|covered| *covered) {
+                    self.errors.push(
+                        ThrushError::Parse(
+                            ThrushErrorKind::UnreachablePattern,
+                            message("E0029.title", &[]),
+                            message("E0029.matched_wildcard", &[]),
+                            pattern.line,
+                        )
+                        .spanned(pattern_span),
+                    );
+                }
+
+                has_wildcard = true;
+            }
+
+            self.consume(
+                TokenKind::FatArrow,
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.expected_fat_arrow", &[]),
+                pattern.line,
+            )?;
+
+            let body: Instruction<'instr> =
+                self.arm_body(&bindings, &bindings_types, pattern.span, pattern.line)?;
+
+            arms.push((tag, bindings, body));
+            patterns.push(pattern_span);
+        }
+
+        if !has_wildcard && covered.iter().any(|covered| !*covered) {
+            let missing: String = variants
+                .iter()
+                .zip(covered.iter())
+                .filter(|(_, covered)| !**covered)
+                .map(|((name, _), _)| format!("`{}::{}`", type_name, name))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::NonExhaustiveMatch,
+                    message("E0028.title", &[]),
+                    message("E0028.uncovered_variants", &[&missing]),
+                    line,
+                )
+                .spanned(keyword),
+            );
+        }
+
+        let kind: DataTypes = self.match_arms_type(&arms, &patterns, line);
+
+        Ok(Instruction::Match {
+            value: Box::new(value),
+            type_name,
+            arms,
+            kind,
+        })
+    }
+
+    fn arm_body(
+        &mut self,
+        bindings: &[Option<&'instr str>],
+        bindings_types: &[DataTypes],
+        span: Span,
+        line: usize,
+    ) -> Result<Instruction<'instr>, ThrushError> {
+        self.objects.begin_local_scope();
+
+        let mut comptime_bindings: Vec<Instruction<'instr>> = Vec::with_capacity(bindings.len());
+
+        bindings
+            .iter()
+            .zip(bindings_types.iter())
+            .for_each(|(binding, kind)| {
+                if let Some(name) = *binding {
+                    self.objects.insert_new_local(
+                        self.objects.current_scope(),
+                        name,
+                        (*kind, false, false, false, 0, ""),
+                    );
+
+                    comptime_bindings.push(Instruction::Var {
+                        name,
+                        kind: *kind,
+                        value: Box::new(Instruction::Null),
+                        line,
+                        span,
+                        only_comptime: true,
+                        type_name: "",
+                    });
+                }
+            });
+
+        let body: Result<Instruction<'instr>, ThrushError> =
+            if self.peek().kind == TokenKind::LBrace {
+                self.block(&mut comptime_bindings)
+            } else {
+                self.expression()
+            };
+
+        self.objects.end_local_scope();
+
+        body
+    }
+
+    fn pattern(
+        &mut self,
+        type_name: &str,
+        variants: &[(&str, Vec<DataTypes>)],
+        line: usize,
+    ) -> Result<Pattern<'instr>, ThrushError> {
+        let pattern: &Token = self.consume(
+            TokenKind::Identifier,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.expected_pattern", &[]),
+            message("E0001.expected_pattern_help", &[&type_name]),
+            line,
+        )?;
+
+        if pattern.lexeme.as_ref().unwrap() == "_" {
+            return Ok((pattern, None, Vec::new()));
+        }
+
+        // The variants of the results are written without the type, like `Ok(value)`.
+        let variant: &Token =
+            if self.objects.is_result(type_name) && self.peek().kind != TokenKind::ColonColon {
+                pattern
+            } else {
+                if pattern.lexeme.as_ref().unwrap() != type_name {
+                    return Err(ThrushError::Parse(
+                        ThrushErrorKind::TypeMismatch,
+                        message("E0010.title", &[]),
+                        message(
+                            "E0010.pattern_enum",
+                            &[&type_name, &pattern.lexeme.as_ref().unwrap()],
+                        ),
+                        pattern.line,
+                    )
+                    .spanned(pattern.span));
+                }
+
+                self.consume(
+                    TokenKind::ColonColon,
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.expected_colon_colon", &[]),
+                    pattern.line,
+                )?;
+
+                self.consume(
+                    TokenKind::Identifier,
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.expected_variant_name", &[]),
+                    message("E0001.expected_enum_variant", &[&type_name]),
+                    pattern.line,
+                )?
+            };
+
+        let variant_name: &str = variant.lexeme.as_ref().unwrap();
+
+        let position: usize = match variants.iter().position(|(name, _)| *name == variant_name) {
+            Some(position) => position,
+            None => {
+                return Err(ThrushError::Parse(
+                    ThrushErrorKind::UnknownVariant,
+                    message("E0017.title", &[]),
+                    message("E0017.unknown_variant", &[&type_name, &variant_name]),
+                    variant.line,
+                )
+                .spanned(variant.span));
+            }
+        };
+
+        let mut bindings: Vec<Option<&'instr str>> = Vec::new();
+
+        if self.match_token(TokenKind::LParen)? {
+            while !self.match_token(TokenKind::RParen)? {
+                if self.match_token(TokenKind::Comma)? {
+                    continue;
+                }
+
+                let binding: &Token = self.consume(
+                    TokenKind::Identifier,
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.expected_binding", &[]),
+                    variant.line,
+                )?;
+
+                match binding.lexeme.as_ref().unwrap().as_str() {
+                    "_" => bindings.push(None),
+                    binding => bindings.push(Some(binding)),
+                }
+            }
+        }
+
+        if bindings.len() != variants[position].1.len() {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message(
+                        "E0001.binding_count",
+                        &[
+                            &type_name,
+                            &variant_name,
+                            &variants[position].1.len(),
+                            &bindings.len(),
+                        ],
+                    ),
+                    variant.line,
+                )
+                .spanned(self.span_from(pattern.span)),
+            );
+        }
+
+        Ok((pattern, Some(position), bindings))
+    }
+
+    fn match_arms_type(
+        &mut self,
+        arms: &[MatchArm<'instr>],
+        patterns: &[Span],
+        line: usize,
+    ) -> DataTypes {
+        if arms.is_empty() {
+            return DataTypes::Void;
+        }
+
+        let mut kind: DataTypes = arms[0].2.get_data_type();
+
+        arms.iter().zip(patterns).skip(1).for_each(|(arm, span)| {
+            match type_checking::check_branches_type(kind, arm.2.get_data_type(), line) {
+                Ok(arms_kind) => kind = arms_kind,
+                Err(e) => self.errors.push(e.spanned(*span)),
+            }
+
+            if matches!(
+                kind,
+                DataTypes::Enum | DataTypes::Fn | DataTypes::Closure | DataTypes::Struct
+            ) && arms[0].2.get_type_name() != arm.2.get_type_name()
+            {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::TypeMismatch,
+                        message("E0010.title", &[]),
+                        message(
+                            "E0010.arm_types",
+                            &[&arms[0].2.get_type_name(), &arm.2.get_type_name()],
+                        ),
+                        line,
+                    )
+                    .spanned(*span),
+                );
+            }
+        });
+
+        kind
+    }
+
+    fn print(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        self.only_advance()?;
+
+        let start: &Token = self.consume(
+            TokenKind::LParen,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_lparen", &[]),
+            self.previous().line,
+        )?;
+
+        let mut args: Vec<Instruction<'instr>> = Vec::with_capacity(24);
+        let mut spans: Vec<Span> = Vec::new();
+
+        while !self.match_token(TokenKind::RParen)? {
+            if self.match_token(TokenKind::Comma)? {
+                continue;
+            }
+
+            let arg_start: Span = self.peek().span;
+
+            args.push(self.expression()?);
+            spans.push(self.span_from(arg_start));
+        }
+
+        let span: Span = start.span.to(self.previous().span);
+
+        self.parse_string_formatted(&args, &spans, span, start.line, true);
+
+        self.consume(
+            TokenKind::SemiColon,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_semicolon", &[]),
+            start.line,
+        )?;
+
+        Ok(Instruction::Print(args))
+    }
+
+    fn println(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        self.only_advance()?;
+
+        let start: &Token = self.consume(
+            TokenKind::LParen,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_lparen", &[]),
+            self.previous().line,
+        )?;
+
+        let mut args: Vec<Instruction<'instr>> = Vec::new();
+        let mut spans: Vec<Span> = Vec::new();
+
+        while !self.match_token(TokenKind::RParen)? {
+            if self.match_token(TokenKind::Comma)? {
+                continue;
+            }
+
+            let arg_start: Span = self.peek().span;
+
+            args.push(self.expression()?);
+            spans.push(self.span_from(arg_start));
+        }
+
+        let span: Span = start.span.to(self.previous().span);
+
+        self.parse_string_formatted(&args, &spans, span, start.line, false);
+
+        self.consume(
+            TokenKind::SemiColon,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_semicolon", &[]),
+            start.line,
+        )?;
+
+        Ok(Instruction::Println(args))
+    }
+
+    fn expression(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        let instr: Instruction = self.ternary()?;
+
+        self.objects.decrease_local_references();
+
+        Ok(instr)
+    }
+
+    fn ternary(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        let cond_start: Span = self.peek().span;
+        let cond: Instruction<'instr> = self.or()?;
+        let cond_span: Span = self.span_from(cond_start);
+
+        if !self.match_token(TokenKind::Question)? {
+            return Ok(cond);
+        }
+
+        let line: usize = self.previous().line;
+        let question: Span = self.previous().span;
+
+        if cond.get_data_type() != DataTypes::Bool {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::TypeMismatch,
+                    message("E0010.title", &[]),
+                    message("E0010.non_bool_ternary_condition", &[&cond.get_data_type()]),
+                    line,
+                )
+                .spanned(cond_span),
+            );
+        }
+
+        let block: Instruction<'instr> = self.ternary()?;
+
+        self.consume(
+            TokenKind::Colon,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_ternary_colon", &[]),
+            line,
+        )?;
+
+        let otherwise: Instruction<'instr> = self.ternary()?;
+
+        let kind: DataTypes = self.branches_type(&block, &otherwise, question, line);
+
+        Ok(Instruction::If {
+            cond: Box::new(cond),
+            block: Box::new(block),
+            otherwise: Some(Box::new(otherwise)),
+            kind,
+        })
+    }
+
+    fn or(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        let start: Span = self.peek().span;
+        let mut instr: Instruction<'_> = self.and()?;
+
+        while self.match_token(TokenKind::Or)? {
+            let op: &TokenKind = &self.previous().kind;
+            let right: Instruction<'instr> = self.and()?;
+            let span: Span = self.span_from(start);
+
+            type_checking::check_binary_instr(
+                op,
+                &instr.get_data_type(),
+                &right.get_data_type(),
+                self.previous().line,
+            )
+            .map_err(|error| error.spanned(span))?;
+
+            instr = Instruction::Binary {
+                left: Box::new(instr),
+                op,
+                right: Box::new(right),
+                kind: DataTypes::Bool,
+                span,
+                line: self.previous().line,
+            }
+        }
+
+        Ok(instr)
+    }
+
+    fn and(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        let start: Span = self.peek().span;
+        let mut instr: Instruction<'_> = self.equality()?;
+
+        while self.match_token(TokenKind::And)? {
+            let op: &TokenKind = &self.previous().kind;
+            let right: Instruction<'_> = self.equality()?;
+            let span: Span = self.span_from(start);
+
+            type_checking::check_binary_instr(
+                op,
+                &instr.get_data_type(),
+                &right.get_data_type(),
+                self.previous().line,
+            )
+            .map_err(|error| error.spanned(span))?;
+
+            instr = Instruction::Binary {
+                left: Box::new(instr),
+                op,
+                right: Box::new(right),
+                kind: DataTypes::Bool,
+                span,
+                line: self.previous().line,
+            }
+        }
+
+        Ok(instr)
+    }
+
+    fn equality(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        let start: Span = self.peek().span;
+        let mut instr: Instruction<'_> = self.comparison()?;
+
+        while self.match_token(TokenKind::BangEq)? || self.match_token(TokenKind::EqEq)? {
+            let op: &TokenKind = &self.previous().kind;
+
+            if self.peek().kind == TokenKind::Null {
+                let line: usize = self.previous().line;
+
+                instr = self
+                    .null_check(instr, op, line)
+                    .map_err(|error| error.spanned(self.span_from(start)))?;
+
+                continue;
+            }
+
+            let right: Instruction<'_> = self.comparison()?;
+            let span: Span = self.span_from(start);
+
+            if instr.get_data_type() == DataTypes::Struct
+                || right.get_data_type() == DataTypes::Struct
+            {
+                let line: usize = self.previous().line;
+                let kind: DataTypes = self
+                    .operator_overload(op, &instr, &right, line)
+                    .map_err(|error| error.spanned(span))?;
+
+                instr = Instruction::Binary {
+                    left: Box::from(instr),
+                    op,
+                    right: Box::from(right),
+                    kind,
+                    span,
+                    line,
+                };
+
+                continue;
+            }
+
+            type_checking::check_binary_instr(
+                op,
+                &instr.get_data_type(),
+                &right.get_data_type(),
+                self.previous().line,
+            )
+            .map_err(|error| error.spanned(span))?;
+
+            instr = Instruction::Binary {
+                left: Box::from(instr),
+                op,
+                right: Box::from(right),
+                kind: DataTypes::Bool,
+                span,
+                line: self.previous().line,
+            }
+        }
+
+        Ok(instr)
+    }
+
+    fn comparison(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        let start: Span = self.peek().span;
+        let mut instr: Instruction<'_> = self.term()?;
+
+        while self.match_token(TokenKind::Greater)?
+            || self.match_token(TokenKind::GreaterEq)?
+            || self.match_token(TokenKind::Less)?
+            || self.match_token(TokenKind::LessEq)?
+        {
+            let op: &TokenKind = &self.previous().kind;
+            let right: Instruction<'_> = self.term()?;
+            let span: Span = self.span_from(start);
+
+            if instr.get_data_type() == DataTypes::Struct
+                || right.get_data_type() == DataTypes::Struct
+            {
+                let line: usize = self.previous().line;
+                let kind: DataTypes = self
+                    .operator_overload(op, &instr, &right, line)
+                    .map_err(|error| error.spanned(span))?;
+
+                instr = Instruction::Binary {
+                    left: Box::from(instr),
+                    op,
+                    right: Box::from(right),
+                    kind,
+                    span,
+                    line,
+                };
+
+                continue;
+            }
+
+            type_checking::check_binary_instr(
+                op,
+                &instr.get_data_type(),
+                &right.get_data_type(),
+                self.previous().line,
+            )
+            .map_err(|error| error.spanned(span))?;
+
+            instr = Instruction::Binary {
+                left: Box::from(instr),
+                op,
+                right: Box::from(right),
+                kind: DataTypes::Bool,
+                span,
+                line: self.previous().line,
+            };
+        }
+
+        Ok(instr)
+    }
+
+    fn term(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        let start: Span = self.peek().span;
+        let mut instr: Instruction<'_> = self.unary()?;
+
+        while self.match_token(TokenKind::Plus)?
+            || self.match_token(TokenKind::Minus)?
+            || self.match_token(TokenKind::Slash)?
+            || self.match_token(TokenKind::Star)?
+        {
+            let op: &TokenKind = &self.previous().kind;
+            let right: Instruction<'_> = self.unary()?;
+            let span: Span = self.span_from(start);
+
+            if instr.get_data_type() == DataTypes::Struct
+                || right.get_data_type() == DataTypes::Struct
+            {
+                let line: usize = self.previous().line;
+                let kind: DataTypes = self
+                    .operator_overload(op, &instr, &right, line)
+                    .map_err(|error| error.spanned(span))?;
+
+                instr = Instruction::Binary {
+                    left: Box::from(instr),
+                    op,
+                    right: Box::from(right),
+                    kind,
+                    span,
+                    line,
+                };
+
+                continue;
+            }
+
+            let left_type: DataTypes = instr.get_data_type();
+            let right_type: DataTypes = right.get_data_type();
+
+            let kind: DataTypes = if left_type.is_integer() && right_type.is_integer() {
+                left_type.calculate_integer_datatype(right_type)
+            } else {
+                self.in_var_type
+            };
+
+            type_checking::check_binary_instr(
+                op,
+                &instr.get_data_type(),
+                &right.get_data_type(),
+                self.previous().line,
+            )
+            .map_err(|error| error.spanned(span))?;
+
+            instr = Instruction::Binary {
+                left: Box::from(instr),
+                op,
+                right: Box::from(right),
+                kind,
+                span,
+                line: self.previous().line,
+            };
+        }
+
+        Ok(instr)
+    }
+
+    fn operator_overload(
+        &mut self,
+        op: &TokenKind,
+        left: &Instruction<'instr>,
+        right: &Instruction<'instr>,
+        line: usize,
+    ) -> Result<DataTypes, ThrushError> {
+        /*
+            `v * 2` calls `v.mul(2)`. The operators aren't commutative, so `2 * v` calls the
+            reflected method `v.rmul(2)` and the comparisons need the struct at the left.
+        */
+        let (receiver, other, method): (&Instruction, &Instruction, &str) =
+            if left.get_data_type() == DataTypes::Struct {
+                (left, right, op.as_operator_method().unwrap())
+            } else if let Some(method) = op.as_reflected_operator_method() {
+                (right, left, method)
+            } else {
+                return Err(ThrushError::Parse(
+                    ThrushErrorKind::TypeChecking,
+                    message("E0011.title", &[]),
+                    message("E0011.struct_left_operand", &[&op, &right.get_type_name()]),
+                    line,
+                ));
+            };
+
+        let struct_name: &str = receiver.get_type_name();
+
+        let signature: Option<Signature> = match self.objects.get_method((struct_name, ""), method)
+        {
+            Some(mangled) => {
+                let object: FoundObject = self.objects.get_object(mangled, line)?;
+                self.objects.get_signature(object.7).cloned()
+            }
+            None => None,
+        };
+
+        type_checking::check_overloaded_binary_instr(
+            op,
+            method,
+            struct_name,
+            signature.as_ref(),
+            (other.get_data_type(), other.get_type_name()),
+            line,
+        )
+    }
+
+    fn unary(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        if self.match_token(TokenKind::Bang)? {
+            let line: usize = self.previous().line;
+            let start: Span = self.previous().span;
+
+            let op: &TokenKind = &self.previous().kind;
+            let value: Instruction<'instr> = self.postfix()?;
+            let span: Span = self.span_from(start);
+
+            type_checking::check_unary_instr(op, &value.get_data_type(), self.previous().line)
+                .map_err(|error| error.spanned(span))?;
+
+            return Ok(Instruction::Unary {
+                op,
+                value: Box::from(value),
+                kind: DataTypes::Bool,
+                span,
+                line,
+            });
+        } else if self.match_token(TokenKind::PlusPlus)?
+            | self.match_token(TokenKind::MinusMinus)?
+            | self.match_token(TokenKind::Minus)?
+        {
+            let line: usize = self.previous().line;
+            let start: Span = self.previous().span;
+
+            let op: &TokenKind = &self.previous().kind;
+            let mut value: Instruction<'instr> = self.postfix()?;
+            let span: Span = self.span_from(start);
+
+            if let Instruction::Integer(_, _, is_signed) = &mut value {
+                if *op == TokenKind::Minus {
+                    *is_signed = true;
+                    return Ok(value);
+                }
+            }
+
+            let value_type: &DataTypes = &value.get_data_type();
+
+            type_checking::check_unary_instr(op, value_type, self.previous().line)
+                .map_err(|error| error.spanned(span))?;
+
+            return Ok(Instruction::Unary {
+                op,
+                value: Box::from(value),
+                kind: *value_type,
+                span,
+                line,
+            });
+        }
+
+        let instr: Instruction<'_> = self.postfix()?;
+
+        Ok(instr)
+    }
+
+    fn primary(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        let start: Span = self.peek().span;
+
+        let primary: Instruction = match &self.peek().kind {
+            TokenKind::Error => {
+                self.only_advance()?;
+                return Err(ThrushError::None);
+            }
+
+            TokenKind::Match => return self.match_expression(),
+            TokenKind::If => return self.if_expression(),
+            TokenKind::LBrace => return self.block(&mut []),
+            TokenKind::Fn => return self.lambda(),
+            TokenKind::Null => return self.null_value(),
+
+            TokenKind::Identifier
+                if self
+                    .objects
+                    .get_generic(self.peek().lexeme.as_ref().unwrap())
+                    .is_some() =>
+            {
+                return self.generic_call()
+            }
+
+            TokenKind::Identifier
+                if matches!(self.peek().lexeme.as_deref(), Some("Ok" | "Err"))
+                    && self.check_next(TokenKind::LParen) =>
+            {
+                return self.result_value()
+            }
+
+            TokenKind::LParen => {
+                let line: usize = self.peek().line;
+
+                self.only_advance()?;
+
+                let instr: Instruction<'instr> = self.expression()?;
+                let kind: DataTypes = instr.get_data_type();
+
+                if !instr.is_binary() {
+                    self.errors.push(
+                        ThrushError::Parse(
+                            ThrushErrorKind::SyntaxError,
+                            message("E0001.title", &[]),
+                            message("E0001.group_without_binary", &[]),
+                            line,
+                        )
+                        .spanned(self.span_from(start)),
+                    );
+                }
+
+                self.consume(
+                    TokenKind::RParen,
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.expected_rparen", &[]),
+                    line,
+                )?;
+
+                return Ok(Instruction::Group {
+                    instr: Box::new(instr),
+                    kind,
+                });
+            }
+
+            TokenKind::String => {
+                let current: &Token = self.advance()?;
+
+                Instruction::String(
+                    current.lexeme.as_ref().unwrap().to_string(),
+                    current.lexeme.as_ref().unwrap().contains("{}"),
+                )
+            }
+            TokenKind::Char => {
+                Instruction::Char(self.advance()?.lexeme.as_ref().unwrap().as_bytes()[0])
+            }
+
+            kind => match kind {
+                TokenKind::Integer(kind, num, is_signed) => {
+                    self.only_advance()?;
+
+                    let instr: Instruction<'instr> = match kind {
+                        DataTypes::I8 => Instruction::Integer(DataTypes::I8, *num, *is_signed),
+                        DataTypes::I16 => Instruction::Integer(DataTypes::I16, *num, *is_signed),
+                        DataTypes::I32 => Instruction::Integer(DataTypes::I32, *num, *is_signed),
+                        DataTypes::I64 => Instruction::Integer(DataTypes::I64, *num, *is_signed),
+                        _ => unreachable!(),
+                    };
+
+                    if self.match_token(TokenKind::PlusPlus)?
+                        | self.match_token(TokenKind::MinusMinus)?
+                    {
+                        let span: Span = self.span_from(start);
+
+                        type_checking::check_unary_instr(
+                            &self.previous().kind,
+                            kind,
+                            self.previous().line,
+                        )
+                        .map_err(|error| error.spanned(span))?;
+
+                        return Ok(Instruction::Unary {
+                            op: &self.previous().kind,
+                            value: Box::from(instr),
+                            kind: *kind,
+                            span,
+                            line: self.previous().line,
+                        });
+                    }
+
+                    instr
+                }
+
+                TokenKind::Float(kind, num, is_signed) => {
+                    self.only_advance()?;
+
+                    let instr: Instruction<'instr> = match kind {
+                        DataTypes::F32 => Instruction::Float(DataTypes::F32, *num, *is_signed),
+                        DataTypes::F64 => Instruction::Float(DataTypes::F64, *num, *is_signed),
+                        _ => unreachable!(),
+                    };
+
+                    if self.match_token(TokenKind::PlusPlus)?
+                        | self.match_token(TokenKind::MinusMinus)?
+                    {
+                        let span: Span = self.span_from(start);
+
+                        type_checking::check_unary_instr(
+                            &self.previous().kind,
+                            kind,
+                            self.previous().line,
+                        )
+                        .map_err(|error| error.spanned(span))?;
+
+                        return Ok(Instruction::Unary {
+                            op: &self.previous().kind,
+                            value: Box::from(instr),
+                            kind: *kind,
+                            span,
+                            line: self.previous().line,
+                        });
+                    }
+
+                    instr
+                }
+
+                TokenKind::Identifier
+                    if self.check_next(TokenKind::ColonColon)
+                        && self
+                            .objects
+                            .get_struct(self.peek().lexeme.as_ref().unwrap())
+                            .is_some() =>
+                {
+                    self.associated_call()?
+                }
+
+                TokenKind::Identifier if self.check_next(TokenKind::ColonColon) => {
+                    self.enum_value()?
+                }
+
+                TokenKind::Identifier
+                    if self.check_next(TokenKind::LBrace)
+                        && self
+                            .objects
+                            .get_struct(self.peek().lexeme.as_ref().unwrap())
+                            .is_some() =>
+                {
+                    self.struct_literal()?
+                }
+
+                TokenKind::Identifier | TokenKind::This => {
+                    let current: &Token = self.peek();
+                    let line: usize = self.peek().line;
+
+                    // type is_null, is_function, ignore_more_params?, ?params
+                    let var: FoundObject = self
+                        .objects
+                        .get_object(current.lexeme.as_ref().unwrap(), line)
+                        .map_err(|error| self.suggest(error, current, self.value_names()))?;
+
+                    let name: &str = current.lexeme.as_ref().unwrap();
+
+                    self.only_advance()?;
+
+                    if var.0 == DataTypes::Closure
+                        && self.peek().kind != TokenKind::Eq
+                        && self.moved_closures.contains(&name)
+                    {
+                        self.errors.push(
+                            ThrushError::Parse(
+                                ThrushErrorKind::MovedClosure,
+                                message("E0038.title", &[]),
+                                message("E0038.moved_closure", &[&name]),
+                                line,
+                            )
+                            .spanned(current.span),
+                        );
+                    }
+
+                    if self.peek().kind == TokenKind::LeftBracket {
+                        self.consume(
+                            TokenKind::LeftBracket,
+                            ThrushErrorKind::SyntaxError,
+                            message("E0001.title", &[]),
+                            message("E0001.expected_lbracket", &[]),
+                            line,
+                        )?;
+
+                        let expr: Instruction<'instr> = self.primary()?;
+
+                        self.consume(
+                            TokenKind::RightBracket,
+                            ThrushErrorKind::SyntaxError,
+                            message("E0001.title", &[]),
+                            message("E0001.expected_rbracket", &[]),
+                            line,
+                        )?;
+
+                        let kind: DataTypes = if var.0 == DataTypes::String {
+                            DataTypes::Char
+                        } else {
+                            todo!()
+                        };
+
+                        if let Instruction::Integer(_, num, _) = expr {
+                            return Ok(Instruction::Indexe {
+                                origin: name,
+                                index: num as u64,
+                                kind,
+                            });
+                        }
+
+                        self.errors.push(
+                            ThrushError::Parse(
+                                ThrushErrorKind::SyntaxError,
+                                message("E0001.title", &[]),
+                                message("E0001.expected_index", &[]),
+                                self.previous().line,
+                            )
+                            .spanned(self.span_from(start)),
+                        );
+                    } else if self.peek().kind == TokenKind::Eq {
+                        self.only_advance()?;
+
+                        let value_start: Span = self.peek().span;
+
+                        let in_enum_type: &str = mem::replace(&mut self.in_enum_type, var.7);
+                        let expr: Result<Instruction<'instr>, ThrushError> = self.expression();
+
+                        self.in_enum_type = in_enum_type;
+
+                        let value_span: Span = self.span_from(value_start);
+                        let expr: Instruction<'instr> =
+                            self.wrap_optional(var.7, expr?, value_span, line);
+
+                        let span: Span = self.span_from(start);
+
+                        if let Err(err) = type_checking::check_type(
+                            expr.get_data_type(),
+                            var.0,
+                            line,
+                            message("E0010.title", &[]),
+                            message("E0010.expected_found", &[&var.0, &expr.get_data_type()]),
+                        ) {
+                            self.errors.push(err.spanned(value_span).label(
+                                current.span,
+                                message("E0010.variable_type", &[&name, &var.0]),
+                            ));
+                        } else if matches!(
+                            var.0,
+                            DataTypes::Enum
+                                | DataTypes::Fn
+                                | DataTypes::Closure
+                                | DataTypes::Struct
+                        ) && expr.get_type_name() != var.7
+                        {
+                            self.errors.push(
+                                ThrushError::Parse(
+                                    ThrushErrorKind::TypeMismatch,
+                                    message("E0010.title", &[]),
+                                    message(
+                                        "E0010.expected_found",
+                                        &[&var.7, &expr.get_type_name()],
+                                    ),
+                                    line,
+                                )
+                                .spanned(value_span)
+                                .label(
+                                    current.span,
+                                    message("E0010.variable_type", &[&name, &var.7]),
+                                ),
+                            );
+                        }
+
+                        self.consume(
+                            TokenKind::SemiColon,
+                            ThrushErrorKind::SyntaxError,
+                            message("E0001.title", &[]),
+                            message("E0001.expected_semicolon", &[]),
+                            line,
+                        )?;
+
+                        // The variable owns the new closure, the closure assigned to it is moved.
+                        self.moved_closures.retain(|moved| *moved != name);
+
+                        if let Instruction::RefVar {
+                            name: source,
+                            kind: DataTypes::Closure,
+                            ..
+                        } = &expr
+                        {
+                            self.move_closure(source);
+                        }
+
+                        self.objects.insert_new_local(self.scope, name, (var.0, false, false, false, 0, var.7));
+
+                        return Ok(Instruction::MutVar {
+                            name,
+                            value: Box::new(expr),
+                            kind: var.0,
+                            type_name: var.7,
+                            span,
+                            line,
+                        });
+
+                    } else if self.peek().kind == TokenKind::LParen {
+                        self.only_advance()?;
+
+                        if !var.3 && matches!(var.0, DataTypes::Fn | DataTypes::Closure) {
+                            return self.indirect_call(
+                                name,
+                                var.7,
+                                var.0 == DataTypes::Closure,
+                                line,
+                            );
+                        }
+
+                        return self.call(name, var, current.span, line);
+                    } else if self.peek().kind == TokenKind::Dot {
+                        return self.property(name, var, line);
+                    }
+
+                    if var.3 {
+                        if var.0 == DataTypes::Struct {
+                            self.errors.push(
+                                ThrushError::Parse(
+                                    ThrushErrorKind::SyntaxError,
+                                    message("E0001.title", &[]),
+                                    message("E0001.struct_return_as_value", &[&name]),
+                                    line,
+                                )
+                                .spanned(current.span),
+                            );
+                        }
+
+                        self.references.push(name);
+
+                        return Ok(Instruction::FunctionRef {
+                            name,
+                            type_name: var.7,
+                        });
+                    }
+
+                    let refvar: Instruction<'_> = Instruction::RefVar {
+                        name,
+                        line,
+                        span: current.span,
+                        kind: var.0,
+                        type_name: var.7,
+                    };
+
+                    if self.match_token(TokenKind::PlusPlus)?
+                        | self.match_token(TokenKind::MinusMinus)?
+                    {
+                        let span: Span = self.span_from(start);
+
+                        type_checking::check_unary_instr(
+                            &current.kind,
+                            &refvar.get_data_type(),
+                            line,
+                        )
+                        .map_err(|error| error.spanned(span))?;
+
+                        let expr: Instruction<'_> = Instruction::Unary {
+                            op: &current.kind,
+                            value: Box::from(refvar),
+                            kind: DataTypes::I64,
+                            span,
+                            line,
+                        };
+
+                        self.consume(
+                            TokenKind::SemiColon,
+                            ThrushErrorKind::SyntaxError,
+                            message("E0001.title", &[]),
+                            message("E0001.expected_semicolon", &[]),
+                            line,
+                        )?;
+
+                        return Ok(expr);
+                    }
+
+                    refvar
+                }
+
+                TokenKind::Pass => {
+                    self.only_advance()?;
+                    Instruction::Pass
+                }
+
+                TokenKind::True => {
+                    self.only_advance()?;
+                    Instruction::Boolean(true)
+                }
+
+                TokenKind::False => {
+                    self.only_advance()?;
+                    Instruction::Boolean(false)
+                }
+
+                _ => {
+                    self.only_advance()?;
+
+                    self.errors.push(
+                        ThrushError::Parse(
+                            ThrushErrorKind::SyntaxError,
+                            message("E0001.title", &[]),
+                            message(
+                                "E0001.statement_not_allowed",
+                                &[&self.previous().lexeme.as_ref().unwrap()],
+                            ),
+                            self.previous().line,
+                        )
+                        .spanned(self.previous().span),
+                    );
+
+                    Instruction::Null
+                }
+            },
+        };
+
+        Ok(primary)
+    }
+
+    fn consume(
+        &mut self,
+        kind: TokenKind,
+        error_kind: ThrushErrorKind,
+        error_title: String,
+        help: String,
+        line: usize,
+    ) -> Result<&'instr Token, ThrushError> {
+        if self.peek().kind == kind {
+            return self.advance();
+        }
+
+        // The lexer already reported the error token.
+        if self.peek().kind == TokenKind::Error {
+            return Err(ThrushError::None);
+        }
+
+        let error: ThrushError =
+            ThrushError::Parse(error_kind, error_title, help, line).spanned(self.peek().span);
+
+        // A missing `;` goes right after the previous token.
+        if kind == TokenKind::SemiColon && self.current > 0 {
+            let end: usize = self.previous().span.end;
+            return Err(error.fix(Span::new(end, end), String::from(";")));
+        }
+
+        Err(error)
+    }
+
+    fn call(
+        &mut self,
+        name: &'instr str,
+        object: FoundObject<'instr>,
+        span: Span,
+        line: usize,
+    ) -> Result<Instruction<'instr>, ThrushError> {
+        if !object.3 {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.call_non_function", &[]),
+                line,
+            )
+            .spanned(span));
+        }
+
+        let arguments: Arguments<'instr> = self.call_args(line)?;
+
+        self.build_call(name, object, arguments, line)
+    }
+
+    #[inline]
+    fn move_closure(&mut self, name: &'instr str) {
+        self.objects.modify_deallocation(name, false, true);
+        self.moved_closures.push(name);
+    }
+
+    fn call_args(&mut self, line: usize) -> Result<Arguments<'instr>, ThrushError> {
+        let start: Span = self.previous().span;
+
+        let mut args: Vec<Instruction<'instr>> = Vec::new();
+        let mut spans: Vec<Span> = Vec::new();
+
+        while self.peek().kind != TokenKind::RParen {
+            if self.match_token(TokenKind::Comma)? {
+                continue;
+            }
+
+            let arg_start: Span = self.peek().span;
+            let arg: Instruction<'instr> = self.expression()?;
+
+            spans.push(self.span_from(arg_start));
+
+            // The callee takes the ownership of the closures passed by value.
+            if let Instruction::RefVar {
+                name,
+                kind: DataTypes::Closure,
+                ..
+            } = &arg
+            {
+                self.move_closure(name);
+            }
+
+            args.push(arg);
+        }
+
+        self.consume(
+            TokenKind::RParen,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_rparen", &[]),
+            line,
+        )?;
+
+        Ok((args, spans, self.span_from(start)))
+    }
+
+    fn build_call(
+        &mut self,
+        name: &'instr str,
+        object: FoundObject<'instr>,
+        arguments: Arguments<'instr>,
+        line: usize,
+    ) -> Result<Instruction<'instr>, ThrushError> {
+        let (args, spans, span): Arguments = arguments;
+        let mut index: usize = 0;
+
+        if object.5.len() != args.len() && !object.4 {
+            let args_types: String = if !args.is_empty() {
+                args.iter()
+                    .map(|param| param.get_data_type().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            } else {
+                DataTypes::Void.to_string()
+            };
+
+            let params_types: String = object
+                .5
+                .iter()
+                .map(|param| param.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.argument_count", &[&params_types, &args_types]),
+                    line,
+                )
+                .spanned(span),
+            );
+        }
+
+        let (params, _, type_name): Signature = self
+            .objects
+            .get_signature(object.7)
+            .cloned()
+            .unwrap_or((Vec::new(), DataTypes::Void, ""));
+
+        if !object.4 {
+            args.iter().for_each(|arg| {
+                let arg_kind: DataTypes = arg.get_data_type();
+
+                if object.5.len() > index && object.5[index] != arg_kind {
+                    self.errors.push(
+                        ThrushError::Parse(
+                            ThrushErrorKind::SyntaxError,
+                            message("E0001.title", &[]),
+                            message(
+                                "E0001.argument_type",
+                                &[&object.5[index], &index, &arg_kind],
+                            ),
+                            line,
+                        )
+                        .spanned(spans[index]),
+                    );
+                } else if params.len() > index
+                    && matches!(
+                        arg_kind,
+                        DataTypes::Fn | DataTypes::Closure | DataTypes::Struct
+                    )
+                    && params[index].1 != arg.get_type_name()
+                {
+                    self.errors.push(
+                        ThrushError::Parse(
+                            ThrushErrorKind::SyntaxError,
+                            message("E0001.title", &[]),
+                            message(
+                                "E0001.argument_type",
+                                &[&params[index].1, &index, &arg.get_type_name()],
+                            ),
+                            line,
+                        )
+                        .spanned(spans[index]),
+                    );
+                }
+
+                index += 1;
+            });
+        }
+        
+        self.references.push(name);
+
+        Ok(Instruction::Call {
+            name,
+            args,
+            kind: object.0,
+            type_name,
+        })
+    }
+
+    fn property(
+        &mut self,
+        name: &'instr str,
+        object: FoundObject<'instr>,
+        line: usize,
+    ) -> Result<Instruction<'instr>, ThrushError> {
+        let receiver: Span = self.previous().span;
+
+        self.only_advance()?;
+
+        if object.0 != DataTypes::Struct {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.no_properties", &[&name, &object.0]),
+                line,
+            )
+            .spanned(receiver));
+        }
+
+        let property: &Token = self.consume(
+            TokenKind::Identifier,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_property", &[]),
+            line,
+        )?;
+
+        let property_name: &str = property.lexeme.as_ref().unwrap();
+
+        if self.match_token(TokenKind::LParen)? {
+            return self.method_call(name, object.7, property_name, property.span, line);
+        }
+
+        let field: Option<(usize, DataTypes, &str)> =
+            self.objects.get_struct(object.7).and_then(|fields| {
+                fields
+                    .iter()
+                    .position(|field| field.0 == property_name)
+                    .map(|index| (index, fields[index].1, fields[index].2))
+            });
+
+        let Some((index, kind, type_name)) = field else {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::UnknownField,
+                message("E0013.title", &[]),
+                message("E0013.unknown_field", &[&object.7, &property_name]),
+                line,
+            )
+            .spanned(property.span));
+        };
+
+        if self.match_token(TokenKind::Eq)? {
+            let value_start: Span = self.peek().span;
+            let value: Instruction<'instr> = self.expression()?;
+            let value_span: Span = self.span_from(value_start);
+
+            if let Err(e) = type_checking::check_type(
+                value.get_data_type(),
+                kind,
+                line,
+                message("E0010.title", &[]),
+                message("E0010.expected_found", &[&kind, &value.get_data_type()]),
+            ) {
+                self.errors.push(e.spanned(value_span));
+            } else if matches!(kind, DataTypes::Fn | DataTypes::Struct)
+                && value.get_type_name() != type_name
+            {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::TypeMismatch,
+                        message("E0010.title", &[]),
+                        message(
+                            "E0010.expected_found",
+                            &[&type_name, &value.get_type_name()],
+                        ),
+                        line,
+                    )
+                    .spanned(value_span),
+                );
+            }
+
+            self.consume(
+                TokenKind::SemiColon,
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.expected_semicolon", &[]),
+                line,
+            )?;
+
+            return Ok(Instruction::MutProperty {
+                name,
+                struct_name: object.7,
+                index: index as u32,
+                value: Box::new(value),
+                kind,
+            });
+        }
+
+        Ok(Instruction::Property {
+            name,
+            struct_name: object.7,
+            index: index as u32,
+            kind,
+            type_name,
+        })
+    }
+
+    fn method_call(
+        &mut self,
+        receiver: &'instr str,
+        struct_name: &'instr str,
+        name: &'instr str,
+        span: Span,
+        line: usize,
+    ) -> Result<Instruction<'instr>, ThrushError> {
+        let methods: Vec<&str> = self.objects.find_methods(struct_name, name);
+
+        if methods.is_empty() {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::UnknownMethod,
+                message("E0015.title", &[]),
+                message("E0015.unknown_method", &[&struct_name, &name]),
+                line,
+            )
+            .spanned(span));
+        }
+
+        if methods.len() > 1 {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::AmbiguousMethod,
+                message("E0021.title", &[]),
+                message("E0021.ambiguous_method", &[&name, &struct_name]),
+                line,
+            )
+            .spanned(span));
+        }
+
+        let object: FoundObject = self.objects.get_object(methods[0], line)?;
+        let arguments: Arguments<'instr> = self.call_args(line)?;
+
+        match self.build_call(methods[0], object, arguments, line)? {
+            Instruction::Call {
+                name,
+                args,
+                kind,
+                type_name,
+            } => Ok(Instruction::MethodCall {
+                name,
+                receiver,
+                args,
+                kind,
+                type_name,
+            }),
+            instr => Ok(instr),
+        }
+    }
+
+    fn associated_call(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        let name: &Token = self.advance()?;
+        let line: usize = name.line;
+        let struct_name: &'instr str = name.lexeme.as_ref().unwrap();
+
+        self.only_advance()?;
+
+        let function: &Token = self.consume(
+            TokenKind::Identifier,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.expected_function_name", &[]),
+            message("E0001.expected_associated_function", &[&struct_name]),
+            line,
+        )?;
+
+        let function_name: &str = function.lexeme.as_ref().unwrap();
+
+        let Some(mangled) = self.objects.get_associated(struct_name, function_name) else {
+            if !self
+                .objects
+                .find_methods(struct_name, function_name)
+                .is_empty()
+            {
+                return Err(ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message(
+                        "E0001.method_without_receiver",
+                        &[&function_name, &function_name],
+                    ),
+                    line,
+                )
+                .spanned(function.span));
+            }
+
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::UnknownFunction,
+                message("E0014.title", &[]),
+                message(
+                    "E0014.unknown_associated_function",
+                    &[&struct_name, &function_name],
+                ),
+                line,
+            )
+            .spanned(function.span));
+        };
+
+        self.consume(
+            TokenKind::LParen,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message(
+                "E0001.associated_function_not_called",
+                &[&struct_name, &function_name],
+            ),
+            line,
+        )?;
+
+        let object: FoundObject = self.objects.get_object(mangled, line)?;
+        let arguments: Arguments<'instr> = self.call_args(line)?;
+
+        self.build_call(mangled, object, arguments, line)
+    }
+
+    fn mangle_method(&mut self, owner: (&'instr str, &'instr str), name: &str) -> &'instr str {
+        if owner.1.is_empty() {
+            return self.objects.intern(format!("{}::{}", owner.0, name));
+        }
+
+        self.objects
+            .intern(format!("{}::{}::{}", owner.0, owner.1, name))
+    }
+
+    fn generic_call(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        let name: &Token = self.advance()?;
+        let line: usize = name.line;
+        let span: Span = name.span;
+        let name: &'instr str = name.lexeme.as_ref().unwrap();
+
+        self.consume(
+            TokenKind::LParen,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.generic_function_not_called", &[&name]),
+            line,
+        )?;
+
+        let arguments: Arguments<'instr> = self.call_args(line)?;
+
+        let generic: Generic = self.objects.get_generic(name).cloned().unwrap();
+        let bindings: Vec<(DataTypes, &str)> =
+            self.infer_bindings(name, &generic, &arguments, span, line)?;
+
+        generic.5.iter().for_each(|(type_param, trait_name)| {
+            let position: usize = generic
+                .1
+                .iter()
+                .position(|param| param == type_param)
+                .unwrap();
+
+            let binding: (DataTypes, &str) = bindings[position];
+
+            if binding.0 != DataTypes::Struct || !self.objects.implements(binding.1, trait_name) {
+                let found: String = if binding.1.is_empty() {
+                    binding.0.to_string()
+                } else {
+                    binding.1.to_string()
+                };
+
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::UnsatisfiedBound,
+                        message("E0024.title", &[]),
+                        message(
+                            "E0024.unsatisfied_bound",
+                            &[&found, &trait_name, &type_param, &name],
+                        ),
+                        line,
+                    )
+                    .spanned(span),
+                );
+            }
+        });
+
+        let types: Vec<String> = bindings
+            .iter()
+            .map(|binding| {
+                if binding.1.is_empty() {
+                    binding.0.to_string()
+                } else {
+                    binding.1.to_string()
+                }
+            })
+            .collect();
+
+        /*
+            The symbol of the instance is `name$T1$T2...`, the `$` can't be written in a name, so
+            it never clashes with a function of the user. The diagnostics show `name<T1, T2, ...>`.
+        */
+
+        let instance: &'instr str = self.objects.intern(format!("{}${}", name, types.join("$")));
+
+        if !self.instances.contains(&instance) {
+            let display: String = format!("{}<{}>", name, types.join(", "));
+
+            self.instantiate((instance, &display), &generic, &bindings, line);
+        }
+
+        let object: FoundObject = self.objects.get_object(instance, line)?;
+
+        self.build_call(instance, object, arguments, line)
+    }
+
+    fn infer_bindings(
+        &mut self,
+        name: &'instr str,
+        generic: &Generic<'instr>,
+        arguments: &Arguments<'instr>,
+        span: Span,
+        line: usize,
+    ) -> Result<Vec<(DataTypes, &'instr str)>, ThrushError> {
+        let args: &[Instruction<'instr>] = &arguments.0;
+
+        if generic.2.len() != args.len() {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message(
+                    "E0001.generic_argument_count",
+                    &[&name, &generic.2.len(), &args.len()],
+                ),
+                line,
+            )
+            .spanned(arguments.2));
+        }
+
+        let mut bindings: Vec<Option<(DataTypes, &'instr str)>> = vec![None; generic.1.len()];
+
+        for ((param, arg), arg_span) in generic.2.iter().zip(args).zip(&arguments.1) {
+            let Some(type_param) = param.2 else {
+                continue;
+            };
+
+            let position: usize = generic
+                .1
+                .iter()
+                .position(|generic_param| *generic_param == type_param)
+                .unwrap();
+
+            let found: (DataTypes, &str) = (arg.get_data_type(), arg.get_type_name());
+
+            match bindings[position] {
+                Some(bound) if bound != found => {
+                    return Err(ThrushError::Parse(
+                        ThrushErrorKind::TypeMismatch,
+                        message("E0010.title", &[]),
+                        message(
+                            "E0010.conflicting_inference",
+                            &[&type_param, &name, &bound.0, &found.0],
+                        ),
+                        line,
+                    )
+                    .spanned(*arg_span));
+                }
+                _ => bindings[position] = Some(found),
+            }
+        }
+
+        generic
+            .1
+            .iter()
+            .zip(bindings)
+            .map(|(type_param, binding)| match binding {
+                Some((DataTypes::Void, _)) => Err(ThrushError::Parse(
+                    ThrushErrorKind::TypeMismatch,
+                    message("E0010.title", &[]),
+                    message("E0010.void_inference", &[&type_param, &name]),
+                    line,
+                )
+                .spanned(span)),
+                Some(binding) => Ok(binding),
+                None => Err(ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.uninferable_type_parameter", &[&type_param, &name]),
+                    line,
+                )
+                .spanned(span)),
+            })
+            .collect()
+    }
+
+    fn instantiate(
+        &mut self,
+        instance: (&'instr str, &str),
+        generic: &Generic<'instr>,
+        bindings: &[(DataTypes, &'instr str)],
+        line: usize,
+    ) {
+        /*
+
+            MONOMORPHISATION
+
+            Each instantiation parses again the generic definition with the type parameters
+            bound to the inferred types, and emits it as a function named `name$T1$T2...`.
+            The instance is declared before of parse the body to allow recursive calls.
+
+            Instance (&str, &str)
+                      ^^^^  ^^^^
+                   Symbol - Name shown in the diagnostics, `name<T1, T2, ...>`
+
+        */
+
+        let (instance, display): (&'instr str, &str) = instance;
+
+        let resolve = |generic_type: &GenericType<'instr>| -> (DataTypes, &'instr str) {
+            match generic_type.2 {
+                Some(type_param) => {
+                    let position: usize = generic
+                        .1
+                        .iter()
+                        .position(|param| *param == type_param)
+                        .unwrap();
+
+                    bindings[position]
+                }
+                None => (generic_type.0, generic_type.1),
+            }
+        };
+
+        let params: Vec<(DataTypes, &str)> = generic.2.iter().map(resolve).collect();
+        let (return_kind, return_type_name): (DataTypes, &str) = resolve(&generic.3);
+
+        let params_kinds: Vec<DataTypes> = params.iter().map(|param| param.0).collect();
+
+        let signature: &str = self.objects.intern_signature(
+            DataTypes::Fn,
+            params,
+            return_kind,
+            return_type_name,
+            false,
+        );
+
+        self.objects.insert_new_global(
+            instance,
+            (return_kind, params_kinds, true, false, signature),
+        );
+
+        self.instances.push(instance);
+
+        let current: usize = self.current;
+        let in_function: bool = self.in_function;
+        let in_type_function: DataTypes = self.in_type_function;
+        let in_type_name_function: &str = self.in_type_name_function;
+        let in_var_type: DataTypes = self.in_var_type;
+        let in_enum_type: &str = mem::take(&mut self.in_enum_type);
+        let loops: Vec<(Option<&str>, usize)> = mem::take(&mut self.loops);
+        let borrowing_closures: Vec<&str> = mem::take(&mut self.borrowing_closures);
+        let moved_closures: Vec<&str> = mem::take(&mut self.moved_closures);
+        let receiver: Option<(&str, &str)> = self.receiver.take();
+        let errors: usize = self.errors.len();
+        let warnings: usize = self.warnings.len();
+
+        self.objects.begin_instantiation(
+            generic
+                .1
+                .iter()
+                .copied()
+                .zip(bindings.iter().copied())
+                .collect(),
+        );
+
+        self.current = generic.0;
+        self.instance = Some(instance);
+
+        let function: Result<Instruction<'instr>, ThrushError> = self.function(false, false, "");
+
+        self.objects.end_instantiation();
+
+        self.current = current;
+        self.in_function = in_function;
+        self.in_type_function = in_type_function;
+        self.in_type_name_function = in_type_name_function;
+        self.in_var_type = in_var_type;
+        self.in_enum_type = in_enum_type;
+        self.loops = loops;
+        self.borrowing_closures = borrowing_closures;
+        self.moved_closures = moved_closures;
+        self.receiver = receiver;
+        self.instance = None;
+
+        // The warnings of the generic body would be repeated on each instantiation.
+        self.warnings.truncate(warnings);
+
+        let mut failures: Vec<ThrushError> = self.errors.drain(errors..).collect();
+
+        match function {
+            Ok(function) => self.stmts.push(function),
+            Err(error) => failures.push(error),
+        }
+
+        self.errors.extend(
+            failures.into_iter().map(|error| {
+                ThrushError::Instantiation(Box::new(error), display.to_string(), line)
+            }),
+        );
+    }
+
+    fn named_type(&self) -> Option<(DataTypes, &'instr str)> {
+        if self.peek().kind != TokenKind::Identifier {
+            return None;
+        }
+
+        let name: &'instr str = self.peek().lexeme.as_ref().unwrap();
+
+        if let Some(binding) = self.objects.get_binding(name) {
+            return Some(binding);
+        }
+
+        self.objects
+            .get_struct(name)
+            .map(|_| (DataTypes::Struct, name))
+    }
+
+    fn missing_type(&self, name: &Token, value: &Instruction, value_span: Span) -> ThrushError {
+        let annotation: String = match value.get_data_type() {
+            DataTypes::Enum | DataTypes::Struct => value.get_type_name().to_string(),
+            DataTypes::Void | DataTypes::Fn | DataTypes::Closure => String::new(),
+            kind => kind.to_string(),
+        };
+
+        let error: ThrushError = ThrushError::Parse(
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.missing_variable_type", &[]),
+            name.line,
+        )
+        .spanned(name.span);
+
+        if annotation.is_empty() {
+            return error;
+        }
+
+        error
+            .label(value_span, message("E0001.value_type", &[&annotation]))
+            .fix(
+                Span::new(name.span.end, name.span.end),
+                format!(": {}", annotation),
+            )
+    }
+
+    fn suggest(&self, error: ThrushError, name: &Token, candidates: Vec<&str>) -> ThrushError {
+        match suggestions::closest(name.lexeme.as_ref().unwrap(), candidates) {
+            Some(suggestion) => error.suggestion(name.span, suggestion),
+            None => error.spanned(name.span),
+        }
+    }
+
+    fn value_names(&self) -> Vec<&'instr str> {
+        let mut names: Vec<&'instr str> = self.objects.names();
+        names.extend(suggestions::KEYWORDS);
+        names
+    }
+
+    fn type_names(&self) -> Vec<&'instr str> {
+        let mut names: Vec<&'instr str> = self.objects.type_names();
+        names.extend(suggestions::TYPES);
+        names
+    }
+
+    #[inline]
+    fn is_result_type(&self) -> bool {
+        self.peek()
+            .lexeme
+            .as_ref()
+            .is_some_and(|name| name == "Result")
+            && self.check_next(TokenKind::Less)
+    }
+
+    fn result_type(&mut self, line: usize) -> Result<(DataTypes, &'instr str), ThrushError> {
+        /*
+            `Result<T, E>` is a built-in enum with the variants `Ok(T)` and `Err(E)`,
+            it's declared the first time that is used.
+        */
+
+        self.only_advance()?;
+        self.only_advance()?;
+
+        let ok: DataTypes = self.result_payload(line)?;
+
+        self.consume(
+            TokenKind::Comma,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_result_comma", &[]),
+            line,
+        )?;
+
+        let err: DataTypes = self.result_payload(line)?;
+
+        self.consume(
+            TokenKind::Greater,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_result_greater", &[]),
+            line,
+        )?;
+
+        let name: &str = self.objects.intern(format!("Result<{}, {}>", ok, err));
+
+        if self.objects.get_enum(name).is_none() {
+            let payload = |kind: DataTypes| -> Vec<DataTypes> {
+                if kind == DataTypes::Void {
+                    return Vec::new();
+                }
+
+                vec![kind]
+            };
+
+            let variants: Vec<(&str, Vec<DataTypes>)> =
+                vec![("Ok", payload(ok)), ("Err", payload(err))];
+
+            self.objects.insert_new_enum(name, variants.clone());
+            self.stmts.push(Instruction::Enum { name, variants });
+        }
+
+        Ok((DataTypes::Enum, name))
+    }
+
+    fn result_payload(&mut self, line: usize) -> Result<DataTypes, ThrushError> {
+        match &self.peek().kind {
+            TokenKind::DataType(kind) => {
+                self.only_advance()?;
+
+                Ok(*kind)
+            }
+
+            _ => Err(ThrushError::Parse(
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.non_primitive_result", &[]),
+                line,
+            )
+            .spanned(self.peek().span)),
+        }
+    }
+
+    fn result_value(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        let variant: &Token = self.advance()?;
+        let variant_name: &str = variant.lexeme.as_ref().unwrap();
+
+        let type_name: &'instr str = self.in_enum_type;
+
+        if !self.objects.is_result(type_name) {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::TypeAnnotationsNeeded,
+                message("E0036.title", &[]),
+                message("E0036.uninferable_result", &[&variant_name]),
+                variant.line,
+            )
+            .spanned(variant.span));
+        }
+
+        let (tag, payload): (u32, Vec<DataTypes>) = match variant_name {
+            "Ok" => (0, self.objects.get_enum(type_name).unwrap()[0].1.clone()),
+            _ => (1, self.objects.get_enum(type_name).unwrap()[1].1.clone()),
+        };
+
+        self.consume(
+            TokenKind::LParen,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_call_lparen", &[&variant_name]),
+            variant.line,
+        )?;
+
+        let (args, spans, span): Arguments = self.call_args(variant.line)?;
+
+        if args.len() != payload.len() {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message(
+                        "E0001.variant_argument_count",
+                        &[&type_name, &variant_name, &payload.len(), &args.len()],
+                    ),
+                    variant.line,
+                )
+                .spanned(span),
+            );
+        }
+
+        args.iter()
+            .zip(spans.iter())
+            .zip(payload.iter())
+            .for_each(|((arg, span), kind)| {
+                if let Err(e) = type_checking::check_type(
+                    arg.get_data_type(),
+                    *kind,
+                    variant.line,
+                    message("E0010.title", &[]),
+                    message("E0010.expected_found", &[&kind, &arg.get_data_type()]),
+                ) {
+                    self.errors.push(e.spanned(*span));
+                }
+            });
+
+        Ok(Instruction::EnumValue {
+            name: type_name,
+            tag,
+            args,
+        })
+    }
+
+    fn postfix(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        let start: Span = self.peek().span;
+        let instr: Instruction<'instr> = self.primary()?;
+
+        if instr.get_data_type() != DataTypes::Enum {
+            return Ok(instr);
+        }
+
+        // The results and optionals are never booleans, so the ternary operator is not ambiguous with `?`.
+        if self.peek().kind == TokenKind::Question && self.objects.is_result(instr.get_type_name())
+        {
+            return self.propagate(instr);
+        }
+
+        if self.peek().kind == TokenKind::Bang && self.objects.is_optional(instr.get_type_name()) {
+            return self.unwrap(instr, start);
+        }
+
+        Ok(instr)
+    }
+
+    fn propagate(
+        &mut self,
+        instr: Instruction<'instr>,
+    ) -> Result<Instruction<'instr>, ThrushError> {
+        self.only_advance()?;
+
+        let line: usize = self.previous().line;
+        let source: &str = instr.get_type_name();
+
+        if !self.in_function || !self.objects.is_result(self.in_type_name_function) {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.question_without_result", &[]),
+                line,
+            )
+            .spanned(self.previous().span));
+        }
+
+        let variants: Vec<(&str, Vec<DataTypes>)> = self.objects.get_enum(source).unwrap().clone();
+
+        if self.objects.get_enum(self.in_type_name_function).unwrap()[1].1 != variants[1].1 {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::TypeMismatch,
+                    message("E0010.title", &[]),
+                    message(
+                        "E0010.propagated_error",
+                        &[&source, &self.in_type_name_function],
+                    ),
+                    line,
+                )
+                .spanned(self.previous().span),
+            );
+        }
+
+        Ok(Instruction::Propagate {
+            value: Box::new(instr),
+            kind: variants[0].1.first().copied().unwrap_or(DataTypes::Void),
+            return_type_name: self.in_type_name_function,
+            deallocators: self.objects.create_early_deallocators(),
+        })
+    }
+
+    fn unwrap(
+        &mut self,
+        instr: Instruction<'instr>,
+        start: Span,
+    ) -> Result<Instruction<'instr>, ThrushError> {
+        self.only_advance()?;
+
+        let line: usize = self.previous().line;
+        let kind: DataTypes = self.objects.get_enum(instr.get_type_name()).unwrap()[0].1[0];
+
+        Ok(Instruction::Unwrap {
+            value: Box::new(instr),
+            kind,
+            span: self.span_from(start),
+            line,
+        })
+    }
+
+    fn optional_suffix(
+        &mut self,
+        kind: DataTypes,
+        line: usize,
+    ) -> Result<(DataTypes, &'instr str), ThrushError> {
+        if !self.match_token(TokenKind::Question)? {
+            return Ok((kind, ""));
+        }
+
+        if kind == DataTypes::Void {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.optional_void", &[]),
+                    line,
+                )
+                .spanned(self.previous().span),
+            );
+
+            return Ok((kind, ""));
+        }
+
+        Ok((DataTypes::Enum, self.optional_type(kind)))
+    }
+
+    fn optional_type(&mut self, kind: DataTypes) -> &'instr str {
+        /*
+            `T?` is a built-in enum with the variants `Some(T)` and `Null`, it's declared the
+            first time that is used. The non-optional types can't hold `null`.
+        */
+
+        let name: &str = self.objects.intern(format!("{}?", kind));
+
+        if self.objects.get_enum(name).is_none() {
+            let variants: Vec<(&str, Vec<DataTypes>)> =
+                vec![("Some", vec![kind]), ("Null", Vec::new())];
+
+            self.objects.insert_new_enum(name, variants.clone());
+            self.stmts.push(Instruction::Enum { name, variants });
+        }
+
+        name
+    }
+
+    fn null_value(&mut self) -> Result<Instruction<'instr>, ThrushError> {
+        let line: usize = self.advance()?.line;
+
+        if !self.objects.is_optional(self.in_enum_type) {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::NullValue,
+                message("E0027.title", &[]),
+                message("E0027.null_in_non_optional", &[]),
+                line,
+            )
+            .spanned(self.previous().span));
+        }
+
+        Ok(Instruction::EnumValue {
+            name: self.in_enum_type,
+            tag: 1,
+            args: Vec::new(),
+        })
+    }
+
+    fn wrap_optional(
+        &mut self,
+        type_name: &'instr str,
+        value: Instruction<'instr>,
+        span: Span,
+        line: usize,
+    ) -> Instruction<'instr> {
+        if !self.objects.is_optional(type_name)
+            || (value.get_data_type() == DataTypes::Enum && value.get_type_name() == type_name)
+        {
+            return value;
+        }
+
+        let kind: DataTypes = self.objects.get_enum(type_name).unwrap()[0].1[0];
+
+        if let Err(error) = type_checking::check_type(
+            value.get_data_type(),
+            kind,
+            line,
+            message("E0010.title", &[]),
+            message(
+                "E0010.expected_found",
+                &[&type_name, &value.get_data_type()],
+            ),
+        ) {
+            self.errors.push(error.spanned(span));
+        }
+
+        Instruction::EnumValue {
+            name: type_name,
+            tag: 0,
+            args: vec![value],
+        }
+    }
+
+    fn null_check(
+        &mut self,
+        value: Instruction<'instr>,
+        op: &TokenKind,
+        line: usize,
+    ) -> Result<Instruction<'instr>, ThrushError> {
+        /*
+            `x == null` and `x != null` are lowered to a match over the tag of the optional.
+        */
+
+        self.only_advance()?;
+
+        if value.get_data_type() != DataTypes::Enum
+            || !self.objects.is_optional(value.get_type_name())
+        {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::NullValue,
+                message("E0027.title", &[]),
+                message("E0027.null_comparison", &[&value.get_data_type()]),
+                line,
+            ));
+        }
+
+        let is_null: bool = *op == TokenKind::EqEq;
+
+        Ok(Instruction::Match {
+            type_name: value.get_type_name(),
+            value: Box::new(value),
+            arms: vec![
+                (Some(1), Vec::new(), Instruction::Boolean(is_null)),
+                (None, Vec::new(), Instruction::Boolean(!is_null)),
+            ],
+            kind: DataTypes::Bool,
+        })
+    }
+
+    fn narrowed_optional(&self) -> bool {
+        /*
+            if x != null { ... }
+
+            The optional is narrowed to its value inside of the branch where it isn't null.
+        */
+
+        if self.current + 3 >= self.tokens.len() || self.peek().kind != TokenKind::Identifier {
+            return false;
+        }
+
+        matches!(
+            self.tokens[self.current + 1].kind,
+            TokenKind::BangEq | TokenKind::EqEq
+        ) && self.tokens[self.current + 2].kind == TokenKind::Null
+            && self.tokens[self.current + 3].kind == TokenKind::LBrace
+            && self
+                .objects
+                .get_local_type_name(self.peek().lexeme.as_ref().unwrap())
+                .is_some_and(|type_name| self.objects.is_optional(type_name))
+    }
+
+    fn if_narrowed(
+        &mut self,
+        keyword: Span,
+        line: usize,
+    ) -> Result<Instruction<'instr>, ThrushError> {
+        let name: &'instr str = self.peek().lexeme.as_ref().unwrap();
+        let span: Span = self.peek().span;
+        let value: Instruction<'instr> = self.primary()?;
+        let is_null: bool = self.advance()?.kind == TokenKind::EqEq;
+
+        self.only_advance()?;
+
+        let type_name: &str = value.get_type_name();
+        let bindings: Vec<Option<&str>> = vec![Some(name)];
+        let bindings_types: Vec<DataTypes> = self.objects.get_enum(type_name).unwrap()[0].1.clone();
+
+        let (block, otherwise, narrowed): (Instruction, Option<Instruction>, bool) = if is_null {
+            let block: Instruction<'instr> = self.block(&mut [])?;
+
+            if self.peek().kind == TokenKind::Else && self.check_next(TokenKind::LBrace) {
+                self.only_advance()?;
+
+                let otherwise: Instruction<'instr> =
+                    self.arm_body(&bindings, &bindings_types, span, line)?;
+
+                (block, Some(otherwise), true)
+            } else {
+                (block, self.else_branch()?, false)
+            }
+        } else {
+            let block: Instruction<'instr> =
+                self.arm_body(&bindings, &bindings_types, span, line)?;
+
+            (block, self.else_branch()?, true)
+        };
+
+        let kind: DataTypes = self.if_type(&block, otherwise.as_ref(), keyword, line);
+        let otherwise: Instruction<'instr> =
+            otherwise.unwrap_or(Instruction::Block { stmts: Vec::new() });
+
+        let (some, null): (Instruction, Instruction) = if is_null {
+            (otherwise, block)
+        } else {
+            (block, otherwise)
+        };
+
+        let some_bindings: Vec<Option<&str>> = if narrowed { bindings } else { vec![None] };
+
+        Ok(Instruction::Match {
+            value: Box::new(value),
+            type_name,
+            arms: vec![(Some(0), some_bindings, some), (None, Vec::new(), null)],
+            kind,
+        })
+    }
+
+    fn indirect_call(
+        &mut self,
+        name: &'instr str,
+        signature: &'instr str,
+        is_closure: bool,
+        line: usize,
+    ) -> Result<Instruction<'instr>, ThrushError> {
+        let (params, kind, type_name): Signature = self
+            .objects
+            .get_signature(signature)
+            .cloned()
+            .unwrap_or((Vec::new(), DataTypes::Void, ""));
+
+        let (args, spans, span): Arguments = self.call_args(line)?;
+
+        if params.len() != args.len() {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message(
+                        "E0001.pointer_argument_count",
+                        &[&name, &signature, &params.len(), &args.len()],
+                    ),
+                    line,
+                )
+                .spanned(span),
+            );
+        }
+
+        args.iter()
+            .zip(params.iter())
+            .enumerate()
+            .for_each(|(index, (arg, param))| {
+                if let Err(e) = type_checking::check_type(
+                    arg.get_data_type(),
+                    param.0,
+                    line,
+                    message("E0010.title", &[]),
+                    message(
+                        "E0010.pointer_argument_type",
+                        &[&param.0, &index, &arg.get_data_type()],
+                    ),
+                ) {
+                    self.errors.push(e.spanned(spans[index]));
+                } else if matches!(param.0, DataTypes::Fn | DataTypes::Closure)
+                    && param.1 != arg.get_type_name()
+                {
+                    self.errors.push(
+                        ThrushError::Parse(
+                            ThrushErrorKind::TypeMismatch,
+                            message("E0010.title", &[]),
+                            message(
+                                "E0010.pointer_argument_type",
+                                &[&param.1, &index, &arg.get_type_name()],
+                            ),
+                            line,
+                        )
+                        .spanned(spans[index]),
+                    );
+                }
+            });
+
+        Ok(Instruction::IndirectCall {
+            name,
+            args,
+            params: params.iter().map(|param| param.0).collect(),
+            kind,
+            type_name,
+            is_closure,
+        })
+    }
+
+    fn parse_string_formatted(
+        &mut self,
+        args: &[Instruction],
+        spans: &[Span],
+        span: Span,
+        line: usize,
+        scan_spaces: bool,
+    ) {
+        if args.is_empty() {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.println_without_arguments", &[]),
+                    line,
+                )
+                .spanned(span),
+            );
+        } else if let Instruction::String(str, _) = &args[0] {
+            let mut formats: usize = 0;
+
+            str.split_inclusive("{}").for_each(|substr| {
+                if substr.contains("{}") {
+                    formats += 1;
+                }
+            });
+
+            if formats != args.iter().skip(1).collect::<Vec<_>>().len() {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::SyntaxError,
+                        message("E0001.expected_format_title", &[]),
+                        message("E0001.format_argument_count", &[]),
+                        line,
+                    )
+                    .spanned(spans[0]),
+                );
+            }
+        }
+
+        if scan_spaces {
+            args.iter().zip(spans).for_each(|(arg, arg_span)| {
+                if let Instruction::String(str, _) = arg {
+                    if str.contains("\n") {
+                        self.errors.push(
+                            ThrushError::Parse(
+                                ThrushErrorKind::SyntaxError,
+                                message("E0001.title", &[]),
+                                message("E0001.print_newline", &[]),
+                                self.peek().line,
+                            )
+                            .spanned(*arg_span),
+                        );
+                    }
+                }
+            });
+        }
+    }
+
+
+    fn forward_declare(&mut self) {
+        let mut structs_positions: Vec<usize> = Vec::new();
+        let mut traits_positions: Vec<usize> = Vec::new();
+        let mut functions_positions: Vec<usize> = Vec::new();
+        let mut impls_positions: Vec<usize> = Vec::new();
+
+        let mut depth: usize = 0;
+        let mut in_methods: bool = false;
+
+        self.tokens
+            .iter()
+            .enumerate()
+            .for_each(|(pos, tok)| match tok.kind {
+                TokenKind::LBrace => depth += 1,
+                TokenKind::RBrace => {
+                    depth = depth.saturating_sub(1);
+
+                    if depth == 0 {
+                        in_methods = false;
+                    }
+                }
+                TokenKind::Struct if depth == 0 => structs_positions.push(pos),
+                TokenKind::Trait if depth == 0 => {
+                    traits_positions.push(pos);
+                    in_methods = true;
+                }
+                TokenKind::Impl if depth == 0 => {
+                    impls_positions.push(pos);
+                    in_methods = true;
+                }
+                TokenKind::Fn
+                    if !in_methods
+                        && self
+                            .tokens
+                            .get(pos + 1)
+                            .is_some_and(|next| next.kind == TokenKind::Identifier) =>
+                {
+                    functions_positions.push(pos);
+                }
+                _ => {}
+            });
+
+        structs_positions.iter().for_each(|index| {
+            if let Err(e) = self.predefine_struct(*index) {
+                self.errors.push(e);
+            }
+        });
+
+        traits_positions.iter().for_each(|index| {
+            if let Err(e) = self.predefine_trait(*index) {
+                self.errors.push(e);
+            }
+        });
+
+        functions_positions.iter().for_each(|index| {
+            let _ = self.predefine_function(*index);
+        });
+
+        impls_positions.iter().for_each(|index| {
+            if let Err(e) = self.predefine_impl(*index) {
+                self.errors.push(e);
+            }
+        });
+
+        self.current = 0;
+    }
+
+    fn predefine_function(&mut self, index: usize) -> Result<(), ThrushError> {
+        self.current = index;
+
+        let is_external: bool = if self.current < 4 {
+            false
+        } else {
+            self.tokens[self.current - 4].kind == TokenKind::External
+        };
+
+        if is_external {
+            while self.peek().kind != TokenKind::Fn {
+                self.only_advance()?;
+            }
+        }
+
+        let mut ignore_more_params: bool = false;
+
+        self.only_advance()?;
+
+        let name: &Token = self.consume(
+            TokenKind::Identifier,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.expected_function_name", &[]),
+            message("E0001.expected_external_function", &[]),
+            self.previous().line,
+        )?;
+
+        if self.peek().kind == TokenKind::Less {
+            return self.predefine_generic(index, name);
+        }
+
+        self.consume(
+            TokenKind::LParen,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_lparen", &[]),
+            name.line,
+        )?;
+
+        let mut params: Vec<DataTypes> = Vec::new();
+        let mut params_types: Vec<(DataTypes, &str)> = Vec::new();
+
+        while !self.match_token(TokenKind::RParen)? {
+            if self.match_token(TokenKind::Comma)? {
+                continue;
+            }
+
+            if self.match_token(TokenKind::Pass)? {
+                ignore_more_params = true;
+                continue;
+            }
+
+            if !self.match_token(TokenKind::Identifier)? {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::SyntaxError,
+                        message("E0001.title", &[]),
+                        message("E0001.expected_parameter_name", &[]),
+                        name.line,
+                    )
+                    .spanned(self.peek().span),
+                );
+            }
+
+            if !self.match_token(TokenKind::ColonColon)? {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::SyntaxError,
+                        message("E0001.title", &[]),
+                        message("E0001.expected_colon_colon", &[]),
+                        name.line,
+                    )
+                    .spanned(self.peek().span),
+                );
+            }
+
+            let param: (DataTypes, &str) = self.param_type(name.line)?;
+
+            params.push(param.0);
+            params_types.push(param);
+        }
+
+        if ignore_more_params && !is_external {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.ignored_parameters", &[]),
+                    name.line,
+                )
+                .spanned(name.span),
+            );
+        }
+
+        if self.peek().kind == TokenKind::Colon {
+            self.consume(
+                TokenKind::Colon,
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.expected_return_type", &[]),
+                name.line,
+            )?;
+        }
+
+        let (return_kind, return_type_name): (DataTypes, &str) = match &self.peek().kind {
+            TokenKind::DataType(kind) => {
+                self.only_advance()?;
+                (*kind, "")
+            }
+            TokenKind::Fn | TokenKind::Identifier => self.param_type(name.line)?,
+            _ => (DataTypes::Void, ""),
+        };
+
+        self.current = 0;
+
+        let signature: &str = self.objects.intern_signature(
+            DataTypes::Fn,
+            params_types,
+            return_kind,
+            return_type_name,
+            ignore_more_params,
+        );
+
+        self.objects.insert_new_global(
+            name.lexeme.as_ref().unwrap(),
+            (return_kind, params, true, ignore_more_params, signature),
+        );
+
+        Ok(())
+    }
+
+
+    fn predefine_struct(&mut self, index: usize) -> Result<(), ThrushError> {
+        self.current = index;
+
+        self.only_advance()?;
+
+        let name: &Token = self.consume(
+            TokenKind::Identifier,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.expected_struct_name", &[]),
+            message("E0001.expected_struct_declaration", &[]),
+            self.previous().line,
+        )?;
+
+        let struct_name: &str = name.lexeme.as_ref().unwrap();
+
+        if self.objects.get_struct(struct_name).is_some() {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::DuplicatedDefinition,
+                message("E0020.duplicated_struct_title", &[]),
+                message("E0020.duplicated_struct", &[&struct_name]),
+                name.line,
+            )
+            .spanned(name.span));
+        }
+
+        self.consume(
+            TokenKind::LBrace,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_lbrace", &[]),
+            name.line,
+        )?;
+
+        let mut fields: StructFields = Vec::new();
+
+        while !self.match_token(TokenKind::RBrace)? {
+            if self.match_token(TokenKind::Comma)? {
+                continue;
+            }
+
+            let field: &Token = self.consume(
+                TokenKind::Identifier,
+                ThrushErrorKind::SyntaxError,
+                message("E0001.expected_field_name", &[]),
+                message("E0001.expected_field_declaration", &[]),
+                name.line,
+            )?;
+
+            let field_name: &str = field.lexeme.as_ref().unwrap();
+
+            self.consume(
+                TokenKind::ColonColon,
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.expected_colon_colon", &[]),
+                field.line,
+            )?;
+
+            let (kind, type_name): (DataTypes, &str) = self.param_type(field.line)?;
+
+            if fields.iter().any(|field| field.0 == field_name) {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::DuplicatedDefinition,
+                        message("E0020.duplicated_field_title", &[]),
+                        message("E0020.duplicated_field", &[&struct_name, &field_name]),
+                        field.line,
+                    )
+                    .spanned(field.span),
+                );
+            }
+
+            if matches!(
+                kind,
+                DataTypes::String | DataTypes::Closure | DataTypes::Enum
+            ) {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::InvalidField,
+                        message("E0026.title", &[]),
+                        message("E0026.invalid_field", &[&struct_name, &field_name, &kind]),
+                        field.line,
+                    )
+                    .spanned(field.span),
+                );
+            }
+
+            fields.push((field_name, kind, type_name));
+        }
+
+        if fields.is_empty() {
+            self.errors.push(
+                ThrushError::Parse(
+                    ThrushErrorKind::SyntaxError,
+                    message("E0001.title", &[]),
+                    message("E0001.empty_struct", &[&struct_name]),
+                    name.line,
+                )
+                .spanned(name.span),
+            );
+        }
+
+        self.objects.insert_new_struct(struct_name, fields);
+
+        Ok(())
+    }
+
+    fn predefine_trait(&mut self, index: usize) -> Result<(), ThrushError> {
+        self.current = index;
+
+        self.only_advance()?;
+
+        let name: &Token = self.consume(
+            TokenKind::Identifier,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.expected_trait_name", &[]),
+            message("E0001.expected_trait_declaration", &[]),
+            self.previous().line,
+        )?;
+
+        let trait_name: &str = name.lexeme.as_ref().unwrap();
+
+        if self.objects.get_trait(trait_name).is_some() {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::DuplicatedDefinition,
+                message("E0020.duplicated_trait_title", &[]),
+                message("E0020.duplicated_trait", &[&trait_name]),
+                name.line,
+            )
+            .spanned(name.span));
+        }
+
+        self.consume(
+            TokenKind::LBrace,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_lbrace", &[]),
+            name.line,
+        )?;
+
+        let mut methods: Trait = Vec::new();
+
+        while !self.match_token(TokenKind::RBrace)? {
+            self.consume(
+                TokenKind::Fn,
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.expected_method_signature", &[]),
+                name.line,
+            )?;
+
+            let method: &Token = self.consume(
+                TokenKind::Identifier,
+                ThrushErrorKind::SyntaxError,
+                message("E0001.expected_method_name", &[]),
+                message("E0001.expected_method_signature", &[]),
+                name.line,
+            )?;
+
+            let method_name: &str = method.lexeme.as_ref().unwrap();
+
+            let (has_receiver, signature): (bool, Signature) =
+                self.method_signature(method.line)?;
+
+            self.consume(
+                TokenKind::SemiColon,
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.trait_method_body", &[]),
+                method.line,
+            )?;
+
+            if !has_receiver {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::SyntaxError,
+                        message("E0001.title", &[]),
+                        message("E0001.method_without_this", &[&trait_name, &method_name]),
+                        method.line,
+                    )
+                    .spanned(method.span),
+                );
+            }
+
+            if methods.iter().any(|method| method.0 == method_name) {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::DuplicatedDefinition,
+                        message("E0020.duplicated_method_title", &[]),
+                        message("E0020.duplicated_method", &[&trait_name, &method_name]),
+                        method.line,
+                    )
+                    .spanned(method.span),
+                );
+
+                continue;
+            }
+
+            methods.push((method_name, signature));
+        }
+
+        self.objects.insert_new_trait(trait_name, methods);
+
+        Ok(())
+    }
+
+    fn predefine_impl(&mut self, index: usize) -> Result<(), ThrushError> {
+        self.current = index;
+
+        self.only_advance()?;
+
+        let line: usize = self.previous().line;
+
+        let header_start: Span = self.peek().span;
+        let owner: (&str, &str) = self.impl_header(line)?;
+        let header: Span = self.span_from(header_start);
+        let is_inherent: bool = owner.1.is_empty();
+
+        let requirements: Trait = match self.objects.get_trait(owner.1) {
+            Some(requirements) => requirements.clone(),
+            None if is_inherent => Vec::new(),
+            None => {
+                return Err(ThrushError::Parse(
+                    ThrushErrorKind::UnknownTrait,
+                    message("E0016.title", &[]),
+                    message("E0016.undefined_trait", &[&owner.1]),
+                    line,
+                )
+                .spanned(header));
+            }
+        };
+
+        if self.objects.get_struct(owner.0).is_none() {
             return Err(ThrushError::Parse(
-                ThrushErrorKind::SyntaxError,
-                String::from("Syntax Error"),
-                String::from(
-                    "The object called is don't a function. Call is only allowed for functions.",
-                ),
+                ThrushErrorKind::UnknownType,
+                message("E0012.title", &[]),
+                message("E0012.undefined_type", &[&owner.0]),
                 line,
-            ));
+            )
+            .spanned(header));
         }
 
-        let mut args: Vec<Instruction<'instr>> = Vec::new();
-
-        while self.peek().kind != TokenKind::RParen {
-            if self.match_token(TokenKind::Comma)? {
-                continue;
-            }
-
-            args.push(self.expression()?);
+        // The inherent implementations can be splitted in several blocks.
+        if !self.objects.insert_new_implementation(owner.0, owner.1) && !is_inherent {
+            return Err(ThrushError::Parse(
+                ThrushErrorKind::DuplicatedDefinition,
+                message("E0020.duplicated_impl_title", &[]),
+                message("E0020.duplicated_impl", &[&owner.1, &owner.0]),
+                line,
+            )
+            .spanned(header));
         }
 
         self.consume(
-            TokenKind::RParen,
+            TokenKind::LBrace,
             ThrushErrorKind::SyntaxError,
-            String::from("Syntax Error"),
-            String::from("Expected ')'."),
+            message("E0001.title", &[]),
+            message("E0001.expected_lbrace", &[]),
             line,
         )?;
 
-        let mut index: usize = 0;
+        let mut implemented: Vec<&str> = Vec::with_capacity(requirements.len());
 
-        if object.5.len() != args.len() && !object.4 {
-            let args_types: String = if !args.is_empty() {
-                args.iter()
-                    .map(|param| param.get_data_type().to_string())
-                    .collect::<Vec<_>>()
-                    .join(", ")
-            } else {
-                DataTypes::Void.to_string()
-            };
+        while !self.match_token(TokenKind::RBrace)? {
+            self.consume(
+                TokenKind::Fn,
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.expected_method", &[]),
+                line,
+            )?;
 
-            self.errors.push(ThrushError::Parse(
+            let method: &Token = self.consume(
+                TokenKind::Identifier,
                 ThrushErrorKind::SyntaxError,
-                String::from("Syntax Error"),
-                format!(
-                    "Function called expected all arguments with types '{}' don't '{}'.",
-                    object
-                        .5
-                        .iter()
-                        .map(|param| param.to_string())
-                        .collect::<Vec<_>>()
-                        .join(", "),
-                    args_types,
-                ),
+                message("E0001.expected_method_name", &[]),
+                message("E0001.expected_method", &[]),
                 line,
-            ));
+            )?;
+
+            let method_name: &str = method.lexeme.as_ref().unwrap();
+
+            let (has_receiver, signature): (bool, Signature) =
+                self.method_signature(method.line)?;
+
+            self.skip_block(method.line)?;
+
+            match requirements
+                .iter()
+                .find(|required| required.0 == method_name)
+            {
+                None if is_inherent => {}
+                Some(required) if !has_receiver || required.1 != signature => {
+                    self.errors.push(
+                        ThrushError::Parse(
+                            ThrushErrorKind::SignatureMismatch,
+                            message("E0022.title", &[]),
+                            message("E0022.signature_mismatch", &[&method_name, &owner.1]),
+                            method.line,
+                        )
+                        .spanned(method.span),
+                    );
+                }
+                Some(_) => {}
+                None => {
+                    self.errors.push(
+                        ThrushError::Parse(
+                            ThrushErrorKind::UnknownMethod,
+                            message("E0015.title", &[]),
+                            message("E0015.not_trait_member", &[&method_name, &owner.1]),
+                            method.line,
+                        )
+                        .spanned(method.span),
+                    );
+                }
+            }
+
+            if implemented.contains(&method_name)
+                || (is_inherent && self.objects.has_member(owner.0, method_name))
+            {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::DuplicatedDefinition,
+                        message("E0020.duplicated_method_title", &[]),
+                        message("E0020.duplicated_impl_method", &[&method_name, &owner.0]),
+                        method.line,
+                    )
+                    .spanned(method.span),
+                );
+
+                continue;
+            }
+
+            implemented.push(method_name);
+
+            self.define_method(owner, method_name, has_receiver, signature);
         }
 
-        if !object.4 {
-            args.iter().for_each(|arg| {
-                let arg_kind: DataTypes = arg.get_data_type();
-    
-                if object.5.len() > index && object.5[index] != arg_kind  {
-                    self.errors.push(ThrushError::Parse(
-                        ThrushErrorKind::SyntaxError,
-                        String::from("Syntax Error"),
-                        format!(
-                            "Function called, expected '{}' argument type in position {} don't '{}' type.",
-                            object.5[index], index, arg_kind
-                        ),
+        requirements
+            .iter()
+            .filter(|required| !implemented.contains(&required.0))
+            .for_each(|required| {
+                self.errors.push(
+                    ThrushError::Parse(
+                        ThrushErrorKind::MissingMethod,
+                        message("E0023.title", &[]),
+                        message("E0023.missing_method", &[&required.0, &owner.1, &owner.0]),
                         line,
-                    ));
-                }
-    
-                index += 1;
+                    )
+                    .spanned(header),
+                );
             });
-        }
-        
-        Ok(Instruction::Call {
-            name,
-            args,
-            kind: object.0,
-        })
+
+        Ok(())
     }
 
-    fn parse_string_formatted(&mut self, args: &[Instruction], line: usize, scan_spaces: bool) {
-        if args.is_empty() {
-            self.errors.push(ThrushError::Parse(
+    fn method_signature(&mut self, line: usize) -> Result<(bool, Signature<'instr>), ThrushError> {
+        if self.peek().kind == TokenKind::Less {
+            return Err(ThrushError::Parse(
                 ThrushErrorKind::SyntaxError,
-                String::from("Syntax Error"),
-                String::from(
-                    "Expected at least 1 argument for 'println' call. Like 'println(\"Hi!\");'",
-                ),
+                message("E0001.title", &[]),
+                message("E0001.generic_method", &[]),
                 line,
-            ));
-        } else if let Instruction::String(str, _) = &args[0] {
-            let mut formats: usize = 0;
+            )
+            .spanned(self.peek().span));
+        }
 
-            str.split_inclusive("{}").for_each(|substr| {
-                if substr.contains("{}") {
-                    formats += 1;
-                }
-            });
+        self.consume(
+            TokenKind::LParen,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_lparen", &[]),
+            line,
+        )?;
 
-            if formats != args.iter().skip(1).collect::<Vec<_>>().len() {
-                self.errors.push(ThrushError::Parse(
-                    ThrushErrorKind::SyntaxError,
-                    String::from("Expected format"),
-                    String::from("Missing format for argument or an argument. Should be like this println(\"{}\", arguments.size() == formatters.size());"),
-                    line,
-                ));
+        let has_receiver: bool = self.match_token(TokenKind::This)?;
+
+        let mut params: Vec<(DataTypes, &str)> = Vec::new();
+
+        while !self.match_token(TokenKind::RParen)? {
+            if self.match_token(TokenKind::Comma)? {
+                continue;
             }
-        }
 
-        if scan_spaces {
-            args.iter().for_each(|arg| {
-                if let Instruction::String(str, _) = arg {
-                    if str.contains("\n") {
-                        self.errors.push(ThrushError::Parse(
-                            ThrushErrorKind::SyntaxError,
-                            String::from("Syntax Error"),
-                            String::from(
-                                "You can't print strings that contain newlines. Use 'println' instead.",
-                            ),
-                            self.peek().line,
-                        ));
-                    }
-                }
-            });
-        }
-    }
+            self.consume(
+                TokenKind::Identifier,
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.expected_parameter_name", &[]),
+                line,
+            )?;
 
+            self.consume(
+                TokenKind::ColonColon,
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.expected_colon_colon", &[]),
+                line,
+            )?;
 
-    fn forward_declare_functions(&mut self) {
-        let mut functions_positions: Vec<usize> = Vec::new();
-        let mut pos: usize = 0;
+            params.push(self.param_type(line)?);
+        }
 
-        self.tokens.iter().for_each(|tok| match tok.kind {
-            TokenKind::Fn => {
-                functions_positions.push(pos);
+        self.match_token(TokenKind::Colon)?;
 
-                pos += 1;
-            }
-            _ => {
-                pos += 1;
+        let (return_kind, return_type_name): (DataTypes, &str) = match &self.peek().kind {
+            TokenKind::DataType(_) | TokenKind::Fn | TokenKind::Identifier => {
+                self.param_type(line)?
             }
-        });
+            _ => (DataTypes::Void, ""),
+        };
 
-        functions_positions.iter().for_each(|index| {
-            let _ = self.predefine_function(*index);
-        });
+        Ok((has_receiver, (params, return_kind, return_type_name)))
     }
 
-    fn predefine_function(&mut self, index: usize) -> Result<(), ThrushError> {
-        self.current = index;
+    fn define_method(
+        &mut self,
+        owner: (&'instr str, &'instr str),
+        name: &'instr str,
+        has_receiver: bool,
+        signature: Signature<'instr>,
+    ) {
+        let mangled: &str = self.mangle_method(owner, name);
+        let params: Vec<DataTypes> = signature.0.iter().map(|param| param.0).collect();
+
+        let fn_signature: &str = self.objects.intern_signature(
+            DataTypes::Fn,
+            signature.0,
+            signature.1,
+            signature.2,
+            false,
+        );
 
-        let is_external: bool = if self.current < 4 {
-            false
-        } else {
-            self.tokens[self.current - 4].kind == TokenKind::External
-        };
+        self.objects
+            .insert_new_global(mangled, (signature.1, params, true, false, fn_signature));
 
-        if is_external {
-            while self.peek().kind != TokenKind::Fn {
-                self.only_advance()?;
-            }
+        if has_receiver {
+            self.objects.insert_new_method(owner, name, mangled);
+        } else if owner.1.is_empty() {
+            self.objects.insert_new_associated(owner.0, name, mangled);
         }
+    }
 
-        let mut ignore_more_params: bool = false;
-
+    fn predefine_generic(&mut self, index: usize, name: &'instr Token) -> Result<(), ThrushError> {
         self.only_advance()?;
 
-        let name: &Token = self.consume(
-            TokenKind::Identifier,
-            ThrushErrorKind::SyntaxError,
-            String::from("Expected function name"),
-            String::from("Expected fn < name >."),
-            self.previous().line,
-        )?;
-
-        self.consume(
-            TokenKind::LParen,
-            ThrushErrorKind::SyntaxError,
-            String::from("Syntax Error"),
-            String::from("Expected '('."),
-            name.line,
-        )?;
-
-        let mut params: Vec<DataTypes> = Vec::new();
+        let mut type_params: Vec<&'instr str> = Vec::new();
+        let mut bounds: Vec<(&'instr str, &'instr str)> = Vec::new();
 
-        while !self.match_token(TokenKind::RParen)? {
+        while !self.match_token(TokenKind::Greater)? {
             if self.match_token(TokenKind::Comma)? {
                 continue;
             }
 
-            if self.match_token(TokenKind::Pass)? {
-                ignore_more_params = true;
-                continue;
-            }
-
-            if !self.match_token(TokenKind::Identifier)? {
-                self.errors.push(ThrushError::Parse(
-                    ThrushErrorKind::SyntaxError,
-                    String::from("Syntax Error"),
-                    String::from("Expected argument name."),
-                    name.line,
-                ));
-            }
-
-            if !self.match_token(TokenKind::ColonColon)? {
-                self.errors.push(ThrushError::Parse(
-                    ThrushErrorKind::SyntaxError,
-                    String::from("Syntax Error"),
-                    String::from("Expected '::'."),
-                    name.line,
-                ));
-            }
+            let type_param: &Token = self.consume(
+                TokenKind::Identifier,
+                ThrushErrorKind::SyntaxError,
+                message("E0001.title", &[]),
+                message("E0001.expected_type_parameter", &[]),
+                name.line,
+            )?;
 
-            let kind: DataTypes = match &self.peek().kind {
-                TokenKind::DataType(kind) => {
-                    self.only_advance()?;
+            let type_param_name: &str = type_param.lexeme.as_ref().unwrap();
 
-                    *kind
-                }
-                _ => {
-                    self.errors.push(ThrushError::Parse(
+            if self.match_token(TokenKind::Colon)? {
+                loop {
+                    let bound: &Token = self.consume(
+                        TokenKind::Identifier,
                         ThrushErrorKind::SyntaxError,
-                        String::from("Syntax Error"),
-                        String::from("Expected argument type."),
+                        message("E0001.title", &[]),
+                        message("E0001.expected_bound", &[]),
                         name.line,
-                    ));
+                    )?;
+
+                    let trait_name: &str = bound.lexeme.as_ref().unwrap();
+
+                    if self.objects.get_trait(trait_name).is_none() {
+                        self.errors.push(
+                            ThrushError::Parse(
+                                ThrushErrorKind::UnknownTrait,
+                                message("E0016.title", &[]),
+                                message("E0016.undefined_trait", &[&trait_name]),
+                                name.line,
+                            )
+                            .spanned(bound.span),
+                        );
+                    }
+
+                    bounds.push((type_param_name, trait_name));
 
-                    DataTypes::Void
+                    if !self.match_token(TokenKind::Plus)? {
+                        break;
+                    }
                 }
-            };
+            }
 
-            params.push(kind)
+            type_params.push(type_param_name);
         }
 
-        if ignore_more_params && !is_external {
-            self.errors.push(ThrushError::Parse(
+        self.consume(
+            TokenKind::LParen,
+            ThrushErrorKind::SyntaxError,
+            message("E0001.title", &[]),
+            message("E0001.expected_lparen", &[]),
+            name.line,
+        )?;
+
+        let mut params: Vec<GenericType> = Vec::new();
+
+        while !self.match_token(TokenKind::RParen)? {
+            if self.match_token(TokenKind::Comma)? {
+                continue;
+            }
+
+            self.consume(
+                TokenKind::Identifier,
                 ThrushErrorKind::SyntaxError,
-                String::from("Syntax Error"),
-                String::from(
-                    "Pass statement \"(...)\" in functions is only allowed in external funtions.",
-                ),
+                message("E0001.title", &[]),
+                message("E0001.expected_parameter_name", &[]),
                 name.line,
-            ));
-        }
+            )?;
 
-        if self.peek().kind == TokenKind::Colon {
             self.consume(
-                TokenKind::Colon,
+                TokenKind::ColonColon,
                 ThrushErrorKind::SyntaxError,
-                String::from("Syntax Error"),
-                String::from("Missing return type. Expected ':' followed by return type."),
+                message("E0001.title", &[]),
+                message("E0001.expected_colon_colon", &[]),
                 name.line,
             )?;
+
+            params.push(self.generic_type(&type_params, name.line)?);
         }
 
-        let return_kind: Option<DataTypes> = match &self.peek().kind {
-            TokenKind::DataType(kind) => {
-                self.only_advance()?;
-                Some(*kind)
+        self.match_token(TokenKind::Colon)?;
+
+        let return_type: GenericType = match &self.peek().kind {
+            TokenKind::DataType(_) | TokenKind::Fn | TokenKind::Identifier => {
+                self.generic_type(&type_params, name.line)?
             }
-            _ => None,
+            _ => (DataTypes::Void, "", None),
         };
 
         self.current = 0;
 
-        if let Some(kind) = &return_kind {
-            self.objects.insert_new_global(name.lexeme.as_ref().unwrap(), (*kind, params, true, ignore_more_params));
+        self.objects.insert_new_generic(
+            name.lexeme.as_ref().unwrap(),
+            (index, type_params, params, return_type, name.line, bounds),
+        );
+
+        Ok(())
+    }
 
-            return Ok(());
+    fn generic_type(
+        &mut self,
+        type_params: &[&'instr str],
+        line: usize,
+    ) -> Result<GenericType<'instr>, ThrushError> {
+        if self.peek().kind == TokenKind::Identifier {
+            let lexeme: &str = self.peek().lexeme.as_ref().unwrap();
+
+            if let Some(type_param) = type_params.iter().find(|param| **param == lexeme) {
+                self.only_advance()?;
+
+                return Ok((DataTypes::Void, "", Some(*type_param)));
+            }
         }
 
-        self.objects.insert_new_global(name.lexeme.as_ref().unwrap(), (DataTypes::Void, params, true, ignore_more_params));
+        let (kind, type_name): (DataTypes, &str) = self.param_type(line)?;
 
-        Ok(())
+        Ok((kind, type_name, None))
     }
 
-
     fn match_token(&mut self, kind: TokenKind) -> Result<bool, ThrushError> {
         if self.end() {
             return Ok(false);
@@ -1540,12 +6026,12 @@ impl<'instr> Parser<'instr> {
         }
 
         Err(ThrushError::Parse(
-            ThrushErrorKind::SyntaxError,
-            String::from("Undeterminated Code"),
-            String::from("The code has ended abruptly and without any order, review the code and write the syntax correctly."),
-
+            ThrushErrorKind::UnexpectedEnd,
+            message("E0009.title", &[]),
+            message("E0009.unexpected_end", &[]),
             self.previous().line,
-        ))
+        )
+        .spanned(self.peek().span))
     }
 
     fn advance(&mut self) -> Result<&'instr Token, ThrushError> {
@@ -1555,18 +6041,64 @@ impl<'instr> Parser<'instr> {
         }
 
         Err(ThrushError::Parse(
-            ThrushErrorKind::SyntaxError,
-            String::from("Undeterminated Code"),
-            String::from("The code has ended abruptly and without any order, review the code and write the syntax correctly."),
-
+            ThrushErrorKind::UnexpectedEnd,
+            message("E0009.title", &[]),
+            message("E0009.unexpected_end", &[]),
             self.previous().line,
-        ))
+        )
+        .spanned(self.peek().span))
     }
 
-    fn sync(&mut self) {
+    fn sync(&mut self, position: usize) {
+        /*
+            Skips the rest of the statement that started at the position. The statement ends after
+            of the next `;`, before of the `}` that closes its block or before of the keyword of
+            the next statement. The blocks opened by the statement are skipped whole.
+        */
+
+        // A statement that fails without consume tokens would fail again.
+        if self.current == position && !self.end() {
+            self.current += 1;
+        }
+
+        let mut depth: usize =
+            self.tokens[position..self.current]
+                .iter()
+                .fold(0, |depth, token| match token.kind {
+                    TokenKind::LBrace => depth + 1,
+                    TokenKind::RBrace => depth.saturating_sub(1),
+                    _ => depth,
+                });
+
         while !self.end() {
             match self.peek().kind {
-                TokenKind::Var | TokenKind::Fn => return,
+                TokenKind::LBrace => depth += 1,
+                TokenKind::RBrace if depth == 0 => return,
+                TokenKind::RBrace => depth -= 1,
+                TokenKind::SemiColon if depth == 0 => {
+                    self.current += 1;
+                    return;
+                }
+                TokenKind::Var
+                | TokenKind::Fn
+                | TokenKind::Allow
+                | TokenKind::Return
+                | TokenKind::For
+                | TokenKind::Label
+                | TokenKind::Break
+                | TokenKind::Continue
+                | TokenKind::Println
+                | TokenKind::Print
+                | TokenKind::Public
+                | TokenKind::External
+                | TokenKind::Enum
+                | TokenKind::Struct
+                | TokenKind::Trait
+                | TokenKind::Impl
+                    if depth == 0 =>
+                {
+                    return
+                }
                 _ => {}
             }
 
@@ -1574,6 +6106,37 @@ impl<'instr> Parser<'instr> {
         }
     }
 
+    #[inline]
+    fn check_next(&self, kind: TokenKind) -> bool {
+        if self.end() {
+            return false;
+        }
+
+        self.tokens[self.current + 1].kind == kind
+    }
+
+    fn check_next_word(&self, word: &str) -> bool {
+        if self.end() {
+            return false;
+        }
+
+        let next: &Token = &self.tokens[self.current + 1];
+
+        next.kind == TokenKind::Identifier && next.lexeme.as_ref().is_some_and(|name| name == word)
+    }
+
+    fn match_word(&mut self, word: &str) -> Result<bool, ThrushError> {
+        if self.peek().kind == TokenKind::Identifier
+            && self.peek().lexeme.as_ref().is_some_and(|name| name == word)
+        {
+            self.only_advance()?;
+
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+
     #[inline]
     fn previous_is(&self, kind: TokenKind) -> bool {
         if self.current == 0 {
@@ -1593,6 +6156,11 @@ impl<'instr> Parser<'instr> {
         &self.tokens[self.current - 1]
     }
 
+    #[inline]
+    fn span_from(&self, start: Span) -> Span {
+        start.to(self.previous().span)
+    }
+
     #[inline]
     fn end(&self) -> bool {
         self.peek().kind == TokenKind::Eof
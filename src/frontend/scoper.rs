@@ -1,18 +1,16 @@
 use {
     super::super::{
-        backend::{compiler::options::ThrushFile, instruction::Instruction},
-        diagnostic::Diagnostic,
+        backend::instruction::Instruction,
         error::{ThrushError, ThrushErrorKind},
-        logging::LogType,
+        messages::message,
     },
-    std::process::exit,
+    std::mem,
 };
 
 #[derive(Debug)]
 pub struct ThrushScoper<'ctx> {
     blocks: Vec<ThrushBlock<'ctx>>,
     errors: Vec<ThrushError>,
-    diagnostic: Diagnostic,
 }
 
 #[derive(Debug)]
@@ -21,11 +19,10 @@ struct ThrushBlock<'ctx> {
 }
 
 impl<'ctx> ThrushScoper<'ctx> {
-    pub fn new(file: &ThrushFile) -> Self {
+    pub fn new() -> Self {
         Self {
             blocks: Vec::new(),
             errors: Vec::new(),
-            diagnostic: Diagnostic::new(file),
         }
     }
 
@@ -33,9 +30,9 @@ impl<'ctx> ThrushScoper<'ctx> {
         self.blocks.push(ThrushBlock { stmts });
     }
 
-    pub fn analyze(&mut self) {
+    pub fn analyze(&mut self) -> Vec<ThrushError> {
         if self.blocks.is_empty() {
-            return;
+            return Vec::new();
         }
 
         for depth in (0..=self.blocks.len() - 1).rev() {
@@ -49,13 +46,7 @@ impl<'ctx> ThrushScoper<'ctx> {
             }
         }
 
-        if !self.errors.is_empty() {
-            self.errors.iter().for_each(|error| {
-                self.diagnostic.report(error, LogType::ERROR);
-            });
-
-            exit(1);
-        }
+        mem::take(&mut self.errors)
     }
 
     fn analyze_instruction(
@@ -96,8 +87,8 @@ impl<'ctx> ThrushScoper<'ctx> {
                 if !self.is_at_current_scope(name, None, depth) {
                     return Err(ThrushError::Scope(
                         ThrushErrorKind::VariableNotDefined,
-                        String::from("Undefined Variable"),
-                        format!("The variable `{}` not found in this scope.", name),
+                        message("E0007.title", &[]),
+                        message("E0007.undefined_variable", &[&name]),
                         *line,
                     ));
                 }
@@ -107,11 +98,8 @@ impl<'ctx> ThrushScoper<'ctx> {
                 {
                     return Err(ThrushError::Scope(
                         ThrushErrorKind::UnreachableVariable,
-                        String::from("Unreacheable Variable"),
-                        format!(
-                            "The variable `{}` is unreacheable to the current scope.",
-                            name
-                        ),
+                        message("E0005.title", &[]),
+                        message("E0005.unreachable_variable", &[&name]),
                         *line,
                     ));
                 }
@@ -167,25 +155,23 @@ impl<'ctx> ThrushScoper<'ctx> {
             return false;
         }
 
-        if block.is_some() {
-            if let Instruction::Block { stmts, .. } = block.as_ref().unwrap() {
-                return stmts.iter().rev().any(|instr| match instr {
-                    Instruction::Var { name: n, line, .. } if *n == name => {
-                        if *line > refvar_line {
-                            return false;
-                        }
-
-                        true
-                    }
-                    Instruction::Block { .. } => {
-                        self.is_reacheable_at_current_scope(name, refvar_line, Some(instr), depth)
-                    }
-                    _ => {
-                        depth += 1;
-                        self.is_reacheable_at_current_scope(name, refvar_line, None, depth)
+        if let Some(Instruction::Block { stmts, .. }) = block {
+            return stmts.iter().rev().any(|instr| match instr {
+                Instruction::Var { name: n, line, .. } if *n == name => {
+                    if *line > refvar_line {
+                        return false;
                     }
-                });
-            }
+
+                    true
+                }
+                Instruction::Block { .. } => {
+                    self.is_reacheable_at_current_scope(name, refvar_line, Some(instr), depth)
+                }
+                _ => {
+                    depth += 1;
+                    self.is_reacheable_at_current_scope(name, refvar_line, None, depth)
+                }
+            });
         }
 
         if self.blocks.len() == 1 || depth == 0 {
@@ -239,17 +225,15 @@ impl<'ctx> ThrushScoper<'ctx> {
             return false;
         }
 
-        if block.is_some() {
-            if let Instruction::Block { stmts, .. } = block.as_ref().unwrap() {
-                return stmts.iter().rev().any(|instr| match instr {
-                    Instruction::Var { name: n, .. } if *n == name => true,
-                    Instruction::Block { .. } => self.is_at_current_scope(name, Some(instr), depth),
-                    _ => {
-                        depth += 1;
-                        self.is_at_current_scope(name, None, depth)
-                    }
-                });
-            }
+        if let Some(Instruction::Block { stmts, .. }) = block {
+            return stmts.iter().rev().any(|instr| match instr {
+                Instruction::Var { name: n, .. } if *n == name => true,
+                Instruction::Block { .. } => self.is_at_current_scope(name, Some(instr), depth),
+                _ => {
+                    depth += 1;
+                    self.is_at_current_scope(name, None, depth)
+                }
+            });
         }
 
         if self.blocks.len() == 1 || depth == 0 {
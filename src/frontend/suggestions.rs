@@ -0,0 +1,67 @@
+/*
+
+    SUGGESTIONS
+
+    When a name isn't found, the closest name of the same namespace is suggested: the
+    variables, the functions and the keywords for the values, the built-in types, the enums
+    and the structs for the types.
+
+    Only the names at a small distance of edition are suggested, a third of the length of the
+    name (at least one), so the suggestions are typos and not unrelated names.
+
+*/
+
+pub const KEYWORDS: [&str; 27] = [
+    "var", "let", "fn", "if", "elif", "else", "for", "while", "true", "false", "or", "and",
+    "const", "struct", "trait", "impl", "enum", "match", "return", "break", "continue", "super",
+    "this", "extends", "public", "builtin", "null",
+];
+
+pub const TYPES: [&str; 11] = [
+    "i8", "i16", "i32", "i64", "f32", "f64", "bool", "string", "char", "void", "Result",
+];
+
+pub fn closest<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let limit: usize = name.chars().count().max(3) / 3;
+
+    candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name && is_identifier(candidate))
+        .map(|candidate| (distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= limit)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+fn distance(from: &str, to: &str) -> usize {
+    // Levenshtein distance, a swap of two characters counts as a single edition.
+    let from: Vec<char> = from.to_lowercase().chars().collect();
+    let to: Vec<char> = to.to_lowercase().chars().collect();
+
+    let mut rows: Vec<Vec<usize>> = vec![vec![0; to.len() + 1]; from.len() + 1];
+
+    (0..=from.len()).for_each(|i| rows[i][0] = i);
+    (0..=to.len()).for_each(|j| rows[0][j] = j);
+
+    for i in 1..=from.len() {
+        for j in 1..=to.len() {
+            let cost: usize = usize::from(from[i - 1] != to[j - 1]);
+
+            rows[i][j] = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && from[i - 1] == to[j - 2] && from[i - 2] == to[j - 1] {
+                rows[i][j] = rows[i][j].min(rows[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    rows[from.len()][to.len()]
+}
+
+#[inline]
+fn is_identifier(name: &str) -> bool {
+    // The mangled names of the methods and the lambdas can't be written in the source code.
+    !name.is_empty() && name.chars().all(|ch| ch.is_alphanumeric() || ch == '_')
+}
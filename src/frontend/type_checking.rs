@@ -1,6 +1,10 @@
 use super::super::{
     error::{ThrushError, ThrushErrorKind},
-    frontend::lexer::{DataTypes, TokenKind},
+    frontend::{
+        lexer::{DataTypes, TokenKind},
+        objects::Signature,
+    },
+    messages::message,
 };
 
 /*
@@ -24,12 +28,9 @@ fn check_binary_instr_add(a: &DataTypes, b: &DataTypes, line: usize) -> Result<(
         (DataTypes::F32 | DataTypes::F64, DataTypes::F32 | DataTypes::F64) => Ok(()),
 
         _ => Err(ThrushError::Parse(
-            ThrushErrorKind::SyntaxError,
-            String::from("Type Checking"),
-            format!(
-                "Arithmatic addition ({} + {}) is impossible. Check your operands and types.",
-                a, b
-            ),
+            ThrushErrorKind::TypeChecking,
+            message("E0011.title", &[]),
+            message("E0011.addition", &[&a, &b]),
             line,
         )),
     }
@@ -48,12 +49,9 @@ fn check_binary_instr_sub(a: &DataTypes, b: &DataTypes, line: usize) -> Result<(
     }
 
     Err(ThrushError::Parse(
-        ThrushErrorKind::SyntaxError,
-        String::from("Type Checking"),
-        format!(
-            "Arithmatic subtraction ({} - {}) is impossible. Check your operands and types.",
-            a, b
-        ),
+        ThrushErrorKind::TypeChecking,
+        message("E0011.title", &[]),
+        message("E0011.subtraction", &[&a, &b]),
         line,
     ))
 }
@@ -71,12 +69,9 @@ fn check_binary_instr_div(a: &DataTypes, b: &DataTypes, line: usize) -> Result<(
     }
 
     Err(ThrushError::Parse(
-        ThrushErrorKind::SyntaxError,
-        String::from("Type Checking"),
-        format!(
-            "Arithmatic division ({} / {}) is impossible. Check your operands and types.",
-            a, b
-        ),
+        ThrushErrorKind::TypeChecking,
+        message("E0011.title", &[]),
+        message("E0011.division", &[&a, &b]),
         line,
     ))
 }
@@ -94,12 +89,9 @@ fn check_binary_instr_mul(a: &DataTypes, b: &DataTypes, line: usize) -> Result<(
     }
 
     Err(ThrushError::Parse(
-        ThrushErrorKind::SyntaxError,
-        String::from("Type Checking"),
-        format!(
-            "Arithmatic multiplication ({} * {}) is impossible. Check your operands and types.",
-            a, b
-        ),
+        ThrushErrorKind::TypeChecking,
+        message("E0011.title", &[]),
+        message("E0011.multiplication", &[&a, &b]),
         line,
     ))
 }
@@ -123,12 +115,9 @@ fn check_binary_instr_eqeq(a: &DataTypes, b: &DataTypes, line: usize) -> Result<
     }
 
     Err(ThrushError::Parse(
-        ThrushErrorKind::SyntaxError,
-        String::from("Type Checking"),
-        format!(
-            "Logical operation ({} == {}) is impossible. Check your operands and types.",
-            a, b
-        ),
+        ThrushErrorKind::TypeChecking,
+        message("E0011.title", &[]),
+        message("E0011.equality", &[&a, &b]),
         line,
     ))
 }
@@ -152,12 +141,9 @@ fn check_binary_instr_bangeq(a: &DataTypes, b: &DataTypes, line: usize) -> Resul
     }
 
     Err(ThrushError::Parse(
-        ThrushErrorKind::SyntaxError,
-        String::from("Type Checking"),
-        format!(
-            "Logical operation ({} != {}) is impossible. Check your operands and types.",
-            a, b
-        ),
+        ThrushErrorKind::TypeChecking,
+        message("E0011.title", &[]),
+        message("E0011.inequality", &[&a, &b]),
         line,
     ))
 }
@@ -179,12 +165,9 @@ fn check_binary_instr_greater(
     }
 
     Err(ThrushError::Parse(
-        ThrushErrorKind::SyntaxError,
-        String::from("Type Checking"),
-        format!(
-            "Logical operation ({} > {}) is impossible. Check your operands and types.",
-            a, b
-        ),
+        ThrushErrorKind::TypeChecking,
+        message("E0011.title", &[]),
+        message("E0011.greater", &[&a, &b]),
         line,
     ))
 }
@@ -206,12 +189,9 @@ fn check_binary_instr_greatereq(
     }
 
     Err(ThrushError::Parse(
-        ThrushErrorKind::SyntaxError,
-        String::from("Type Checking"),
-        format!(
-            "Logical operation ({} >= {}) is impossible. Check your operands and types.",
-            a, b
-        ),
+        ThrushErrorKind::TypeChecking,
+        message("E0011.title", &[]),
+        message("E0011.greater_equal", &[&a, &b]),
         line,
     ))
 }
@@ -229,12 +209,9 @@ fn check_binary_instr_less(a: &DataTypes, b: &DataTypes, line: usize) -> Result<
     }
 
     Err(ThrushError::Parse(
-        ThrushErrorKind::SyntaxError,
-        String::from("Type Checking"),
-        format!(
-            "Logical operation ({} < {}) is impossible. Check your operands and types.",
-            a, b
-        ),
+        ThrushErrorKind::TypeChecking,
+        message("E0011.title", &[]),
+        message("E0011.less", &[&a, &b]),
         line,
     ))
 }
@@ -252,12 +229,9 @@ fn check_binary_instr_lesseq(a: &DataTypes, b: &DataTypes, line: usize) -> Resul
     }
 
     Err(ThrushError::Parse(
-        ThrushErrorKind::SyntaxError,
-        String::from("Type Checking"),
-        format!(
-            "Logical operation ({} <= {}) is impossible. Check your operands and types.",
-            a, b
-        ),
+        ThrushErrorKind::TypeChecking,
+        message("E0011.title", &[]),
+        message("E0011.less_equal", &[&a, &b]),
         line,
     ))
 }
@@ -269,12 +243,9 @@ fn check_binary_instr_and(a: &DataTypes, b: &DataTypes, line: usize) -> Result<(
     }
 
     Err(ThrushError::Parse(
-        ThrushErrorKind::SyntaxError,
-        String::from("Type Checking"),
-        format!(
-            "Logical operation ({} && {}) is impossible. Check your operands and types.",
-            a, b
-        ),
+        ThrushErrorKind::TypeChecking,
+        message("E0011.title", &[]),
+        message("E0011.and", &[&a, &b]),
         line,
     ))
 }
@@ -294,12 +265,9 @@ fn check_binary_instr_or(a: &DataTypes, b: &DataTypes, line: usize) -> Result<()
     }
 
     Err(ThrushError::Parse(
-        ThrushErrorKind::SyntaxError,
-        String::from("Type Checking"),
-        format!(
-            "Logical operation ({} || {}) is impossible. Check your operands and types.",
-            a, b
-        ),
+        ThrushErrorKind::TypeChecking,
+        message("E0011.title", &[]),
+        message("E0011.or", &[&a, &b]),
         line,
     ))
 }
@@ -330,6 +298,94 @@ pub fn check_binary_instr(
 
 /*
 
+OVERLOADED BINARY INSTRUCTION
+
+--------------------
+A OPERATOR B ---> A.method(B)
+B OPERATOR A ---> A.rmethod(B)
+--------------------
+*/
+
+pub fn check_overloaded_binary_instr(
+    op: &TokenKind,
+    method: &str,
+    struct_name: &str,
+    signature: Option<&Signature>,
+    other: (DataTypes, &str),
+    line: usize,
+) -> Result<DataTypes, ThrushError> {
+    let Some((params, return_kind, return_type_name)) = signature else {
+        return Err(ThrushError::Parse(
+            ThrushErrorKind::TypeChecking,
+            message("E0011.title", &[]),
+            message(
+                "E0011.not_overloaded",
+                &[&op, &struct_name, &method, &other.0, &struct_name],
+            ),
+            line,
+        ));
+    };
+
+    if params.len() != 1 {
+        return Err(ThrushError::Parse(
+            ThrushErrorKind::TypeChecking,
+            message("E0011.title", &[]),
+            message("E0011.operator_parameter_count", &[&struct_name, &method]),
+            line,
+        ));
+    }
+
+    check_type(
+        other.0,
+        params[0].0,
+        line,
+        message("E0011.title", &[]),
+        message(
+            "E0011.operand_type",
+            &[&op, &struct_name, &params[0].0, &other.0],
+        ),
+    )?;
+
+    if matches!(other.0, DataTypes::Fn | DataTypes::Struct) && params[0].1 != other.1 {
+        return Err(ThrushError::Parse(
+            ThrushErrorKind::TypeChecking,
+            message("E0011.title", &[]),
+            message(
+                "E0011.operand_type",
+                &[&op, &struct_name, &params[0].1, &other.1],
+            ),
+            line,
+        ));
+    }
+
+    if op.is_comparison() && *return_kind != DataTypes::Bool {
+        return Err(ThrushError::Parse(
+            ThrushErrorKind::TypeChecking,
+            message("E0011.title", &[]),
+            message("E0011.comparison_return_type", &[&struct_name, &method]),
+            line,
+        ));
+    }
+
+    if *return_kind == DataTypes::Void
+        || (*return_kind == DataTypes::Struct && *return_type_name != struct_name)
+    {
+        return Err(ThrushError::Parse(
+            ThrushErrorKind::TypeChecking,
+            message("E0011.title", &[]),
+            message(
+                "E0011.operator_return_type",
+                &[&struct_name, &method, &struct_name],
+            ),
+            line,
+        ));
+    }
+
+    Ok(*return_kind)
+}
+
+/*
+
 UNARY INSTRUCTION
 
 --------------------
@@ -350,12 +406,9 @@ fn check_unary_instr_negate(a: &DataTypes, line: usize) -> Result<(), ThrushErro
     }
 
     Err(ThrushError::Parse(
-        ThrushErrorKind::SyntaxError,
-        String::from("Type Checking"),
-        format!(
-            "Negative operation (-{}) is impossible. Check your operand and type.",
-            a
-        ),
+        ThrushErrorKind::TypeChecking,
+        message("E0011.title", &[]),
+        message("E0011.negation", &[&a]),
         line,
     ))
 }
@@ -373,12 +426,9 @@ fn check_unary_instr_minusminus(a: &DataTypes, line: usize) -> Result<(), Thrush
     }
 
     Err(ThrushError::Parse(
-        ThrushErrorKind::SyntaxError,
-        String::from("Type Checking"),
-        format!(
-            "Substractive operation (--{} or {}--) is impossible. Check your operand and type.",
-            a, a
-        ),
+        ThrushErrorKind::TypeChecking,
+        message("E0011.title", &[]),
+        message("E0011.decrement", &[&a, &a]),
         line,
     ))
 }
@@ -396,12 +446,9 @@ fn check_unary_instr_plusplus(a: &DataTypes, line: usize) -> Result<(), ThrushEr
     }
 
     Err(ThrushError::Parse(
-        ThrushErrorKind::SyntaxError,
-        String::from("Type Checking"),
-        format!(
-            "Additive operation (++{} or {}++) is impossible. Check your operand and type.",
-            a, a
-        ),
+        ThrushErrorKind::TypeChecking,
+        message("E0011.title", &[]),
+        message("E0011.increment", &[&a, &a]),
         line,
     ))
 }
@@ -413,12 +460,9 @@ fn check_unary_instr_bang(a: &DataTypes, line: usize) -> Result<(), ThrushError>
     }
 
     Err(ThrushError::Parse(
-        ThrushErrorKind::SyntaxError,
-        String::from("Type Checking"),
-        format!(
-            "Logical operation (!{}) is impossible. Check your operand and type.",
-            a
-        ),
+        ThrushErrorKind::TypeChecking,
+        message("E0011.title", &[]),
+        message("E0011.not", &[&a]),
         line,
     ))
 }
@@ -434,6 +478,29 @@ pub fn check_unary_instr(op: &TokenKind, a: &DataTypes, line: usize) -> Result<(
     }
 }
 
+#[inline]
+pub fn check_branches_type(
+    a: DataTypes,
+    b: DataTypes,
+    line: usize,
+) -> Result<DataTypes, ThrushError> {
+    match (a, b) {
+        (
+            DataTypes::I8 | DataTypes::I16 | DataTypes::I32 | DataTypes::I64,
+            DataTypes::I8 | DataTypes::I16 | DataTypes::I32 | DataTypes::I64,
+        ) => Ok(a.max_integer_datatype(b)),
+        (DataTypes::F64, DataTypes::F32 | DataTypes::F64) => Ok(DataTypes::F64),
+        (DataTypes::F32, DataTypes::F64) => Ok(DataTypes::F64),
+        (a, b) if a == b => Ok(a),
+        _ => Err(ThrushError::Parse(
+            ThrushErrorKind::TypeMismatch,
+            message("E0010.title", &[]),
+            message("E0010.branch_types", &[&a, &b]),
+            line,
+        )),
+    }
+}
+
 #[inline]
 pub fn check_type(
     kind: DataTypes,
@@ -446,6 +513,10 @@ pub fn check_type(
         (DataTypes::Char, DataTypes::Char) => Ok(()),
         (DataTypes::String, DataTypes::String) => Ok(()),
         (DataTypes::Bool, DataTypes::Bool) => Ok(()),
+        (DataTypes::Enum, DataTypes::Enum) => Ok(()),
+        (DataTypes::Fn, DataTypes::Fn) => Ok(()),
+        (DataTypes::Closure, DataTypes::Closure) => Ok(()),
+        (DataTypes::Struct, DataTypes::Struct) => Ok(()),
         (DataTypes::I8, DataTypes::I8 | DataTypes::I16 | DataTypes::I32 | DataTypes::I64) => Ok(()),
         (DataTypes::I16, DataTypes::I16 | DataTypes::I32 | DataTypes::I64) => Ok(()),
         (DataTypes::I32, DataTypes::I32 | DataTypes::I64) => Ok(()),
@@ -453,7 +524,7 @@ pub fn check_type(
         (DataTypes::F32, DataTypes::F32 | DataTypes::F64) => Ok(()),
         (DataTypes::F64, DataTypes::F64) => Ok(()),
         _ => Err(ThrushError::Parse(
-            ThrushErrorKind::SyntaxError,
+            ThrushErrorKind::TypeMismatch,
             title,
             desc,
             line,
@@ -0,0 +1,11 @@
+pub mod backend;
+pub mod constants;
+pub mod diagnostic;
+pub mod error;
+pub mod explain;
+pub mod fix;
+pub mod frontend;
+pub mod logging;
+pub mod messages;
+pub mod session;
+pub mod toolchain;
@@ -0,0 +1,437 @@
+pub const MESSAGES: &[(&str, &str)] = &[
+    ("E0001.title", "Syntax Error"),
+    ("E0001.argument_count", "Function called expected all arguments with types '{0}' don't '{1}'."),
+    ("E0001.argument_type", "Function called, expected '{0}' argument type in position {1} don't '{2}' type."),
+    ("E0001.associated_function_not_called", "Expected '('. The associated function `{0}::{1}` can only be called."),
+    ("E0001.binding_count", "The pattern `{0}::{1}` expected {2} bindings but found {3}."),
+    ("E0001.call_non_function", "The object called is don't a function. Call is only allowed for functions."),
+    ("E0001.char_length", "A char data type only can contain one character."),
+    ("E0001.empty_enum", "The enum `{0}` should have at least one variant."),
+    ("E0001.empty_struct", "The struct `{0}` should have at least one field."),
+    ("E0001.expected_allow_lparen", "Expected '(' after of `@allow`."),
+    ("E0001.expected_associated_function", "Expected `{0}::< function >(...)`."),
+    ("E0001.expected_binding", "Expected a binding name or `_`."),
+    ("E0001.expected_bound", "Expected trait name. Expected `fn name<T: Trait + ...>(...)`."),
+    ("E0001.expected_call_lparen", "Expected '('. Expected `{0}(...)`."),
+    ("E0001.expected_capture_name", "Expected the name of a captured variable."),
+    ("E0001.expected_closure_type_rbracket", "Expected ']'. Closure types are written `fn[](type, ...) -> type`."),
+    ("E0001.expected_colon", "Expected ':'."),
+    ("E0001.expected_colon_after_label", "Expected ':' after the loop label. Like `'outer: for ...`."),
+    ("E0001.expected_colon_colon", "Expected '::'."),
+    ("E0001.expected_else_body", "Expected 'block ({ ... })' for the else body."),
+    ("E0001.expected_enum_declaration", "Expected enum < name > { ... }."),
+    ("E0001.expected_enum_name", "Expected enum name"),
+    ("E0001.expected_enum_variant", "Expected `{0}::< variant >`."),
+    ("E0001.expected_external_function", "Expected fn < name >."),
+    ("E0001.expected_external_name", "Expected String literal for @external(\"NAME\")."),
+    ("E0001.expected_fat_arrow", "Expected '=>'."),
+    ("E0001.expected_field_declaration", "Expected a field like `name :: type`."),
+    ("E0001.expected_field_name", "Expected field name"),
+    ("E0001.expected_for_in_variable", "Expected `for (name) in ...`."),
+    ("E0001.expected_format_title", "Expected format"),
+    ("E0001.expected_function_body", "Expected 'block ({ ... })' for the function body."),
+    ("E0001.expected_function_name", "Expected function name"),
+    ("E0001.expected_function_name_help", "Expected a name to the function."),
+    ("E0001.expected_function_type_lparen", "Expected '(' for the function type. Expected `fn(type, ...) -> type`."),
+    ("E0001.expected_if_body", "Expected 'block ({ ... })' for the if body."),
+    ("E0001.expected_if_let_eq", "Expected '=' after the pattern of the if let."),
+    ("E0001.expected_impl_declaration", "Expected impl < struct > { ... } or impl < trait > for < struct > { ... }."),
+    ("E0001.expected_impl_name", "Expected trait or struct name"),
+    ("E0001.expected_index", "Expected unsigned number for the build an indexe."),
+    ("E0001.expected_lambda_body", "Expected 'block ({ ... })' for the lambda body."),
+    ("E0001.expected_lambda_lparen", "Expected '(' for the lambda parameters."),
+    ("E0001.expected_lbrace", "Expected '{'."),
+    ("E0001.expected_lbracket", "Expected '['."),
+    ("E0001.expected_lint_name", "Expected the name of a lint."),
+    ("E0001.expected_loop_body", "Expected 'block ({ ... })' for the loop body."),
+    ("E0001.expected_lparen", "Expected '('."),
+    ("E0001.expected_method", "Expected a method `fn name(this, ...) { ... }`."),
+    ("E0001.expected_method_name", "Expected method name"),
+    ("E0001.expected_method_signature", "Expected a method signature like `fn name(this, ...) : type;`."),
+    ("E0001.expected_parameter_name", "Expected argument name."),
+    ("E0001.expected_parameter_type", "Expected argument type."),
+    ("E0001.expected_pattern", "Expected pattern"),
+    ("E0001.expected_pattern_help", "Expected a pattern like `{0}::< variant >` or `_`."),
+    ("E0001.expected_payload_type", "Expected a payload type for the variant `{0}::{1}`."),
+    ("E0001.expected_property", "Expected a field or method name after '.'."),
+    ("E0001.expected_rbracket", "Expected ']'."),
+    ("E0001.expected_result_comma", "Expected ','. Expected `Result<T, E>`."),
+    ("E0001.expected_result_greater", "Expected '>'. Expected `Result<T, E>`."),
+    ("E0001.expected_return_type", "Missing return type. Expected ':' followed by return type."),
+    ("E0001.expected_rparen", "Expected ')'."),
+    ("E0001.expected_semicolon", "Expected ';'."),
+    ("E0001.expected_struct_declaration", "Expected struct < name > { ... }."),
+    ("E0001.expected_struct_literal", "Expected `{0} { field: value, ... }`."),
+    ("E0001.expected_struct_name", "Expected struct name"),
+    ("E0001.expected_ternary_colon", "Expected ':' for the ternary. Like `cond ? a : b`."),
+    ("E0001.expected_trait_declaration", "Expected trait < name > { ... }."),
+    ("E0001.expected_trait_impl_declaration", "Expected impl < trait > for < struct > { ... }."),
+    ("E0001.expected_trait_name", "Expected trait name"),
+    ("E0001.expected_type_parameter", "Expected type parameter name. Expected `fn name<T, ...>(...)`."),
+    ("E0001.expected_variable_colon", "Expected variable type indicator"),
+    ("E0001.expected_variable_colon_help", "Expected `var name --> : <-- type = value;`."),
+    ("E0001.expected_variable_declaration", "Expected var (name)."),
+    ("E0001.expected_variable_eq", "Expected '=' for the variable definition."),
+    ("E0001.expected_variable_name", "Expected variable name"),
+    ("E0001.expected_variable_type", "Expected type for the variable."),
+    ("E0001.expected_variant_declaration", "Expected a variant like `Name` or `Name(type, ...)`."),
+    ("E0001.expected_variant_name", "Expected variant name"),
+    ("E0001.format_argument_count", "Missing format for argument or an argument. Should be like this println(\"{}\", arguments.size() == formatters.size());"),
+    ("E0001.function_type_returns_struct", "The function types can't return structs."),
+    ("E0001.generic_argument_count", "The generic function `{0}` expected {1} arguments but found {2}."),
+    ("E0001.generic_function_not_called", "Expected '('. The generic function `{0}` can only be called."),
+    ("E0001.generic_method", "The methods can't have type parameters."),
+    ("E0001.generic_without_body", "Generic functions must have a body."),
+    ("E0001.group_without_binary", "Group the expressions \"(...)\" is only allowed if contain binary expressions."),
+    ("E0001.if_value_without_else", "The if that returns a value should have an else branch. Add an `else { ... }`."),
+    ("E0001.ignored_parameters", "Pass statement \"(...)\" in functions is only allowed in external funtions."),
+    ("E0001.invalid_float", "Float's values should be only contain one dot."),
+    ("E0001.invalid_float_title", "Float Violated Syntax"),
+    ("E0001.iterated_expression", "Only variables and literals can be iterated. Store the value in a variable before of the loop."),
+    ("E0001.jump_outside_loop", "The `{0}` statement is only allowed inside of a loop."),
+    ("E0001.label_without_loop", "The label `'{0}` is only allowed before a loop. Like `'{1}: for ...`."),
+    ("E0001.lambda_returns_struct", "The lambdas can't return structs."),
+    ("E0001.match_non_enum", "Match is only allowed for enums, found '{0}'."),
+    ("E0001.method_without_receiver", "The method `{0}` needs a receiver. Call it as `< value >.{1}(...)`."),
+    ("E0001.method_without_this", "The method `{0}::{1}` should take `this` as the first parameter."),
+    ("E0001.misplaced_this", "`this` is only allowed as the first parameter of a method."),
+    ("E0001.missing_variable_colon", "Expected type for the variable. You forget the `:`."),
+    ("E0001.missing_variable_type", "Expected type for the variable. Write it as `var name: type = value;`."),
+    ("E0001.nested_enum", "The enums must go in the global scope. Rewrite it in the global scope."),
+    ("E0001.nested_function", "The functions must go in the global scope. Rewrite it in the global scope."),
+    ("E0001.nested_impl", "The implementations must go in the global scope. Rewrite it in the global scope."),
+    ("E0001.nested_struct", "The structs must go in the global scope. Rewrite it in the global scope."),
+    ("E0001.nested_trait", "The traits must go in the global scope. Rewrite it in the global scope."),
+    ("E0001.no_properties", "The object `{0}` of type '{1}' don't have properties."),
+    ("E0001.non_primitive_result", "The types of `Result<T, E>` should be primitive types."),
+    ("E0001.optional_void", "The type 'void' can't be optional."),
+    ("E0001.payload_count", "The variant `{0}::{1}` expected {2} values but found {3}."),
+    ("E0001.pointer_argument_count", "Function pointer `{0}` of type '{1}' expected {2} arguments don't {3}."),
+    ("E0001.print_newline", "You can't print strings that contain newlines. Use 'println' instead."),
+    ("E0001.println_without_arguments", "Expected at least 1 argument for 'println' call. Like 'println(\"Hi!\");'"),
+    ("E0001.question_without_result", "The operator `?` can only be used in functions that return a `Result<T, E>`."),
+    ("E0001.return_outside_function", "Return statement outside of function. Invoke this keyword in scope of function."),
+    ("E0001.return_value_in_void_function", "Missing function type indicator with type '{0}', you should add a correct function type indicator with type '{1}'."),
+    ("E0001.return_without_value", "Missing return statement with correctly type '{0}', you should rewrite for return with type '{1}'."),
+    ("E0001.statement_not_allowed", "Statement `{0}` don't allowed."),
+    ("E0001.struct_return_as_value", "The function `{0}` returns a struct and can't be used as a value."),
+    ("E0001.trait_method_body", "Expected ';'. The methods of a trait don't have a body."),
+    ("E0001.undefined_variable_type", "Variable type is undefined. Did you forget to specify the variable type to undefined variable?"),
+    ("E0001.uninferable_type_parameter", "Can't infer the type parameter `{0}` of `{1}`. Use it in the type of a parameter."),
+    ("E0001.unpassable_parameter_type", "The type `{0}` can't be passed as argument. Use `match` before of the call."),
+    ("E0001.unterminated_char", "Unterminated char. Did you forget to close the cjar with a '\''?"),
+    ("E0001.unterminated_comment", "Unterminated multiline comment. Did you forget to close the string with a '*/'?"),
+    ("E0001.unterminated_string", "Unterminated string. Did you forget to close the string with a '\"'?"),
+    ("E0001.value_type", "this value has the type '{0}'"),
+    ("E0001.variant_argument_count", "The variant `{0}::{1}` expected {2} arguments but found {3}."),
+    ("E0001.zero_range_step", "The step of a range can't be zero, the loop would never end."),
+    ("E0001.explanation", "The code doesn't follow the grammar of the language.\nThe parser expected another token in this position, the help of the error says which one.\nA missing `;`, `)` or `}` is the most common cause."),
+    ("E0001.example", "var x: i32 = 10   // Error: expected ';'.\nvar x: i32 = 10;  // Ok."),
+    ("E0002.title", "Unreacheable Number."),
+    ("E0002.size_out_of_bounds", "The size is out of bounds of an isize (0 to n)."),
+    ("E0002.explanation", "The number can't be represented by any numeric type.\nThe integers should fit in 64 bits and the floats in a `f64`."),
+    ("E0002.example", "var x: i64 = 99999999999999999999;  // Error: the number is too big.\nvar x: i64 = 9999999999;            // Ok."),
+    ("E0003.title", "Unreacheable Number"),
+    ("E0003.float_too_big", "The number is too big for an float."),
+    ("E0003.invalid_number", "Did you provide a valid number with the correct format and not out of bounds?"),
+    ("E0003.number_too_big", "The number is too big for an integer or float."),
+    ("E0003.explanation", "The number is malformed or too big for its type.\nThe literal couldn't be parsed as an integer or as a float."),
+    ("E0003.example", "var x: f64 = 1.2.3;  // Error: a float has only one '.'.\nvar x: f64 = 1.23;   // Ok."),
+    ("E0004.title", "Unknown character."),
+    ("E0004.unknown_character", "Did you provide a valid character?"),
+    ("E0004.explanation", "The code contains a character that isn't part of the language.\nOnly the characters of the operators, the delimiters, the identifiers and the literals are valid outside of the strings and the comments."),
+    ("E0004.example", "var x: i32 = 10 $ 2;  // Error: '$' isn't an operator.\nvar x: i32 = 10 * 2;  // Ok."),
+    ("E0005.title", "Unreacheable Variable"),
+    ("E0005.unreachable_variable", "The variable `{0}` is unreacheable to the current scope."),
+    ("E0005.explanation", "The variable is used before of its declaration.\nThe variables only exist from the statement that declares them until the end of their block."),
+    ("E0005.example", "println(\"{}\", x);  // Error: `x` isn't declared yet.\nvar x: i32 = 10;\n\nvar x: i32 = 10;\nprintln(\"{}\", x);  // Ok."),
+    ("E0006.title", "Object don't Found"),
+    ("E0006.undefined_object", "Object with name \"{0}\" is don't in this scope or the global scope."),
+    ("E0006.explanation", "The name doesn't refer to any variable, function or type of the scope.\nCheck the spelling of the name, and declare it before of the use."),
+    ("E0006.example", "var y: i32 = z + 1;  // Error: `z` isn't defined.\n\nvar z: i32 = 1;\nvar y: i32 = z + 1;  // Ok."),
+    ("E0007.title", "Undefined Variable"),
+    ("E0007.undefined_variable", "The variable `{0}` not found in this scope."),
+    ("E0007.explanation", "The variable isn't defined in this scope.\nA variable declared inside of a block can't be used outside of it."),
+    ("E0007.example", "{\n    var x: i32 = 1;\n}\nprintln(\"{}\", x);  // Error: `x` only exists inside of the block."),
+    ("E0008.title", "Uninitialized Variable"),
+    ("E0008.break_before_assignment", "the break at line {0} exits of the loop before of assign it"),
+    ("E0008.declared_here", "declared here without value"),
+    ("E0008.declared_without_value", "declared without value at line {0}"),
+    ("E0008.list_and", "{0}, and {1}"),
+    ("E0008.loop_may_not_run", "the loop at line {0} may run zero times"),
+    ("E0008.missing_else", "the if at line {0} has no else branch"),
+    ("E0008.unassigned_arm", "the arm {0} of the match at line {1} doesn't assign it"),
+    ("E0008.unassigned_else", "the else branch of the if at line {0} doesn't assign it"),
+    ("E0008.unassigned_if", "the if branch at line {0} doesn't assign it"),
+    ("E0008.uninitialized_variable", "The variable `{0}` is used before of be assigned on all the paths. It was {1}. Assign it a value on every path before of the use."),
+    ("E0008.explanation", "The variable is used before of be assigned on all the paths.\nA variable declared without value should be assigned on every path of the control flow that reaches its use."),
+    ("E0008.example", "var x: i32;\nif cond { x = 1; }\nprintln(\"{}\", x);  // Error: `x` isn't assigned when `cond` is false.\n\nvar x: i32;\nif cond { x = 1; } else { x = 2; }\nprintln(\"{}\", x);  // Ok."),
+    ("E0009.title", "Undeterminated Code"),
+    ("E0009.unexpected_end", "The code has ended abruptly and without any order, review the code and write the syntax correctly."),
+    ("E0009.explanation", "The code ends in the middle of a statement.\nSome delimiter or the rest of the statement is missing at the end of the file."),
+    ("E0009.example", "fn main() {\n    println(\"Hello\");\n// Error: the '}' of the function is missing."),
+    ("E0010.title", "Type Mismatch"),
+    ("E0010.arm_types", "The arms of the match should have the same type. Expected '{0}' but found '{1}'."),
+    ("E0010.branch_types", "The branches should have the same type. Expected '{0}' but found '{1}'."),
+    ("E0010.conflicting_inference", "The type parameter `{0}` of `{1}` is inferred as '{2}' and as '{3}'. Pass arguments of the same type."),
+    ("E0010.expected_due_to_type", "expected due to this type"),
+    ("E0010.expected_found", "Type mismatch. Expected '{0}' but found '{1}'."),
+    ("E0010.non_bool_if_condition", "The condition of the if should be of type 'bool' but found '{0}'."),
+    ("E0010.non_bool_ternary_condition", "The condition of the ternary should be of type 'bool' but found '{0}'."),
+    ("E0010.non_enum_if_let", "Expected an enum value for the if let but found '{0}'."),
+    ("E0010.non_integer_range", "The bounds and the step of a range should be integers, but found '{0}'."),
+    ("E0010.not_iterable", "The type '{0}' is not iterable. Use a range 'start..end' or a string."),
+    ("E0010.pattern_enum", "Expected a pattern of the enum `{0}` but found `{1}`."),
+    ("E0010.pointer_argument_type", "Function pointer called, expected '{0}' argument type in position {1} don't '{2}' type."),
+    ("E0010.propagated_error", "The error of `{0}` can't be propagated from a function that returns `{1}`. The error types should be the same."),
+    ("E0010.variable_type", "`{0}` has the type '{1}'"),
+    ("E0010.void_inference", "The type parameter `{0}` of `{1}` can't be inferred as 'void'."),
+    ("E0010.explanation", "The type of the value isn't the expected one.\nThe value assigned to a variable, passed as argument or returned should have the declared type. The integers are widened to the bigger integer types."),
+    ("E0010.example", "var x: i32 = \"hello\";  // Error: expected 'i32' but found 'str'.\nvar x: i32 = 10;       // Ok."),
+    ("E0011.title", "Type Checking"),
+    ("E0011.addition", "Arithmatic addition ({0} + {1}) is impossible. Check your operands and types."),
+    ("E0011.and", "Logical operation ({0} && {1}) is impossible. Check your operands and types."),
+    ("E0011.comparison_return_type", "The operator method `{0}::{1}` should return 'bool'."),
+    ("E0011.decrement", "Substractive operation (--{0} or {1}--) is impossible. Check your operand and type."),
+    ("E0011.division", "Arithmatic division ({0} / {1}) is impossible. Check your operands and types."),
+    ("E0011.equality", "Logical operation ({0} == {1}) is impossible. Check your operands and types."),
+    ("E0011.greater", "Logical operation ({0} > {1}) is impossible. Check your operands and types."),
+    ("E0011.greater_equal", "Logical operation ({0} >= {1}) is impossible. Check your operands and types."),
+    ("E0011.increment", "Additive operation (++{0} or {1}++) is impossible. Check your operand and type."),
+    ("E0011.inequality", "Logical operation ({0} != {1}) is impossible. Check your operands and types."),
+    ("E0011.less", "Logical operation ({0} < {1}) is impossible. Check your operands and types."),
+    ("E0011.less_equal", "Logical operation ({0} <= {1}) is impossible. Check your operands and types."),
+    ("E0011.multiplication", "Arithmatic multiplication ({0} * {1}) is impossible. Check your operands and types."),
+    ("E0011.negation", "Negative operation (-{0}) is impossible. Check your operand and type."),
+    ("E0011.not", "Logical operation (!{0}) is impossible. Check your operand and type."),
+    ("E0011.not_overloaded", "The operator '{0}' is not overloaded for `{1}`. Declare the method `fn {2}(this, other :: {3}) { ... }` in `impl {4} { ... }`."),
+    ("E0011.operand_type", "The operator '{0}' of `{1}` expected '{2}' operand but found '{3}'."),
+    ("E0011.operator_parameter_count", "The operator method `{0}::{1}` should take exactly one parameter besides `this`."),
+    ("E0011.operator_return_type", "The operator method `{0}::{1}` should return `{2}` or a primitive type."),
+    ("E0011.or", "Logical operation ({0} || {1}) is impossible. Check your operands and types."),
+    ("E0011.struct_left_operand", "The operator '{0}' of `{1}` needs the struct as the left operand."),
+    ("E0011.subtraction", "Arithmatic subtraction ({0} - {1}) is impossible. Check your operands and types."),
+    ("E0011.explanation", "The operator can't be applied to the types of its operands.\nThe arithmetic operators need numbers, the logical operators need booleans."),
+    ("E0011.example", "var x: i32 = 1 + true;  // Error: '+' can't add an integer and a boolean.\nvar x: i32 = 1 + 2;     // Ok."),
+    ("E0012.title", "Unknown Type"),
+    ("E0012.undefined_type", "The type `{0}` is not defined. Declare it before of the use."),
+    ("E0012.explanation", "The type isn't defined.\nThe types are the built-in types, or an enum, a struct or a type alias declared before of the use."),
+    ("E0012.example", "var s: Shape = Shape::Circle(1.0);  // Error: `Shape` isn't declared.\n\nenum Shape { Circle(f64) }\nvar s: Shape = Shape::Circle(1.0);  // Ok."),
+    ("E0013.title", "Unknown Field"),
+    ("E0013.unknown_field", "The struct `{0}` don't have a field named `{1}`."),
+    ("E0013.explanation", "The struct doesn't have a field with this name."),
+    ("E0013.example", "struct Point { x :: i32, y :: i32 }\n\nvar p: Point = Point { x: 1, y: 2 };\nprintln(\"{}\", p.z);  // Error: `Point` has no field `z`."),
+    ("E0014.title", "Unknown Function"),
+    ("E0014.unknown_associated_function", "The struct `{0}` don't have an associated function named `{1}`."),
+    ("E0014.explanation", "The function doesn't exist.\nThe functions should be declared in the same file or in an imported one."),
+    ("E0014.example", "sum(1, 2);  // Error: `sum` isn't declared.\n\nfn sum(a: i32, b: i32) : i32 { return a + b; }\nsum(1, 2);  // Ok."),
+    ("E0015.title", "Unknown Method"),
+    ("E0015.not_trait_member", "The method `{0}` is not a member of the trait `{1}`."),
+    ("E0015.unknown_method", "The struct `{0}` don't have a method named `{1}`."),
+    ("E0015.explanation", "The type doesn't have a method with this name.\nThe methods come from the `impl` blocks of the struct and from the traits that it implements."),
+    ("E0015.example", "struct Point { x :: i32, y :: i32 }\n\np.length();  // Error: `Point` has no method `length`."),
+    ("E0016.title", "Unknown Trait"),
+    ("E0016.undefined_trait", "The trait `{0}` is not defined. Declare it before of the use."),
+    ("E0016.explanation", "The trait isn't declared."),
+    ("E0016.example", "impl Display for Point { ... }  // Error: `Display` isn't declared.\n\ntrait Display { fn show(this) : void; }\nimpl Display for Point { ... }  // Ok."),
+    ("E0017.title", "Unknown Variant"),
+    ("E0017.unknown_variant", "The enum `{0}` don't have a variant named `{1}`."),
+    ("E0017.explanation", "The enum doesn't have a variant with this name."),
+    ("E0017.example", "enum Shape { Circle(f64), Square(f64) }\n\nvar s: Shape = Shape::Triangle(1.0);  // Error: `Shape` has no variant `Triangle`."),
+    ("E0018.title", "Unknown Capture"),
+    ("E0018.unknown_capture", "The captured variable `{0}` is not defined in the enclosing scope."),
+    ("E0018.explanation", "The closure captures a variable that doesn't exist.\nOnly the variables of the enclosing function can be captured."),
+    ("E0018.example", "var f: fn[](i32) -> i32 = fn[y](x: i32) : i32 { return x + y; };  // Error: `y` isn't declared."),
+    ("E0019.title", "Unknown Lint"),
+    ("E0019.unknown_lint", "The lint `{0}` doesn't exist. The lints are `unused_variable`, `unused_parameter`, `unused_function`, `dead_store`, `shadowing`, `unreachable_code` and `constant_condition`, or the groups `unused` and `warnings`."),
+    ("E0019.explanation", "The lint given to `@allow` doesn't exist.\nThe lints are unused_variable, unused_parameter, unused_function, dead_store, shadowing, unreachable_code and constant_condition, and the groups unused and warnings."),
+    ("E0019.example", "@allow(unused_vars)      // Error: the lint is `unused_variable`.\n@allow(unused_variable)  // Ok."),
+    ("E0020.duplicated_entrypoint", "The language not support two entrypoints, remove one."),
+    ("E0020.duplicated_entrypoint_title", "Duplicated EntryPoint"),
+    ("E0020.duplicated_enum", "The enum `{0}` is already defined, rename one of them."),
+    ("E0020.duplicated_enum_title", "Duplicated Enum"),
+    ("E0020.duplicated_field", "The field `{0}::{1}` is already defined, rename one of them."),
+    ("E0020.duplicated_field_title", "Duplicated Field"),
+    ("E0020.duplicated_impl", "The trait `{0}` is already implemented for `{1}`."),
+    ("E0020.duplicated_impl_method", "The method `{0}` is already implemented for `{1}`."),
+    ("E0020.duplicated_impl_title", "Duplicated Implementation"),
+    ("E0020.duplicated_method", "The method `{0}::{1}` is already defined, rename one of them."),
+    ("E0020.duplicated_method_title", "Duplicated Method"),
+    ("E0020.duplicated_struct", "The struct `{0}` is already defined, rename one of them."),
+    ("E0020.duplicated_struct_title", "Duplicated Struct"),
+    ("E0020.duplicated_trait", "The trait `{0}` is already defined, rename one of them."),
+    ("E0020.duplicated_trait_title", "Duplicated Trait"),
+    ("E0020.duplicated_variant", "The variant `{0}::{1}` is already defined, rename one of them."),
+    ("E0020.duplicated_variant_title", "Duplicated Variant"),
+    ("E0020.field_initialized_twice", "The field `{0}` is initialized more than once."),
+    ("E0020.explanation", "The name is defined twice.\nTwo enums, structs, traits, fields, variants or methods of the same scope can't have the same name, and a trait can be implemented once per type."),
+    ("E0020.example", "struct Point { x :: i32, x :: i32 }  // Error: the field `x` is duplicated.\nstruct Point { x :: i32, y :: i32 }  // Ok."),
+    ("E0021.title", "Ambiguous Method"),
+    ("E0021.ambiguous_method", "The method `{0}` is defined by several traits implemented for `{1}`. Rename one of them."),
+    ("E0021.explanation", "The method exists in several traits implemented by the type.\nCall it through the trait to choose one of them."),
+    ("E0021.example", "impl Show for Point { fn name(this) : str { ... } }\nimpl Debug for Point { fn name(this) : str { ... } }\n\np.name();  // Error: `name` comes from `Show` and `Debug`."),
+    ("E0022.title", "Signature Mismatch"),
+    ("E0022.signature_mismatch", "The method `{0}` should have the signature declared in the trait `{1}`."),
+    ("E0022.explanation", "The method of the implementation doesn't have the signature of the trait.\nThe parameters and the return type should be the ones declared by the trait."),
+    ("E0022.example", "trait Area { fn area(this) : f64; }\n\nimpl Area for Square {\n    fn area(this) : i32 { ... }  // Error: the trait returns 'f64'.\n}"),
+    ("E0023.title", "Missing Method"),
+    ("E0023.missing_method", "The method `{0}` of the trait `{1}` is not implemented for `{2}`."),
+    ("E0023.explanation", "The implementation of the trait doesn't define all its methods."),
+    ("E0023.example", "trait Shape { fn area(this) : f64; fn perimeter(this) : f64; }\n\nimpl Shape for Square {\n    fn area(this) : f64 { ... }\n}  // Error: `perimeter` is missing."),
+    ("E0024.title", "Unsatisfied Bound"),
+    ("E0024.unsatisfied_bound", "The type '{0}' don't implement the trait `{1}` required by the type parameter `{2}` of `{3}`."),
+    ("E0024.explanation", "The type given to a generic function doesn't implement the traits of its bound."),
+    ("E0024.example", "fn show<T: Display>(value: T) { ... }\n\nshow(10);  // Error: 'i32' doesn't implement `Display`."),
+    ("E0025.title", "Missing Field"),
+    ("E0025.missing_field", "The field `{0}` of the struct `{1}` is not initialized."),
+    ("E0025.explanation", "The struct literal doesn't initialize all the fields of the struct."),
+    ("E0025.example", "struct Point { x :: i32, y :: i32 }\n\nvar p: Point = Point { x: 1 };        // Error: `y` is missing.\nvar p: Point = Point { x: 1, y: 2 };  // Ok."),
+    ("E0026.title", "Invalid Field"),
+    ("E0026.invalid_field", "The field `{0}::{1}` can't be of type '{2}'."),
+    ("E0026.explanation", "The field can't be used in this way.\nThe fields are only read or assigned through a value of the struct."),
+    ("E0026.example", "struct Point { x :: i32, y :: i32 }\n\nPoint.x = 1;  // Error: `Point` is a type, not a value."),
+    ("E0027.title", "Null Value"),
+    ("E0027.null_comparison", "Only the optional types (`T?`) can be compared with `null`, but found '{0}'."),
+    ("E0027.null_in_non_optional", "Only the optional types (`T?`) can hold `null`. Change the type to an optional type."),
+    ("E0027.explanation", "The value `null` is used with a type that isn't optional.\nOnly the optional types (`T?`) accept `null` and can be compared with it."),
+    ("E0027.example", "var x: i32 = null;   // Error: 'i32' isn't optional.\nvar x: i32? = null;  // Ok."),
+    ("E0028.title", "Non-Exhaustive Match"),
+    ("E0028.uncovered_variants", "The match don't cover the variants {0}. Add an arm for each one or a `_` arm."),
+    ("E0028.explanation", "The match doesn't cover all the variants of the enum.\nAdd an arm for each missing variant, or a `_` arm."),
+    ("E0028.example", "enum Shape { Circle(f64), Square(f64) }\n\nmatch s {\n    Shape::Circle(r) => r,\n}  // Error: `Shape::Square` isn't covered."),
+    ("E0029.title", "Unreachable Pattern"),
+    ("E0029.matched_variant", "The pattern `{0}::{1}` is unreachable, it was already matched by a previous arm."),
+    ("E0029.matched_wildcard", "The pattern `_` is unreachable, all the variants were already matched by previous arms."),
+    ("E0029.explanation", "The arm of the match is never selected.\nAn arm after of a `_` arm, or the second arm of the same variant, is unreachable."),
+    ("E0029.example", "match s {\n    _ => 0.0,\n    Shape::Circle(r) => r,  // Error: the `_` arm already covers it.\n}"),
+    ("E0030.title", "Missing Return"),
+    ("E0030.missing_return", "The function may finish without return a value of type '{0}'. Add a `return` at the end of the function."),
+    ("E0030.explanation", "The function doesn't return a value on all the paths.\nA function with a return type should end with a `return` or with a value on every path."),
+    ("E0030.example", "fn sign(x: i32) : i32 {\n    if x > 0 { return 1; }\n}  // Error: nothing is returned when `x` is not positive."),
+    ("E0031.title", "Missing Entrypoint"),
+    ("E0031.missing_main", "Missing entrypoint \"fn main() {}\" in main.th file."),
+    ("E0031.explanation", "The file `main.th` doesn't have a `main` function.\nThe program starts at the function `main` of the file `main.th`."),
+    ("E0031.example", "fn main() {\n    println(\"Hello\");\n}"),
+    ("E0032.title", "Escaping Closure"),
+    ("E0032.escaping_closure", "The closure captures variables by reference and can't escape of the function where it was created. Capture them by value."),
+    ("E0032.explanation", "The closure captures variables by reference and escapes of its function.\nThe captured variables are freed when the function returns, capture them by value."),
+    ("E0032.example", "fn counter() : fn[]() -> i32 {\n    var n: i32 = 0;\n    return fn[&n]() : i32 { return n; };  // Error: `n` doesn't live after of `counter`.\n}"),
+    ("E0033.title", "Invalid Capture"),
+    ("E0033.capture_by_reference", "The {0} `{1}` can only be captured by reference. Write `&{2}` in the capture list."),
+    ("E0033.explanation", "The value can't be captured by the closure.\nOnly the local variables can be captured, the functions and the types are used directly."),
+    ("E0033.example", "fn[sum](x: i32) : i32 { return sum(x, 1); }  // Error: `sum` is a function."),
+    ("E0034.title", "Undeclared Label"),
+    ("E0034.undeclared_label", "The label `'{0}` is not declared by any enclosing loop."),
+    ("E0034.explanation", "The `break` or the `continue` refers to a label of no enclosing loop."),
+    ("E0034.example", "'outer: for var i: i32 = 0; i < 10; i++; {\n    break 'inner;  // Error: there is no loop `'inner`.\n}"),
+    ("E0035.title", "Shadowed Label"),
+    ("E0035.shadowed_label", "The label `'{0}` shadows the label of an enclosing loop. Rename one of them."),
+    ("E0035.explanation", "The loop has the label of an enclosing loop.\nThe labels of nested loops should be different, so the `break` and the `continue` aren't ambiguous."),
+    ("E0035.example", "'outer: for var i: i32 = 0; i < 10; i++; {\n    'outer: for var j: i32 = 0; j < 10; j++; { ... }  // Error: `'outer` is used twice.\n}"),
+    ("E0036.title", "Type Annotations Needed"),
+    ("E0036.uninferable_result", "The type of `{0}(...)` can't be inferred. Assign it to a variable of type `Result<T, E>` or return it from a function that returns a `Result<T, E>`."),
+    ("E0036.explanation", "The types of the generic function can't be inferred from the arguments.\nWrite the types of the call explicitly."),
+    ("E0036.example", "fn empty<T>() : T? { return null; }\n\nvar x: i32? = empty();  // Error: the type of `T` is unknown."),
+    ("E0037.title", "Unreacheable Deallocation"),
+    ("E0037.unstored_char", "In this point the correctly deallocation is imposible. The char should be stored in a variable and pass it variable to the return."),
+    ("E0037.explanation", "The compiler can't free the memory of the value in this position.\nThe value should be stored in a variable before of this use."),
+    ("E0037.example", "println(\"{}\", concat(\"a\", \"b\"));  // Error: the result of `concat` is never freed."),
+    ("E0038.title", "Use of Moved Closure"),
+    ("E0038.moved_closure", "The closure \"{0}\" was moved before, its environment belongs to another variable or function now. Assign it a new closure before of use it."),
+    ("E0038.explanation", "The closure is used after of move it.\nCopying a closure, passing it to a function or capturing it by value moves its environment, the old variable can't be used anymore.\nAssign a new closure to the variable before of use it again."),
+    ("E0038.example", "var g: fn[]() -> i32 = f;\nf();  // Error: the environment of `f` belongs to `g`."),
+    ("W0001.title", "Unused Variable"),
+    ("W0001.unused_variable", "The variable `{0}` is never read. Remove it, or rename it to `_{1}`."),
+    ("W0001.explanation", "The variable is never read. (lint `unused_variable`)\nRemove it, or rename it with a leading `_` if it is unused on purpose."),
+    ("W0001.example", "var x: i32 = 10;   // Warning: `x` is never read.\nvar _x: i32 = 10;  // Ok."),
+    ("W0002.title", "Unused Parameter"),
+    ("W0002.unused_parameter", "The parameter `{0}` is never used. Remove it, or rename it to `_{1}`."),
+    ("W0002.explanation", "The parameter is never used. (lint `unused_parameter`)\nRemove it, or rename it with a leading `_` if the signature needs it."),
+    ("W0002.example", "fn zero(x: i32) : i32 { return 0; }   // Warning: `x` is never used.\nfn zero(_x: i32) : i32 { return 0; }  // Ok."),
+    ("W0003.title", "Unused Function"),
+    ("W0003.unused_function", "The private function `{0}` is never called. Remove it, or make it public with `public`."),
+    ("W0003.explanation", "The private function is never called. (lint `unused_function`)\nRemove it, or make it public with `public` if other files use it."),
+    ("W0003.example", "fn helper() { ... }         // Warning: `helper` is never called.\npublic fn helper() { ... }  // Ok."),
+    ("W0004.title", "Dead Store"),
+    ("W0004.dead_store", "The value stored in `{0}` is never read, it is overwritten or it goes out of scope before. Remove the store."),
+    ("W0004.explanation", "The value stored in the variable is never read. (lint `dead_store`)\nThe variable is assigned again or goes out of scope before of any read of the value."),
+    ("W0004.example", "var x: i32 = 1;  // Warning: the value `1` is never read.\nx = 2;\nprintln(\"{}\", x);"),
+    ("W0005.title", "Shadowed Variable"),
+    ("W0005.shadowed_here", "shadowed variable declared here"),
+    ("W0005.shadowing", "The variable `{0}` shadows the variable declared at line {1}. Rename one of them."),
+    ("W0005.explanation", "The variable has the name of a variable of an enclosing scope. (lint `shadowing`)\nThe outer variable can't be used while the inner one exists, rename one of them."),
+    ("W0005.example", "var x: i32 = 1;\n{\n    var x: i32 = 2;  // Warning: `x` shadows the variable of the line 1.\n}"),
+    ("W0006.title", "Unreachable Code"),
+    ("W0006.unreachable_code", "This code is never executed, the block always exits at line {0}. Remove it."),
+    ("W0006.explanation", "The code is never executed. (lint `unreachable_code`)\nThe block always exits with a `return`, a `break` or a `continue` before of it."),
+    ("W0006.example", "return 0;\nprintln(\"done\");  // Warning: the function already returned."),
+    ("W0007.title", "Constant Condition"),
+    ("W0007.loop_always_false", "The condition of the loop is always false, the body of the loop is never executed."),
+    ("W0007.loop_always_true", "The condition of the loop is always true, the loop only ends with a `break` or a `return`."),
+    ("W0007.explanation", "The condition always has the same value. (lint `constant_condition`)\nA loop with a condition always true only ends with a `break`, and one with a condition always false never runs."),
+    ("W0007.example", "for var i: i32 = 0; false; i++; { ... }  // Warning: the body never runs."),
+    ("cli.directory", "\"{0}\" is a directory"),
+    ("cli.error", "ERROR"),
+    ("cli.help.args", "Pass more arguments to the Backend Compiler."),
+    ("cli.help.code_model", "Define how code is organized and accessed in the executable or object file."),
+    ("cli.help.commands", "Available Commands:"),
+    ("cli.help.deny", "Report the lint or the group of lints as an error."),
+    ("cli.help.deny_warnings", "Report all the warnings as errors."),
+    ("cli.help.dynamic", "Link the executable dynamically."),
+    ("cli.help.emit", "Compile the code to Assembler or LLVM IR."),
+    ("cli.help.error_format", "Print the errors and the warnings as text, or as one JSON object per line in the stderr."),
+    ("cli.help.executable", "Compile the code into native executable."),
+    ("cli.help.explain", "Explain an error or a warning code, like \"E0010\" or \"W0001\"."),
+    ("cli.help.fix", "Apply the fixes of the errors and the warnings in place, or print them as a diff with \"--dry-run\"."),
+    ("cli.help.flags", "Available Flags:"),
+    ("cli.help.help", "Show help message."),
+    ("cli.help.include", "Include a Native API Code in the IR."),
+    ("cli.help.lang", "Write the diagnostics in English or in Spanish, by default the language of LANG."),
+    ("cli.help.library", "Compile to an object file ('*.o')."),
+    ("cli.help.native_target", "Print the native target of this machine."),
+    ("cli.help.optimization", "Optimization level for the executable to emit or object file."),
+    ("cli.help.output", "Output file format."),
+    ("cli.help.passes", "Run a custom pass pipeline instead of the one of the optimization level, like \"function(instcombine,gvn)\"."),
+    ("cli.help.print_passes", "Print every pass run by the optimizer in the stderr."),
+    ("cli.help.reloc", "Indicate how references to memory addresses are handled."),
+    ("cli.help.static", "Link the executable statically."),
+    ("cli.help.static_library", "Compile to an static library ('*.a')."),
+    ("cli.help.target", "Target architecture for the executable or object file."),
+    ("cli.help.targets", "Print the list of supported targets machines."),
+    ("cli.help.time_passes", "Print the time spent in every pass of the optimizer in the stderr."),
+    ("cli.help.title", "The Thrush Compiler"),
+    ("cli.help.usage", "Usage:"),
+    ("cli.help.version", "Show the version."),
+    ("cli.help.warn", "Report the lint or the group of lints as a warning."),
+    ("cli.incompatible_flags", "You can't use \"{0}\" and \"{1}\" together."),
+    ("cli.invalid_target", "Invalid target: {0}"),
+    ("cli.missing_api", "Missing built-in API specification for \"{0}\"."),
+    ("cli.missing_argument", "Missing argument for {0}"),
+    ("cli.missing_explain_code", "Missing error code for \"explain\"."),
+    ("cli.missing_fix_files", "Missing files for \"fix\"."),
+    ("cli.missing_lint", "Missing lint name for \"{0}\"."),
+    ("cli.missing_passes", "Missing pass pipeline for \"{0}\"."),
+    ("cli.missing_quoted_argument", "Missing argument for \"{0}\"."),
+    ("cli.not_thrush_file", "\"{0}\" is not a Thrush file."),
+    ("cli.several_mains", "Compile two or more \"main.th\" file don't allowed."),
+    ("cli.unknown_api", "Unknown built-in API name: \"{0}\"."),
+    ("cli.unknown_code", "Unknown error code \"{0}\". The codes are like \"E0001\" or \"W0001\"."),
+    ("cli.unknown_emit", "\"{0}\" is invalid target to emit raw compiled code. Maybe \"(--emit || -emit) llvm-ir || llvm-bc || asm\", is the command?"),
+    ("cli.unknown_error_format", "Unknown error format \"{0}\". Maybe \"--error-format human || json\", is the command?"),
+    ("cli.unknown_language", "Unknown language \"{0}\". Maybe \"--lang en || es\", is the command?"),
+    ("cli.unknown_lint", "Unknown lint \"{0}\"."),
+    ("cli.unknown_optimization", "Unknown optimization level \"{0}\""),
+    ("cli.without_extension", "\"{0}\" does not have extension."),
+    ("cli.without_name", "\"{0}\" does not have a name."),
+    ("diagnostic.did_you_mean", "Did you mean `{0}`?"),
+    ("diagnostic.errors", "{0} errors"),
+    ("diagnostic.from_generic_line", "The error above comes from this line of the generic function."),
+    ("diagnostic.from_generic_line_json", "The error comes from this line of the generic function."),
+    ("diagnostic.from_instantiation_line", "The error above comes from the generic function instantiated in this line."),
+    ("diagnostic.help", "Help"),
+    ("diagnostic.instantiation", "In the instantiation `{0}`"),
+    ("diagnostic.one_error", "1 error"),
+    ("diagnostic.one_warning", "1 warning"),
+    ("diagnostic.warnings", "{0} warnings"),
+    ("explain.example", "Example:"),
+];
@@ -0,0 +1,124 @@
+use std::{cell::Cell, fmt::Display};
+
+mod english;
+mod spanish;
+
+/*
+
+    MESSAGES
+
+    The messages of the compiler live in a catalogue per language, keyed by an identifier.
+    The messages of a diagnostic are keyed by its code and a short name, like `E0012.title`
+    or `W0001.unused_variable`, and `thrushc explain` reads `<code>.explanation` and
+    `<code>.example`. The rest are grouped by their module, like `cli.*` or `diagnostic.*`.
+
+    The parameters are written as `{0}`, `{1}`... in the order of the arguments, so a translation
+    can move them where its grammar needs them.
+
+    A message missing in a catalogue falls back to the English one.
+
+    The language is scoped to the work of a session, so two sessions with different languages
+    don't share it.
+
+*/
+
+thread_local! {
+    static LANGUAGE: Cell<Language> = const { Cell::new(Language::English) };
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+}
+
+impl Language {
+    pub fn from_code(code: &str) -> Option<Language> {
+        // Accepts the codes of the locales too, like `es_ES.UTF-8`.
+        match code
+            .split(['_', '-', '.'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase()
+            .as_str()
+        {
+            "en" => Some(Language::English),
+            "es" => Some(Language::Spanish),
+            _ => None,
+        }
+    }
+
+    pub fn from_env() -> Language {
+        std::env::var("LANG")
+            .ok()
+            .and_then(|code| Language::from_code(&code))
+            .unwrap_or_default()
+    }
+
+    fn messages(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Language::English => english::MESSAGES,
+            Language::Spanish => spanish::MESSAGES,
+        }
+    }
+}
+
+pub fn with_language<T>(language: Language, work: impl FnOnce() -> T) -> T {
+    // The previous language is restored after the work, like after a nested session.
+    let previous: Language = LANGUAGE.replace(language);
+    let result: T = work();
+
+    LANGUAGE.set(previous);
+
+    result
+}
+
+pub fn message(id: &str, args: &[&dyn Display]) -> String {
+    let language: Language = LANGUAGE.get();
+
+    let template: &str = find(language.messages(), id)
+        .or_else(|| find(english::MESSAGES, id))
+        .unwrap_or(id);
+
+    interpolate(template, args)
+}
+
+#[inline]
+fn find(messages: &'static [(&'static str, &'static str)], id: &str) -> Option<&'static str> {
+    messages
+        .iter()
+        .find(|(key, _)| *key == id)
+        .map(|(_, message)| *message)
+}
+
+fn interpolate(template: &str, args: &[&dyn Display]) -> String {
+    // Only the placeholders of the given arguments are replaced, any other brace is kept.
+    let mut output: String = String::with_capacity(template.len());
+    let mut rest: &str = template;
+
+    while let Some(open) = rest.find('{') {
+        output.push_str(&rest[..open]);
+        rest = &rest[open..];
+
+        let argument: Option<&&dyn Display> = rest[1..]
+            .find('}')
+            .and_then(|close| rest[1..close + 1].parse::<usize>().ok())
+            .and_then(|index| args.get(index));
+
+        match argument {
+            Some(argument) => {
+                output.push_str(&argument.to_string());
+                rest = &rest[rest.find('}').unwrap() + 1..];
+            }
+            None => {
+                output.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    output.push_str(rest);
+
+    output
+}
@@ -0,0 +1,437 @@
+pub const MESSAGES: &[(&str, &str)] = &[
+    ("E0001.title", "Error de Sintaxis"),
+    ("E0001.argument_count", "La función llamada esperaba todos los argumentos con los tipos '{0}' y no '{1}'."),
+    ("E0001.argument_type", "Función llamada, se esperaba un argumento de tipo '{0}' en la posición {1} y no de tipo '{2}'."),
+    ("E0001.associated_function_not_called", "Se esperaba '('. La función asociada `{0}::{1}` solo se puede llamar."),
+    ("E0001.binding_count", "El patrón `{0}::{1}` esperaba {2} enlaces pero se encontraron {3}."),
+    ("E0001.call_non_function", "El objeto llamado no es una función. Solo se pueden llamar funciones."),
+    ("E0001.char_length", "Un dato de tipo char solo puede contener un carácter."),
+    ("E0001.empty_enum", "El enum `{0}` debe tener al menos una variante."),
+    ("E0001.empty_struct", "El struct `{0}` debe tener al menos un campo."),
+    ("E0001.expected_allow_lparen", "Se esperaba '(' después de `@allow`."),
+    ("E0001.expected_associated_function", "Se esperaba `{0}::< función >(...)`."),
+    ("E0001.expected_binding", "Se esperaba el nombre de un enlace o `_`."),
+    ("E0001.expected_bound", "Se esperaba el nombre del trait. Se esperaba `fn nombre<T: Trait + ...>(...)`."),
+    ("E0001.expected_call_lparen", "Se esperaba '('. Se esperaba `{0}(...)`."),
+    ("E0001.expected_capture_name", "Se esperaba el nombre de una variable capturada."),
+    ("E0001.expected_closure_type_rbracket", "Se esperaba ']'. Los tipos de las clausuras se escriben `fn[](tipo, ...) -> tipo`."),
+    ("E0001.expected_colon", "Se esperaba ':'."),
+    ("E0001.expected_colon_after_label", "Se esperaba ':' después de la etiqueta del bucle. Como `'outer: for ...`."),
+    ("E0001.expected_colon_colon", "Se esperaba '::'."),
+    ("E0001.expected_else_body", "Se esperaba un 'bloque ({ ... })' para el cuerpo del else."),
+    ("E0001.expected_enum_declaration", "Se esperaba enum < nombre > { ... }."),
+    ("E0001.expected_enum_name", "Se esperaba el nombre del enum"),
+    ("E0001.expected_enum_variant", "Se esperaba `{0}::< variante >`."),
+    ("E0001.expected_external_function", "Se esperaba fn < nombre >."),
+    ("E0001.expected_external_name", "Se esperaba un literal de cadena para @external(\"NOMBRE\")."),
+    ("E0001.expected_fat_arrow", "Se esperaba '=>'."),
+    ("E0001.expected_field_declaration", "Se esperaba un campo como `nombre :: tipo`."),
+    ("E0001.expected_field_name", "Se esperaba el nombre del campo"),
+    ("E0001.expected_for_in_variable", "Se esperaba `for (nombre) in ...`."),
+    ("E0001.expected_format_title", "Se esperaba un formato"),
+    ("E0001.expected_function_body", "Se esperaba un 'bloque ({ ... })' para el cuerpo de la función."),
+    ("E0001.expected_function_name", "Se esperaba el nombre de la función"),
+    ("E0001.expected_function_name_help", "Se esperaba un nombre para la función."),
+    ("E0001.expected_function_type_lparen", "Se esperaba '(' para el tipo de la función. Se esperaba `fn(tipo, ...) -> tipo`."),
+    ("E0001.expected_if_body", "Se esperaba un 'bloque ({ ... })' para el cuerpo del if."),
+    ("E0001.expected_if_let_eq", "Se esperaba '=' después del patrón del if let."),
+    ("E0001.expected_impl_declaration", "Se esperaba impl < struct > { ... } o impl < trait > for < struct > { ... }."),
+    ("E0001.expected_impl_name", "Se esperaba el nombre de un trait o de un struct"),
+    ("E0001.expected_index", "Se esperaba un número sin signo para construir un índice."),
+    ("E0001.expected_lambda_body", "Se esperaba un 'bloque ({ ... })' para el cuerpo de la lambda."),
+    ("E0001.expected_lambda_lparen", "Se esperaba '(' para los parámetros de la lambda."),
+    ("E0001.expected_lbrace", "Se esperaba '{'."),
+    ("E0001.expected_lbracket", "Se esperaba '['."),
+    ("E0001.expected_lint_name", "Se esperaba el nombre de un lint."),
+    ("E0001.expected_loop_body", "Se esperaba un 'bloque ({ ... })' para el cuerpo del bucle."),
+    ("E0001.expected_lparen", "Se esperaba '('."),
+    ("E0001.expected_method", "Se esperaba un método `fn nombre(this, ...) { ... }`."),
+    ("E0001.expected_method_name", "Se esperaba el nombre del método"),
+    ("E0001.expected_method_signature", "Se esperaba una firma de método como `fn nombre(this, ...) : tipo;`."),
+    ("E0001.expected_parameter_name", "Se esperaba el nombre del argumento."),
+    ("E0001.expected_parameter_type", "Se esperaba el tipo del argumento."),
+    ("E0001.expected_pattern", "Se esperaba un patrón"),
+    ("E0001.expected_pattern_help", "Se esperaba un patrón como `{0}::< variante >` o `_`."),
+    ("E0001.expected_payload_type", "Se esperaba un tipo para el contenido de la variante `{0}::{1}`."),
+    ("E0001.expected_property", "Se esperaba el nombre de un campo o de un método después de '.'."),
+    ("E0001.expected_rbracket", "Se esperaba ']'."),
+    ("E0001.expected_result_comma", "Se esperaba ','. Se esperaba `Result<T, E>`."),
+    ("E0001.expected_result_greater", "Se esperaba '>'. Se esperaba `Result<T, E>`."),
+    ("E0001.expected_return_type", "Falta el tipo de retorno. Se esperaba ':' seguido del tipo de retorno."),
+    ("E0001.expected_rparen", "Se esperaba ')'."),
+    ("E0001.expected_semicolon", "Se esperaba ';'."),
+    ("E0001.expected_struct_declaration", "Se esperaba struct < nombre > { ... }."),
+    ("E0001.expected_struct_literal", "Se esperaba `{0} { campo: valor, ... }`."),
+    ("E0001.expected_struct_name", "Se esperaba el nombre del struct"),
+    ("E0001.expected_ternary_colon", "Se esperaba ':' para el ternario. Como `cond ? a : b`."),
+    ("E0001.expected_trait_declaration", "Se esperaba trait < nombre > { ... }."),
+    ("E0001.expected_trait_impl_declaration", "Se esperaba impl < trait > for < struct > { ... }."),
+    ("E0001.expected_trait_name", "Se esperaba el nombre del trait"),
+    ("E0001.expected_type_parameter", "Se esperaba el nombre del parámetro de tipo. Se esperaba `fn nombre<T, ...>(...)`."),
+    ("E0001.expected_variable_colon", "Se esperaba el indicador de tipo de la variable"),
+    ("E0001.expected_variable_colon_help", "Se esperaba `var nombre --> : <-- tipo = valor;`."),
+    ("E0001.expected_variable_declaration", "Se esperaba var (nombre)."),
+    ("E0001.expected_variable_eq", "Se esperaba '=' para la definición de la variable."),
+    ("E0001.expected_variable_name", "Se esperaba el nombre de la variable"),
+    ("E0001.expected_variable_type", "Se esperaba el tipo de la variable."),
+    ("E0001.expected_variant_declaration", "Se esperaba una variante como `Nombre` o `Nombre(tipo, ...)`."),
+    ("E0001.expected_variant_name", "Se esperaba el nombre de la variante"),
+    ("E0001.format_argument_count", "Falta un formato para un argumento o un argumento. Debería ser así println(\"{}\", argumentos.size() == formatos.size());"),
+    ("E0001.function_type_returns_struct", "Los tipos de función no pueden devolver structs."),
+    ("E0001.generic_argument_count", "La función genérica `{0}` esperaba {1} argumentos pero se encontraron {2}."),
+    ("E0001.generic_function_not_called", "Se esperaba '('. La función genérica `{0}` solo se puede llamar."),
+    ("E0001.generic_method", "Los métodos no pueden tener parámetros de tipo."),
+    ("E0001.generic_without_body", "Las funciones genéricas deben tener un cuerpo."),
+    ("E0001.group_without_binary", "Agrupar las expresiones \"(...)\" solo se permite si contienen expresiones binarias."),
+    ("E0001.if_value_without_else", "El if que devuelve un valor debe tener una rama else. Añade un `else { ... }`."),
+    ("E0001.ignored_parameters", "La sentencia de paso \"(...)\" en funciones solo se permite en funciones externas."),
+    ("E0001.invalid_float", "Los valores flotantes solo deben contener un punto."),
+    ("E0001.invalid_float_title", "Sintaxis de Flotante Inválida"),
+    ("E0001.iterated_expression", "Solo se pueden iterar variables y literales. Guarda el valor en una variable antes del bucle."),
+    ("E0001.jump_outside_loop", "La sentencia `{0}` solo se permite dentro de un bucle."),
+    ("E0001.label_without_loop", "La etiqueta `'{0}` solo se permite antes de un bucle. Como `'{1}: for ...`."),
+    ("E0001.lambda_returns_struct", "Las lambdas no pueden devolver structs."),
+    ("E0001.match_non_enum", "El match solo se permite para enums, se encontró '{0}'."),
+    ("E0001.method_without_receiver", "El método `{0}` necesita un receptor. Llámalo como `< valor >.{1}(...)`."),
+    ("E0001.method_without_this", "El método `{0}::{1}` debe recibir `this` como primer parámetro."),
+    ("E0001.misplaced_this", "`this` solo se permite como el primer parámetro de un método."),
+    ("E0001.missing_variable_colon", "Se esperaba el tipo de la variable. Olvidaste los `:`."),
+    ("E0001.missing_variable_type", "Se esperaba el tipo de la variable. Escríbelo como `var nombre: tipo = valor;`."),
+    ("E0001.nested_enum", "Los enums deben ir en el ámbito global. Reescríbelo en el ámbito global."),
+    ("E0001.nested_function", "Las funciones deben ir en el ámbito global. Reescríbela en el ámbito global."),
+    ("E0001.nested_impl", "Las implementaciones deben ir en el ámbito global. Reescríbela en el ámbito global."),
+    ("E0001.nested_struct", "Los structs deben ir en el ámbito global. Reescríbelo en el ámbito global."),
+    ("E0001.nested_trait", "Los traits deben ir en el ámbito global. Reescríbelo en el ámbito global."),
+    ("E0001.no_properties", "El objeto `{0}` de tipo '{1}' no tiene propiedades."),
+    ("E0001.non_primitive_result", "Los tipos de `Result<T, E>` deben ser tipos primitivos."),
+    ("E0001.optional_void", "El tipo 'void' no puede ser opcional."),
+    ("E0001.payload_count", "La variante `{0}::{1}` esperaba {2} valores pero se encontraron {3}."),
+    ("E0001.pointer_argument_count", "El puntero a función `{0}` de tipo '{1}' esperaba {2} argumentos y no {3}."),
+    ("E0001.print_newline", "No puedes imprimir cadenas que contienen saltos de línea. Usa 'println' en su lugar."),
+    ("E0001.println_without_arguments", "Se esperaba al menos 1 argumento para la llamada a 'println'. Como 'println(\"¡Hola!\");'"),
+    ("E0001.question_without_result", "El operador `?` solo se puede usar en funciones que devuelven un `Result<T, E>`."),
+    ("E0001.return_outside_function", "Sentencia return fuera de una función. Usa esta palabra clave dentro de una función."),
+    ("E0001.return_value_in_void_function", "Falta el indicador de tipo de la función con el tipo '{0}', deberías añadir un indicador de tipo correcto con el tipo '{1}'."),
+    ("E0001.return_without_value", "Falta la sentencia return con el tipo '{0}', deberías reescribir el return con el tipo '{1}'."),
+    ("E0001.statement_not_allowed", "La sentencia `{0}` no está permitida."),
+    ("E0001.struct_return_as_value", "La función `{0}` devuelve un struct y no se puede usar como un valor."),
+    ("E0001.trait_method_body", "Se esperaba ';'. Los métodos de un trait no tienen cuerpo."),
+    ("E0001.undefined_variable_type", "El tipo de la variable no está definido. ¿Olvidaste indicar el tipo de la variable sin valor?"),
+    ("E0001.uninferable_type_parameter", "No se puede inferir el parámetro de tipo `{0}` de `{1}`. Úsalo en el tipo de un parámetro."),
+    ("E0001.unpassable_parameter_type", "El tipo `{0}` no se puede pasar como argumento. Usa `match` antes de la llamada."),
+    ("E0001.unterminated_char", "Char sin terminar. ¿Olvidaste cerrar el char con un '\''?"),
+    ("E0001.unterminated_comment", "Comentario multilínea sin terminar. ¿Olvidaste cerrar el comentario con un '*/'?"),
+    ("E0001.unterminated_string", "Cadena sin terminar. ¿Olvidaste cerrar la cadena con un '\"'?"),
+    ("E0001.value_type", "este valor tiene el tipo '{0}'"),
+    ("E0001.variant_argument_count", "La variante `{0}::{1}` esperaba {2} argumentos pero se encontraron {3}."),
+    ("E0001.zero_range_step", "El paso de un rango no puede ser cero, el bucle nunca terminaría."),
+    ("E0001.explanation", "El código no sigue la gramática del lenguaje.\nEl parser esperaba otro token en esta posición, la ayuda del error dice cuál.\nUn `;`, `)` o `}` que falta es la causa más común."),
+    ("E0001.example", "var x: i32 = 10   // Error: se esperaba ';'.\nvar x: i32 = 10;  // Ok."),
+    ("E0002.title", "Número Inalcanzable."),
+    ("E0002.size_out_of_bounds", "El tamaño está fuera de los límites de un isize (0 a n)."),
+    ("E0002.explanation", "El número no se puede representar con ningún tipo numérico.\nLos enteros deben caber en 64 bits y los flotantes en un `f64`."),
+    ("E0002.example", "var x: i64 = 99999999999999999999;  // Error: el número es demasiado grande.\nvar x: i64 = 9999999999;            // Ok."),
+    ("E0003.title", "Número Inalcanzable"),
+    ("E0003.float_too_big", "El número es demasiado grande para un flotante."),
+    ("E0003.invalid_number", "¿Escribiste un número válido, con el formato correcto y dentro de los límites?"),
+    ("E0003.number_too_big", "El número es demasiado grande para un entero o un flotante."),
+    ("E0003.explanation", "El número está mal formado o es demasiado grande para su tipo.\nEl literal no se pudo leer como un entero ni como un flotante."),
+    ("E0003.example", "var x: f64 = 1.2.3;  // Error: un flotante tiene un solo '.'.\nvar x: f64 = 1.23;   // Ok."),
+    ("E0004.title", "Carácter desconocido."),
+    ("E0004.unknown_character", "¿Escribiste un carácter válido?"),
+    ("E0004.explanation", "El código contiene un carácter que no es parte del lenguaje.\nFuera de las cadenas y los comentarios solo son válidos los caracteres de los operadores, los delimitadores, los identificadores y los literales."),
+    ("E0004.example", "var x: i32 = 10 $ 2;  // Error: '$' no es un operador.\nvar x: i32 = 10 * 2;  // Ok."),
+    ("E0005.title", "Variable Inalcanzable"),
+    ("E0005.unreachable_variable", "La variable `{0}` es inalcanzable desde el ámbito actual."),
+    ("E0005.explanation", "La variable se usa antes de su declaración.\nLas variables solo existen desde la sentencia que las declara hasta el final de su bloque."),
+    ("E0005.example", "println(\"{}\", x);  // Error: `x` aún no está declarada.\nvar x: i32 = 10;\n\nvar x: i32 = 10;\nprintln(\"{}\", x);  // Ok."),
+    ("E0006.title", "Objeto no Encontrado"),
+    ("E0006.undefined_object", "El objeto con el nombre \"{0}\" no está en este ámbito ni en el ámbito global."),
+    ("E0006.explanation", "El nombre no se refiere a ninguna variable, función o tipo del ámbito.\nRevisa cómo está escrito el nombre, y decláralo antes de usarlo."),
+    ("E0006.example", "var y: i32 = z + 1;  // Error: `z` no está definida.\n\nvar z: i32 = 1;\nvar y: i32 = z + 1;  // Ok."),
+    ("E0007.title", "Variable no Definida"),
+    ("E0007.undefined_variable", "La variable `{0}` no se encontró en este ámbito."),
+    ("E0007.explanation", "La variable no está definida en este ámbito.\nUna variable declarada dentro de un bloque no se puede usar fuera de él."),
+    ("E0007.example", "{\n    var x: i32 = 1;\n}\nprintln(\"{}\", x);  // Error: `x` solo existe dentro del bloque."),
+    ("E0008.title", "Variable no Inicializada"),
+    ("E0008.break_before_assignment", "el break de la línea {0} sale del bucle antes de asignarla"),
+    ("E0008.declared_here", "declarada aquí sin valor"),
+    ("E0008.declared_without_value", "declarada sin valor en la línea {0}"),
+    ("E0008.list_and", "{0} y {1}"),
+    ("E0008.loop_may_not_run", "el bucle de la línea {0} puede ejecutarse cero veces"),
+    ("E0008.missing_else", "el if de la línea {0} no tiene rama else"),
+    ("E0008.unassigned_arm", "el brazo {0} del match de la línea {1} no la asigna"),
+    ("E0008.unassigned_else", "la rama else del if de la línea {0} no la asigna"),
+    ("E0008.unassigned_if", "la rama if de la línea {0} no la asigna"),
+    ("E0008.uninitialized_variable", "La variable `{0}` se usa antes de ser asignada en todos los caminos. Fue {1}. Asígnale un valor en cada camino antes de usarla."),
+    ("E0008.explanation", "La variable se usa antes de ser asignada en todos los caminos.\nUna variable declarada sin valor debe asignarse en cada camino del flujo de control que llega a su uso."),
+    ("E0008.example", "var x: i32;\nif cond { x = 1; }\nprintln(\"{}\", x);  // Error: `x` no se asigna cuando `cond` es falsa.\n\nvar x: i32;\nif cond { x = 1; } else { x = 2; }\nprintln(\"{}\", x);  // Ok."),
+    ("E0009.title", "Código Inacabado"),
+    ("E0009.unexpected_end", "El código terminó de forma abrupta y sin orden, revisa el código y escribe la sintaxis correctamente."),
+    ("E0009.explanation", "El código termina en medio de una sentencia.\nFalta algún delimitador o el resto de la sentencia al final del archivo."),
+    ("E0009.example", "fn main() {\n    println(\"Hello\");\n// Error: falta la '}' de la función."),
+    ("E0010.title", "Tipos Incompatibles"),
+    ("E0010.arm_types", "Los brazos del match deben tener el mismo tipo. Se esperaba '{0}' pero se encontró '{1}'."),
+    ("E0010.branch_types", "Las ramas deben tener el mismo tipo. Se esperaba '{0}' pero se encontró '{1}'."),
+    ("E0010.conflicting_inference", "El parámetro de tipo `{0}` de `{1}` se infiere como '{2}' y como '{3}'. Pasa argumentos del mismo tipo."),
+    ("E0010.expected_due_to_type", "esperado por este tipo"),
+    ("E0010.expected_found", "Tipos incompatibles. Se esperaba '{0}' pero se encontró '{1}'."),
+    ("E0010.non_bool_if_condition", "La condición del if debe ser de tipo 'bool' pero se encontró '{0}'."),
+    ("E0010.non_bool_ternary_condition", "La condición del ternario debe ser de tipo 'bool' pero se encontró '{0}'."),
+    ("E0010.non_enum_if_let", "Se esperaba un valor de un enum para el if let pero se encontró '{0}'."),
+    ("E0010.non_integer_range", "Los límites y el paso de un rango deben ser enteros, pero se encontró '{0}'."),
+    ("E0010.not_iterable", "El tipo '{0}' no es iterable. Usa un rango 'inicio..fin' o una cadena."),
+    ("E0010.pattern_enum", "Se esperaba un patrón del enum `{0}` pero se encontró `{1}`."),
+    ("E0010.pointer_argument_type", "Puntero a función llamado, se esperaba un argumento de tipo '{0}' en la posición {1} y no de tipo '{2}'."),
+    ("E0010.propagated_error", "El error de `{0}` no se puede propagar desde una función que devuelve `{1}`. Los tipos de error deben ser iguales."),
+    ("E0010.variable_type", "`{0}` tiene el tipo '{1}'"),
+    ("E0010.void_inference", "El parámetro de tipo `{0}` de `{1}` no se puede inferir como 'void'."),
+    ("E0010.explanation", "El tipo del valor no es el esperado.\nEl valor asignado a una variable, pasado como argumento o devuelto debe tener el tipo declarado. Los enteros se amplían a los tipos enteros más grandes."),
+    ("E0010.example", "var x: i32 = \"hello\";  // Error: se esperaba 'i32' pero se encontró 'str'.\nvar x: i32 = 10;       // Ok."),
+    ("E0011.title", "Comprobación de Tipos"),
+    ("E0011.addition", "La suma ({0} + {1}) es imposible. Revisa tus operandos y sus tipos."),
+    ("E0011.and", "La operación lógica ({0} && {1}) es imposible. Revisa tus operandos y sus tipos."),
+    ("E0011.comparison_return_type", "El método de operador `{0}::{1}` debe devolver 'bool'."),
+    ("E0011.decrement", "El decremento (--{0} o {1}--) es imposible. Revisa tu operando y su tipo."),
+    ("E0011.division", "La división ({0} / {1}) es imposible. Revisa tus operandos y sus tipos."),
+    ("E0011.equality", "La operación lógica ({0} == {1}) es imposible. Revisa tus operandos y sus tipos."),
+    ("E0011.greater", "La operación lógica ({0} > {1}) es imposible. Revisa tus operandos y sus tipos."),
+    ("E0011.greater_equal", "La operación lógica ({0} >= {1}) es imposible. Revisa tus operandos y sus tipos."),
+    ("E0011.increment", "El incremento (++{0} o {1}++) es imposible. Revisa tu operando y su tipo."),
+    ("E0011.inequality", "La operación lógica ({0} != {1}) es imposible. Revisa tus operandos y sus tipos."),
+    ("E0011.less", "La operación lógica ({0} < {1}) es imposible. Revisa tus operandos y sus tipos."),
+    ("E0011.less_equal", "La operación lógica ({0} <= {1}) es imposible. Revisa tus operandos y sus tipos."),
+    ("E0011.multiplication", "La multiplicación ({0} * {1}) es imposible. Revisa tus operandos y sus tipos."),
+    ("E0011.negation", "La negación (-{0}) es imposible. Revisa tu operando y su tipo."),
+    ("E0011.not", "La operación lógica (!{0}) es imposible. Revisa tu operando y su tipo."),
+    ("E0011.not_overloaded", "El operador '{0}' no está sobrecargado para `{1}`. Declara el método `fn {2}(this, other :: {3}) { ... }` en `impl {4} { ... }`."),
+    ("E0011.operand_type", "El operador '{0}' de `{1}` esperaba un operando '{2}' pero se encontró '{3}'."),
+    ("E0011.operator_parameter_count", "El método de operador `{0}::{1}` debe recibir exactamente un parámetro además de `this`."),
+    ("E0011.operator_return_type", "El método de operador `{0}::{1}` debe devolver `{2}` o un tipo primitivo."),
+    ("E0011.or", "La operación lógica ({0} || {1}) es imposible. Revisa tus operandos y sus tipos."),
+    ("E0011.struct_left_operand", "El operador '{0}' de `{1}` necesita el struct como operando izquierdo."),
+    ("E0011.subtraction", "La resta ({0} - {1}) es imposible. Revisa tus operandos y sus tipos."),
+    ("E0011.explanation", "El operador no se puede aplicar a los tipos de sus operandos.\nLos operadores aritméticos necesitan números, los operadores lógicos necesitan booleanos."),
+    ("E0011.example", "var x: i32 = 1 + true;  // Error: '+' no puede sumar un entero y un booleano.\nvar x: i32 = 1 + 2;     // Ok."),
+    ("E0012.title", "Tipo Desconocido"),
+    ("E0012.undefined_type", "El tipo `{0}` no está definido. Decláralo antes de usarlo."),
+    ("E0012.explanation", "El tipo no está definido.\nLos tipos son los tipos integrados, o un enum, un struct o un alias de tipo declarado antes de usarlo."),
+    ("E0012.example", "var s: Shape = Shape::Circle(1.0);  // Error: `Shape` no está declarado.\n\nenum Shape { Circle(f64) }\nvar s: Shape = Shape::Circle(1.0);  // Ok."),
+    ("E0013.title", "Campo Desconocido"),
+    ("E0013.unknown_field", "El struct `{0}` no tiene un campo llamado `{1}`."),
+    ("E0013.explanation", "El struct no tiene un campo con este nombre."),
+    ("E0013.example", "struct Point { x :: i32, y :: i32 }\n\nvar p: Point = Point { x: 1, y: 2 };\nprintln(\"{}\", p.z);  // Error: `Point` no tiene el campo `z`."),
+    ("E0014.title", "Función Desconocida"),
+    ("E0014.unknown_associated_function", "El struct `{0}` no tiene una función asociada llamada `{1}`."),
+    ("E0014.explanation", "La función no existe.\nLas funciones deben declararse en el mismo archivo o en uno importado."),
+    ("E0014.example", "sum(1, 2);  // Error: `sum` no está declarada.\n\nfn sum(a: i32, b: i32) : i32 { return a + b; }\nsum(1, 2);  // Ok."),
+    ("E0015.title", "Método Desconocido"),
+    ("E0015.not_trait_member", "El método `{0}` no es miembro del trait `{1}`."),
+    ("E0015.unknown_method", "El struct `{0}` no tiene un método llamado `{1}`."),
+    ("E0015.explanation", "El tipo no tiene un método con este nombre.\nLos métodos vienen de los bloques `impl` del struct y de los traits que implementa."),
+    ("E0015.example", "struct Point { x :: i32, y :: i32 }\n\np.length();  // Error: `Point` no tiene el método `length`."),
+    ("E0016.title", "Trait Desconocido"),
+    ("E0016.undefined_trait", "El trait `{0}` no está definido. Decláralo antes de usarlo."),
+    ("E0016.explanation", "El trait no está declarado."),
+    ("E0016.example", "impl Display for Point { ... }  // Error: `Display` no está declarado.\n\ntrait Display { fn show(this) : void; }\nimpl Display for Point { ... }  // Ok."),
+    ("E0017.title", "Variante Desconocida"),
+    ("E0017.unknown_variant", "El enum `{0}` no tiene una variante llamada `{1}`."),
+    ("E0017.explanation", "El enum no tiene una variante con este nombre."),
+    ("E0017.example", "enum Shape { Circle(f64), Square(f64) }\n\nvar s: Shape = Shape::Triangle(1.0);  // Error: `Shape` no tiene la variante `Triangle`."),
+    ("E0018.title", "Captura Desconocida"),
+    ("E0018.unknown_capture", "La variable capturada `{0}` no está definida en el ámbito exterior."),
+    ("E0018.explanation", "El closure captura una variable que no existe.\nSolo se pueden capturar las variables de la función que lo contiene."),
+    ("E0018.example", "var f: fn[](i32) -> i32 = fn[y](x: i32) : i32 { return x + y; };  // Error: `y` no está declarada."),
+    ("E0019.title", "Lint Desconocido"),
+    ("E0019.unknown_lint", "El lint `{0}` no existe. Los lints son `unused_variable`, `unused_parameter`, `unused_function`, `dead_store`, `shadowing`, `unreachable_code` y `constant_condition`, o los grupos `unused` y `warnings`."),
+    ("E0019.explanation", "El lint dado a `@allow` no existe.\nLos lints son unused_variable, unused_parameter, unused_function, dead_store, shadowing, unreachable_code y constant_condition, y los grupos unused y warnings."),
+    ("E0019.example", "@allow(unused_vars)      // Error: el lint es `unused_variable`.\n@allow(unused_variable)  // Ok."),
+    ("E0020.duplicated_entrypoint", "El lenguaje no admite dos puntos de entrada, elimina uno."),
+    ("E0020.duplicated_entrypoint_title", "Punto de Entrada Duplicado"),
+    ("E0020.duplicated_enum", "El enum `{0}` ya está definido, renombra uno de ellos."),
+    ("E0020.duplicated_enum_title", "Enum Duplicado"),
+    ("E0020.duplicated_field", "El campo `{0}::{1}` ya está definido, renombra uno de ellos."),
+    ("E0020.duplicated_field_title", "Campo Duplicado"),
+    ("E0020.duplicated_impl", "El trait `{0}` ya está implementado para `{1}`."),
+    ("E0020.duplicated_impl_method", "El método `{0}` ya está implementado para `{1}`."),
+    ("E0020.duplicated_impl_title", "Implementación Duplicada"),
+    ("E0020.duplicated_method", "El método `{0}::{1}` ya está definido, renombra uno de ellos."),
+    ("E0020.duplicated_method_title", "Método Duplicado"),
+    ("E0020.duplicated_struct", "El struct `{0}` ya está definido, renombra uno de ellos."),
+    ("E0020.duplicated_struct_title", "Struct Duplicado"),
+    ("E0020.duplicated_trait", "El trait `{0}` ya está definido, renombra uno de ellos."),
+    ("E0020.duplicated_trait_title", "Trait Duplicado"),
+    ("E0020.duplicated_variant", "La variante `{0}::{1}` ya está definida, renombra una de ellas."),
+    ("E0020.duplicated_variant_title", "Variante Duplicada"),
+    ("E0020.field_initialized_twice", "El campo `{0}` se inicializa más de una vez."),
+    ("E0020.explanation", "El nombre está definido dos veces.\nDos enums, structs, traits, campos, variantes o métodos del mismo ámbito no pueden tener el mismo nombre, y un trait se puede implementar una vez por tipo."),
+    ("E0020.example", "struct Point { x :: i32, x :: i32 }  // Error: el campo `x` está duplicado.\nstruct Point { x :: i32, y :: i32 }  // Ok."),
+    ("E0021.title", "Método Ambiguo"),
+    ("E0021.ambiguous_method", "El método `{0}` está definido por varios traits implementados para `{1}`. Renombra uno de ellos."),
+    ("E0021.explanation", "El método existe en varios traits implementados por el tipo.\nLlámalo a través del trait para elegir uno de ellos."),
+    ("E0021.example", "impl Show for Point { fn name(this) : str { ... } }\nimpl Debug for Point { fn name(this) : str { ... } }\n\np.name();  // Error: `name` viene de `Show` y de `Debug`."),
+    ("E0022.title", "Firma Incompatible"),
+    ("E0022.signature_mismatch", "El método `{0}` debe tener la firma declarada en el trait `{1}`."),
+    ("E0022.explanation", "El método de la implementación no tiene la firma del trait.\nLos parámetros y el tipo de retorno deben ser los declarados por el trait."),
+    ("E0022.example", "trait Area { fn area(this) : f64; }\n\nimpl Area for Square {\n    fn area(this) : i32 { ... }  // Error: el trait devuelve 'f64'.\n}"),
+    ("E0023.title", "Falta un Método"),
+    ("E0023.missing_method", "El método `{0}` del trait `{1}` no está implementado para `{2}`."),
+    ("E0023.explanation", "La implementación del trait no define todos sus métodos."),
+    ("E0023.example", "trait Shape { fn area(this) : f64; fn perimeter(this) : f64; }\n\nimpl Shape for Square {\n    fn area(this) : f64 { ... }\n}  // Error: falta `perimeter`."),
+    ("E0024.title", "Restricción no Satisfecha"),
+    ("E0024.unsatisfied_bound", "El tipo '{0}' no implementa el trait `{1}` requerido por el parámetro de tipo `{2}` de `{3}`."),
+    ("E0024.explanation", "El tipo dado a una función genérica no implementa los traits de su restricción."),
+    ("E0024.example", "fn show<T: Display>(value: T) { ... }\n\nshow(10);  // Error: 'i32' no implementa `Display`."),
+    ("E0025.title", "Falta un Campo"),
+    ("E0025.missing_field", "El campo `{0}` del struct `{1}` no está inicializado."),
+    ("E0025.explanation", "El literal del struct no inicializa todos los campos del struct."),
+    ("E0025.example", "struct Point { x :: i32, y :: i32 }\n\nvar p: Point = Point { x: 1 };        // Error: falta `y`.\nvar p: Point = Point { x: 1, y: 2 };  // Ok."),
+    ("E0026.title", "Campo Inválido"),
+    ("E0026.invalid_field", "El campo `{0}::{1}` no puede ser de tipo '{2}'."),
+    ("E0026.explanation", "El campo no se puede usar de esta forma.\nLos campos solo se leen o se asignan a través de un valor del struct."),
+    ("E0026.example", "struct Point { x :: i32, y :: i32 }\n\nPoint.x = 1;  // Error: `Point` es un tipo, no un valor."),
+    ("E0027.title", "Valor Nulo"),
+    ("E0027.null_comparison", "Solo los tipos opcionales (`T?`) se pueden comparar con `null`, pero se encontró '{0}'."),
+    ("E0027.null_in_non_optional", "Solo los tipos opcionales (`T?`) pueden contener `null`. Cambia el tipo a un tipo opcional."),
+    ("E0027.explanation", "El valor `null` se usa con un tipo que no es opcional.\nSolo los tipos opcionales (`T?`) aceptan `null` y se pueden comparar con él."),
+    ("E0027.example", "var x: i32 = null;   // Error: 'i32' no es opcional.\nvar x: i32? = null;  // Ok."),
+    ("E0028.title", "Match no Exhaustivo"),
+    ("E0028.uncovered_variants", "El match no cubre las variantes {0}. Añade un brazo para cada una o un brazo `_`."),
+    ("E0028.explanation", "El match no cubre todas las variantes del enum.\nAñade un brazo por cada variante que falta, o un brazo `_`."),
+    ("E0028.example", "enum Shape { Circle(f64), Square(f64) }\n\nmatch s {\n    Shape::Circle(r) => r,\n}  // Error: `Shape::Square` no está cubierta."),
+    ("E0029.title", "Patrón Inalcanzable"),
+    ("E0029.matched_variant", "El patrón `{0}::{1}` es inalcanzable, ya fue cubierto por un brazo anterior."),
+    ("E0029.matched_wildcard", "El patrón `_` es inalcanzable, todas las variantes ya fueron cubiertas por los brazos anteriores."),
+    ("E0029.explanation", "El brazo del match nunca se elige.\nUn brazo después de un brazo `_`, o el segundo brazo de la misma variante, es inalcanzable."),
+    ("E0029.example", "match s {\n    _ => 0.0,\n    Shape::Circle(r) => r,  // Error: el brazo `_` ya la cubre.\n}"),
+    ("E0030.title", "Falta el Return"),
+    ("E0030.missing_return", "La función puede terminar sin devolver un valor de tipo '{0}'. Añade un `return` al final de la función."),
+    ("E0030.explanation", "La función no devuelve un valor en todos los caminos.\nUna función con tipo de retorno debe terminar con un `return` o con un valor en cada camino."),
+    ("E0030.example", "fn sign(x: i32) : i32 {\n    if x > 0 { return 1; }\n}  // Error: no se devuelve nada cuando `x` no es positivo."),
+    ("E0031.title", "Falta el Punto de Entrada"),
+    ("E0031.missing_main", "Falta el punto de entrada \"fn main() {}\" en el archivo main.th."),
+    ("E0031.explanation", "El archivo `main.th` no tiene una función `main`.\nEl programa empieza en la función `main` del archivo `main.th`."),
+    ("E0031.example", "fn main() {\n    println(\"Hello\");\n}"),
+    ("E0032.title", "Clausura que Escapa"),
+    ("E0032.escaping_closure", "La clausura captura variables por referencia y no puede escapar de la función donde se creó. Captúralas por valor."),
+    ("E0032.explanation", "El closure captura variables por referencia y escapa de su función.\nLas variables capturadas se liberan cuando la función retorna, captúralas por valor."),
+    ("E0032.example", "fn counter() : fn[]() -> i32 {\n    var n: i32 = 0;\n    return fn[&n]() : i32 { return n; };  // Error: `n` no vive después de `counter`.\n}"),
+    ("E0033.title", "Captura Inválida"),
+    ("E0033.capture_by_reference", "{0} `{1}` solo se puede capturar por referencia. Escribe `&{2}` en la lista de capturas."),
+    ("E0033.explanation", "El valor no puede ser capturado por el closure.\nSolo se pueden capturar las variables locales, las funciones y los tipos se usan directamente."),
+    ("E0033.example", "fn[sum](x: i32) : i32 { return sum(x, 1); }  // Error: `sum` es una función."),
+    ("E0034.title", "Etiqueta no Declarada"),
+    ("E0034.undeclared_label", "La etiqueta `'{0}` no está declarada por ningún bucle exterior."),
+    ("E0034.explanation", "El `break` o el `continue` se refiere a una etiqueta de ningún bucle exterior."),
+    ("E0034.example", "'outer: for var i: i32 = 0; i < 10; i++; {\n    break 'inner;  // Error: no hay ningún bucle `'inner`.\n}"),
+    ("E0035.title", "Etiqueta Ocultada"),
+    ("E0035.shadowed_label", "La etiqueta `'{0}` oculta la etiqueta de un bucle exterior. Renombra una de ellas."),
+    ("E0035.explanation", "El bucle tiene la etiqueta de un bucle exterior.\nLas etiquetas de los bucles anidados deben ser distintas, para que el `break` y el `continue` no sean ambiguos."),
+    ("E0035.example", "'outer: for var i: i32 = 0; i < 10; i++; {\n    'outer: for var j: i32 = 0; j < 10; j++; { ... }  // Error: `'outer` se usa dos veces.\n}"),
+    ("E0036.title", "Se Necesitan Anotaciones de Tipo"),
+    ("E0036.uninferable_result", "El tipo de `{0}(...)` no se puede inferir. Asígnalo a una variable de tipo `Result<T, E>` o devuélvelo desde una función que devuelve un `Result<T, E>`."),
+    ("E0036.explanation", "Los tipos de la función genérica no se pueden inferir de los argumentos.\nEscribe los tipos de la llamada explícitamente."),
+    ("E0036.example", "fn empty<T>() : T? { return null; }\n\nvar x: i32? = empty();  // Error: el tipo de `T` es desconocido."),
+    ("E0037.title", "Liberación Inalcanzable"),
+    ("E0037.unstored_char", "En este punto es imposible liberar la memoria correctamente. El char debe guardarse en una variable y pasar esa variable al return."),
+    ("E0037.explanation", "El compilador no puede liberar la memoria del valor en esta posición.\nEl valor debe guardarse en una variable antes de este uso."),
+    ("E0037.example", "println(\"{}\", concat(\"a\", \"b\"));  // Error: el resultado de `concat` nunca se libera."),
+    ("E0038.title", "Uso de Clausura Movida"),
+    ("E0038.moved_closure", "La clausura \"{0}\" se movió antes, su entorno pertenece ahora a otra variable o función. Asígnale una nueva clausura antes de usarla."),
+    ("E0038.explanation", "El closure se usa después de moverlo.\nCopiar un closure, pasarlo a una función o capturarlo por valor mueve su entorno, la variable anterior ya no se puede usar.\nAsigna un nuevo closure a la variable antes de volver a usarla."),
+    ("E0038.example", "var g: fn[]() -> i32 = f;\nf();  // Error: el entorno de `f` pertenece a `g`."),
+    ("W0001.title", "Variable sin Usar"),
+    ("W0001.unused_variable", "La variable `{0}` nunca se lee. Elimínala, o renómbrala a `_{1}`."),
+    ("W0001.explanation", "La variable nunca se lee. (lint `unused_variable`)\nElimínala, o renómbrala con un `_` al principio si no se usa a propósito."),
+    ("W0001.example", "var x: i32 = 10;   // Advertencia: `x` nunca se lee.\nvar _x: i32 = 10;  // Ok."),
+    ("W0002.title", "Parámetro sin Usar"),
+    ("W0002.unused_parameter", "El parámetro `{0}` nunca se usa. Elimínalo, o renómbralo a `_{1}`."),
+    ("W0002.explanation", "El parámetro nunca se usa. (lint `unused_parameter`)\nElimínalo, o renómbralo con un `_` al principio si la firma lo necesita."),
+    ("W0002.example", "fn zero(x: i32) : i32 { return 0; }   // Advertencia: `x` nunca se usa.\nfn zero(_x: i32) : i32 { return 0; }  // Ok."),
+    ("W0003.title", "Función sin Usar"),
+    ("W0003.unused_function", "La función privada `{0}` nunca se llama. Elimínala, o hazla pública con `public`."),
+    ("W0003.explanation", "La función privada nunca se llama. (lint `unused_function`)\nElimínala, o hazla pública con `public` si otros archivos la usan."),
+    ("W0003.example", "fn helper() { ... }         // Advertencia: `helper` nunca se llama.\npublic fn helper() { ... }  // Ok."),
+    ("W0004.title", "Asignación Muerta"),
+    ("W0004.dead_store", "El valor guardado en `{0}` nunca se lee, se sobrescribe o sale del ámbito antes. Elimina la asignación."),
+    ("W0004.explanation", "El valor guardado en la variable nunca se lee. (lint `dead_store`)\nLa variable se asigna otra vez o sale del ámbito antes de cualquier lectura del valor."),
+    ("W0004.example", "var x: i32 = 1;  // Advertencia: el valor `1` nunca se lee.\nx = 2;\nprintln(\"{}\", x);"),
+    ("W0005.title", "Variable Ocultada"),
+    ("W0005.shadowed_here", "variable ocultada declarada aquí"),
+    ("W0005.shadowing", "La variable `{0}` oculta la variable declarada en la línea {1}. Renombra una de ellas."),
+    ("W0005.explanation", "La variable tiene el nombre de una variable de un ámbito exterior. (lint `shadowing`)\nLa variable exterior no se puede usar mientras exista la interior, renombra una de ellas."),
+    ("W0005.example", "var x: i32 = 1;\n{\n    var x: i32 = 2;  // Advertencia: `x` oculta la variable de la línea 1.\n}"),
+    ("W0006.title", "Código Inalcanzable"),
+    ("W0006.unreachable_code", "Este código nunca se ejecuta, el bloque siempre sale en la línea {0}. Elimínalo."),
+    ("W0006.explanation", "El código nunca se ejecuta. (lint `unreachable_code`)\nEl bloque siempre sale con un `return`, un `break` o un `continue` antes de él."),
+    ("W0006.example", "return 0;\nprintln(\"done\");  // Advertencia: la función ya retornó."),
+    ("W0007.title", "Condición Constante"),
+    ("W0007.loop_always_false", "La condición del bucle siempre es falsa, el cuerpo del bucle nunca se ejecuta."),
+    ("W0007.loop_always_true", "La condición del bucle siempre es verdadera, el bucle solo termina con un `break` o un `return`."),
+    ("W0007.explanation", "La condición siempre tiene el mismo valor. (lint `constant_condition`)\nUn bucle con una condición siempre verdadera solo termina con un `break`, y uno con una condición siempre falsa nunca se ejecuta."),
+    ("W0007.example", "for var i: i32 = 0; false; i++; { ... }  // Advertencia: el cuerpo nunca se ejecuta."),
+    ("cli.directory", "\"{0}\" es un directorio"),
+    ("cli.error", "ERROR"),
+    ("cli.help.args", "Pasa más argumentos al compilador del backend."),
+    ("cli.help.code_model", "Define cómo se organiza y se accede al código en el ejecutable o en el archivo objeto."),
+    ("cli.help.commands", "Comandos Disponibles:"),
+    ("cli.help.deny", "Reporta el lint o el grupo de lints como un error."),
+    ("cli.help.deny_warnings", "Reporta todas las advertencias como errores."),
+    ("cli.help.dynamic", "Enlaza el ejecutable dinámicamente."),
+    ("cli.help.emit", "Compila el código a Ensamblador o a LLVM IR."),
+    ("cli.help.error_format", "Imprime los errores y las advertencias como texto, o como un objeto JSON por línea en el stderr."),
+    ("cli.help.executable", "Compila el código a un ejecutable nativo."),
+    ("cli.help.explain", "Explica un código de error o de advertencia, como \"E0010\" o \"W0001\"."),
+    ("cli.help.fix", "Aplica las correcciones de los errores y las advertencias en los archivos, o las imprime como un diff con \"--dry-run\"."),
+    ("cli.help.flags", "Opciones Disponibles:"),
+    ("cli.help.help", "Muestra el mensaje de ayuda."),
+    ("cli.help.include", "Incluye el código de una API nativa en el IR."),
+    ("cli.help.lang", "Escribe los diagnósticos en inglés o en español, por defecto el idioma de LANG."),
+    ("cli.help.library", "Compila a un archivo objeto ('*.o')."),
+    ("cli.help.native_target", "Imprime el objetivo nativo de esta máquina."),
+    ("cli.help.optimization", "Nivel de optimización del ejecutable o del archivo objeto a emitir."),
+    ("cli.help.output", "Formato del archivo de salida."),
+    ("cli.help.passes", "Ejecuta un pipeline de pases propio en lugar del del nivel de optimización, como \"function(instcombine,gvn)\"."),
+    ("cli.help.print_passes", "Imprime cada pase ejecutado por el optimizador en el stderr."),
+    ("cli.help.reloc", "Indica cómo se manejan las referencias a direcciones de memoria."),
+    ("cli.help.static", "Enlaza el ejecutable estáticamente."),
+    ("cli.help.static_library", "Compila a una biblioteca estática ('*.a')."),
+    ("cli.help.target", "Arquitectura objetivo del ejecutable o del archivo objeto."),
+    ("cli.help.targets", "Imprime la lista de máquinas objetivo soportadas."),
+    ("cli.help.time_passes", "Imprime el tiempo de cada pase del optimizador en el stderr."),
+    ("cli.help.title", "El Compilador de Thrush"),
+    ("cli.help.usage", "Uso:"),
+    ("cli.help.version", "Muestra la versión."),
+    ("cli.help.warn", "Reporta el lint o el grupo de lints como una advertencia."),
+    ("cli.incompatible_flags", "No puedes usar \"{0}\" y \"{1}\" a la vez."),
+    ("cli.invalid_target", "Objetivo inválido: {0}"),
+    ("cli.missing_api", "Falta la API integrada para \"{0}\"."),
+    ("cli.missing_argument", "Falta el argumento para {0}"),
+    ("cli.missing_explain_code", "Falta el código de error para \"explain\"."),
+    ("cli.missing_fix_files", "Faltan los archivos para \"fix\"."),
+    ("cli.missing_lint", "Falta el nombre del lint para \"{0}\"."),
+    ("cli.missing_passes", "Falta el pipeline de pases para \"{0}\"."),
+    ("cli.missing_quoted_argument", "Falta el argumento para \"{0}\"."),
+    ("cli.not_thrush_file", "\"{0}\" no es un archivo de Thrush."),
+    ("cli.several_mains", "No se permite compilar dos o más archivos \"main.th\"."),
+    ("cli.unknown_api", "Nombre de API integrada desconocido: \"{0}\"."),
+    ("cli.unknown_code", "Código de error desconocido \"{0}\". Los códigos son como \"E0001\" o \"W0001\"."),
+    ("cli.unknown_emit", "\"{0}\" no es un objetivo válido para emitir el código compilado. ¿Quizás el comando es \"(--emit || -emit) llvm-ir || llvm-bc || asm\"?"),
+    ("cli.unknown_error_format", "Formato de error desconocido \"{0}\". ¿Quizás el comando es \"--error-format human || json\"?"),
+    ("cli.unknown_language", "Idioma desconocido \"{0}\". ¿Quizás el comando es \"--lang en || es\"?"),
+    ("cli.unknown_lint", "Lint desconocido \"{0}\"."),
+    ("cli.unknown_optimization", "Nivel de optimización desconocido \"{0}\""),
+    ("cli.without_extension", "\"{0}\" no tiene extensión."),
+    ("cli.without_name", "\"{0}\" no tiene nombre."),
+    ("diagnostic.did_you_mean", "¿Quisiste decir `{0}`?"),
+    ("diagnostic.errors", "{0} errores"),
+    ("diagnostic.from_generic_line", "El error de arriba viene de esta línea de la función genérica."),
+    ("diagnostic.from_generic_line_json", "El error viene de esta línea de la función genérica."),
+    ("diagnostic.from_instantiation_line", "El error de arriba viene de la función genérica instanciada en esta línea."),
+    ("diagnostic.help", "Ayuda"),
+    ("diagnostic.instantiation", "En la instanciación `{0}`"),
+    ("diagnostic.one_error", "1 error"),
+    ("diagnostic.one_warning", "1 advertencia"),
+    ("diagnostic.warnings", "{0} advertencias"),
+    ("explain.example", "Ejemplo:"),
+];
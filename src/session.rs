@@ -0,0 +1,375 @@
+use {
+    super::{
+        backend::{
+            apis::{debug, vector},
+            builder::{Clang, Emit, LLVMOptimizator},
+            compiler::{
+                options::{CompilerOptions, ErrorFormat, ThrushFile},
+                Compiler,
+            },
+            instruction::Instruction,
+        },
+        diagnostic::{self, Diagnostic},
+        error::ThrushError,
+        frontend::{
+            lexer::{Lexer, Token},
+            parser::Parser,
+        },
+        messages::{self, Language},
+        toolchain,
+    },
+    inkwell::{
+        builder::Builder,
+        context::Context,
+        module::Module,
+        targets::{InitializationConfig, Target, TargetMachine},
+    },
+    std::{fs, io, path::PathBuf, sync::Once},
+};
+
+/*
+
+    SESSION
+
+    The compiler as a library: a session compiles the sources with the options and returns the
+    artifacts, or the diagnostics of what went wrong. Nothing is printed and the process is
+    never terminated, the caller decides how to report the diagnostics.
+
+    The sources are given in memory, the artifacts are written in the output directory of the
+    options.
+
+*/
+
+static INITIALIZE_TARGETS: Once = Once::new();
+
+#[derive(Debug, Clone)]
+pub struct Source {
+    pub file: ThrushFile,
+    pub code: String,
+}
+
+impl Source {
+    pub fn new(name: &str, code: String) -> Self {
+        Self {
+            file: ThrushFile::new(name.to_string(), PathBuf::from(name), name == "main.th"),
+            code,
+        }
+    }
+
+    pub fn read(file: &ThrushFile) -> io::Result<Self> {
+        Ok(Self {
+            file: file.clone(),
+            code: fs::read_to_string(&file.path)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub source: Source,
+    pub errors: Vec<ThrushError>,
+    pub warnings: Vec<ThrushError>,
+}
+
+#[derive(Debug, Default)]
+pub struct Diagnostics {
+    pub reports: Vec<Report>,
+    pub errors: Vec<String>,
+    pub language: Language,
+}
+
+impl Diagnostics {
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty() || self.reports.iter().any(|report| !report.errors.is_empty())
+    }
+
+    pub fn emit(self, format: ErrorFormat) {
+        messages::with_language(self.language, || {
+            self.reports.into_iter().for_each(|report| {
+                Diagnostic::from_source(&report.source.file, report.source.code, format)
+                    .report_all(report.errors, report.warnings);
+            });
+
+            self.errors
+                .iter()
+                .for_each(|error| diagnostic::report_message(error, format));
+        });
+    }
+}
+
+impl From<String> for Diagnostics {
+    fn from(error: String) -> Self {
+        Self {
+            reports: Vec::new(),
+            errors: vec![error],
+            language: Language::default(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Artifacts {
+    pub outputs: Vec<PathBuf>,
+    pub diagnostics: Diagnostics,
+}
+
+pub struct Session {
+    options: CompilerOptions,
+}
+
+impl Session {
+    pub fn new(options: CompilerOptions) -> Self {
+        // The targets of LLVM are registered once for the whole process.
+        INITIALIZE_TARGETS.call_once(|| Target::initialize_all(&InitializationConfig::default()));
+
+        Self { options }
+    }
+
+    pub fn options(&self) -> &CompilerOptions {
+        &self.options
+    }
+
+    pub fn compile(&mut self, sources: Vec<Source>) -> Result<Artifacts, Diagnostics> {
+        // The diagnostics are written in the language of the session that found them.
+        let language: Language = self.options.language;
+
+        match messages::with_language(language, || self.compile_sources(sources)) {
+            Ok(mut artifacts) => {
+                artifacts.diagnostics.language = language;
+                Ok(artifacts)
+            }
+            Err(mut diagnostics) => {
+                diagnostics.language = language;
+                Err(diagnostics)
+            }
+        }
+    }
+
+    fn compile_sources(&mut self, mut sources: Vec<Source>) -> Result<Artifacts, Diagnostics> {
+        fs::create_dir_all(&self.options.output_directory).map_err(|error| error.to_string())?;
+
+        sources.sort_by_key(|source| source.file.name != "main.th");
+
+        /*
+            The runtime APIs are compiled before of the sources. The timing of the passes is
+            enabled for the whole process by the first pipeline of the sources, after it the
+            pipelines of the runtime APIs would be reported too.
+        */
+
+        if self.links() {
+            self.compile_runtime()?;
+        }
+
+        let mut diagnostics: Diagnostics = Diagnostics::default();
+        let mut emitted: Vec<PathBuf> = Vec::with_capacity(sources.len());
+
+        for source in sources {
+            match self.compile_source(&source, &mut diagnostics) {
+                Ok(paths) => emitted.extend(paths),
+                Err(error) => {
+                    // The diagnostics of the previous sources are returned with the error.
+                    remove_files(&emitted);
+                    self.remove_runtime();
+
+                    diagnostics.errors.push(error);
+
+                    return Err(diagnostics);
+                }
+            }
+        }
+
+        if diagnostics.has_errors() {
+            remove_files(&emitted);
+            self.remove_runtime();
+
+            return Err(diagnostics);
+        }
+
+        // The object files are only the input of the link.
+        let (objects, mut outputs): (Vec<PathBuf>, Vec<PathBuf>) = emitted
+            .into_iter()
+            .partition(|path| path.extension().is_some_and(|extension| extension == "o"));
+
+        if self.links() {
+            match self.link(&objects) {
+                Ok(linked) => outputs.extend(linked),
+                Err(error) => {
+                    diagnostics.errors.push(error);
+                    return Err(diagnostics);
+                }
+            }
+        }
+
+        Ok(Artifacts {
+            outputs,
+            diagnostics,
+        })
+    }
+
+    fn compile_source(
+        &self,
+        source: &Source,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<Vec<PathBuf>, String> {
+        /*
+            The errors of a source are collected and the next source is compiled, so all the
+            errors are reported together. Only the sources without errors reach the backend.
+        */
+
+        let file: &ThrushFile = &source.file;
+
+        let mut lexer: Lexer = Lexer::new(source.code.as_bytes());
+        let (tokens, mut errors): (&[Token], Vec<ThrushError>) = lexer.lex();
+
+        let mut parser: Parser = Parser::new(tokens, file, &self.options.lints);
+        let (instructions, parser_errors, warnings): (
+            &[Instruction],
+            Vec<ThrushError>,
+            Vec<ThrushError>,
+        ) = parser.start();
+
+        errors.extend(parser_errors);
+        errors.retain(|error| *error != ThrushError::None);
+
+        let has_errors: bool = !errors.is_empty();
+
+        if has_errors || !warnings.is_empty() {
+            diagnostics.reports.push(Report {
+                source: source.clone(),
+                errors,
+                warnings,
+            });
+        }
+
+        if has_errors || diagnostics.has_errors() {
+            return Ok(Vec::new());
+        }
+
+        let context: Context = Context::create();
+        let builder: Builder<'_> = context.create_builder();
+        let module: Module<'_> = context.create_module(&file.name);
+
+        module.set_triple(&self.options.target_triple);
+
+        let machine: TargetMachine = self.options.target_machine()?;
+
+        module.set_data_layout(&machine.get_target_data().get_data_layout());
+
+        Compiler::compile(&module, &builder, &context, &self.options, instructions);
+
+        LLVMOptimizator::optimize(&module, &machine, &self.options)?;
+
+        self.emit(&module, &machine, &file.name)
+    }
+
+    fn emit(
+        &self,
+        module: &Module<'_>,
+        machine: &TargetMachine,
+        name: &str,
+    ) -> Result<Vec<PathBuf>, String> {
+        // Every output is written beside the others, like `output/main.th.ll`.
+        let emits: [(bool, Emit); 4] = [
+            (self.options.emit_llvm_ir, Emit::LLVMIR),
+            (self.options.emit_llvm_bitcode, Emit::LLVMBitcode),
+            (self.options.emit_asm, Emit::Assembly),
+            (self.links(), Emit::Object),
+        ];
+
+        let mut emitted: Vec<PathBuf> = Vec::with_capacity(emits.len());
+
+        for (_, emit) in emits.iter().filter(|(enabled, _)| *enabled) {
+            let path: PathBuf =
+                self.options
+                    .output_directory
+                    .join(format!("{}.{}", name, emit.extension()));
+
+            if let Err(error) = emit.write(module, machine, &path) {
+                remove_files(&emitted);
+                return Err(error);
+            }
+
+            emitted.push(path);
+        }
+
+        Ok(emitted)
+    }
+
+    fn link(&mut self, objects: &[PathBuf]) -> Result<Vec<PathBuf>, String> {
+        // The toolchain is only needed to link, the outputs are emitted without it.
+        let toolchain: PathBuf = toolchain::backend_compiler().inspect_err(|_| {
+            remove_files(objects);
+            self.remove_runtime();
+        })?;
+
+        let runtime: [PathBuf; 2] = self.runtime();
+        let options: &mut CompilerOptions = &mut self.options;
+        let previous_args: Vec<String> = options.args.clone();
+
+        if options.executable || options.library || options.static_library {
+            options.args.extend(
+                runtime
+                    .iter()
+                    .map(|path| path.to_string_lossy().into_owned()),
+            );
+        }
+
+        let linked: Result<(), String> = Clang::new(objects, options, &toolchain).link();
+
+        options.args = previous_args;
+
+        let output: PathBuf = options.output_directory.join(&options.output);
+
+        let _ = fs::copy(&options.output, &output);
+        let _ = fs::remove_file(&options.output);
+
+        remove_files(objects);
+        remove_files(&runtime);
+
+        linked?;
+
+        Ok([output].into_iter().filter(|path| path.is_file()).collect())
+    }
+
+    fn compile_runtime(&self) -> Result<(), String> {
+        if !self.options.include_vector_api {
+            vector::compile_vector_api(&self.options)?;
+        }
+
+        if !self.options.include_debug_api {
+            debug::compile_debug_api(&self.options).inspect_err(|_| self.remove_runtime())?;
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn runtime(&self) -> [PathBuf; 2] {
+        [
+            self.options.output_directory.join("vector.o"),
+            self.options.output_directory.join("debug.o"),
+        ]
+    }
+
+    #[inline]
+    fn remove_runtime(&self) {
+        remove_files(&self.runtime());
+    }
+
+    #[inline]
+    fn links(&self) -> bool {
+        // Only the LLVM IR and the LLVM bitcode are emitted without link.
+        !self.options.emit_llvm_ir && !self.options.emit_llvm_bitcode
+    }
+}
+
+pub fn compile(options: CompilerOptions, sources: Vec<Source>) -> Result<Artifacts, Diagnostics> {
+    Session::new(options).compile(sources)
+}
+
+#[inline]
+fn remove_files(paths: &[PathBuf]) {
+    paths.iter().for_each(|path| {
+        let _ = fs::remove_file(path);
+    });
+}
@@ -0,0 +1,76 @@
+use {
+    ahash::AHashMap as HashMap,
+    lazy_static::lazy_static,
+    std::{env, path::PathBuf},
+};
+
+/*
+
+    TOOLCHAIN
+
+    The Thrush Toolchain lives in the home of the user, the LLVM backend and the core library
+    are looked up there. A missing piece is returned as an error, the caller decides how to
+    report it.
+
+*/
+
+lazy_static! {
+    static ref HOME: Option<PathBuf> = {
+        match env::consts::OS {
+            "windows" => env::var("APPDATA").ok().map(PathBuf::from),
+            "linux" => env::var("HOME").ok().map(PathBuf::from),
+            _ => None,
+        }
+    };
+}
+
+pub fn core_libraries() -> Result<HashMap<&'static str, (String, String)>, String> {
+    let Some(home) = HOME.as_ref() else {
+        return Err(format!(
+            "Thrush Toolchain is unreacheable via path, re-install the entire toolchain via \"thorium install {}\".",
+            env::consts::OS
+        ));
+    };
+
+    let mut imports: HashMap<&'static str, (String, String)> = HashMap::with_capacity(1);
+
+    imports.insert(
+        "core.fmt",
+        (
+            String::from("fmt.th"),
+            home.join("thrushlang/core/fmt.th")
+                .to_string_lossy()
+                .to_string(),
+        ),
+    );
+
+    Ok(imports)
+}
+
+pub fn backend_compiler() -> Result<PathBuf, String> {
+    let Some(home) = HOME.as_ref() else {
+        return Err(format!(
+            "LLVM Toolchain was corrupted from Thrush Toolchain, re-install the entire toolchain via \"thorium install {}\".",
+            env::consts::OS
+        ));
+    };
+
+    let backend: PathBuf = home.join("thrushlang/backends/llvm/backend/bin/");
+
+    if !backend.exists() {
+        return Err(format!(
+            "LLVM Toolchain was corrupted from Thrush Toolchain, re-install the entire toolchain via \"thorium install {}\".",
+            env::consts::OS
+        ));
+    }
+
+    // Clang is the only tool run from the toolchain, to link the object files.
+    if !backend.join("clang-17").exists() {
+        return Err(format!(
+            "Clang-17 don't exists in Thrush Toolchain, re-install the entire toolchain via \"thorium install {}\".",
+            env::consts::OS
+        ));
+    }
+
+    Ok(backend)
+}